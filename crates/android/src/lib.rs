@@ -67,8 +67,7 @@ fn parse_schemes(output: &str) -> Vec<String> {
         }
     }
 
+    let mut product_flavors = product_flavors.into_iter().collect::<Vec<String>>();
+    product_flavors.sort();
     product_flavors
-        .iter()
-        .map(|x| x.to_string())
-        .collect::<Vec<String>>()
 }
@@ -5,7 +5,10 @@ use serde::de::DeserializeOwned;
 use tokio::{
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     process::{ChildStdin, Command},
-    sync::{broadcast, Mutex},
+    sync::{
+        broadcast::{self, error::RecvError},
+        watch, Mutex,
+    },
 };
 
 use super::io::{
@@ -15,7 +18,7 @@ use super::io::{
         ConnectedEventParams, FlutterDaemonEvent, LogEventParams, LogMessageEventParams,
         ShowMessageEventParams,
     },
-    parse_event, parse_response,
+    parse_error_response, parse_event, parse_response,
     request::{
         CreateEmultorParams, DeviceForwardParams, DeviceUnforwardParams, FlutterDaemonRequest,
         GetSupportedPlatformsParams, LaunchEmulatorParams,
@@ -37,6 +40,8 @@ pub struct FlutterDaemon {
     // So we need to keep the receiver alive.
     _rx: broadcast::Receiver<String>,
     _error_rx: broadcast::Receiver<String>,
+    /// Becomes true when the stdout of the daemon is closed.
+    exited: watch::Receiver<bool>,
 
     stdin: Arc<Mutex<ChildStdin>>,
     request_count: Arc<Mutex<u32>>,
@@ -73,12 +78,14 @@ impl FlutterDaemon {
         let (tx, _rx) = broadcast::channel::<String>(16);
         let (error_tx, _error_rx) = broadcast::channel::<String>(16);
 
+        let (exited_tx, exited) = watch::channel(false);
         let _tx = tx.clone();
         tokio::spawn(async move {
             let mut lines = BufReader::new(stdout).lines();
             while let Ok(Some(line)) = lines.next_line().await {
                 let _ = _tx.send(line);
             }
+            let _ = exited_tx.send(true);
         });
 
         let _error_tx = error_tx.clone();
@@ -95,6 +102,7 @@ impl FlutterDaemon {
             error_tx,
             _rx,
             _error_rx,
+            exited,
             _process: process,
             request_count: Arc::new(Mutex::new(0)),
         })
@@ -154,12 +162,14 @@ impl FlutterDaemon {
         Ok(())
     }
 
+    /// Forwards `port` on the device to `host_port`, or to a free port when it's `None`.
+    /// Returns the host port.
     pub async fn forward_device(
         &self,
         device_id: String,
         port: u32,
         host_port: Option<u32>,
-    ) -> Result<()> {
+    ) -> Result<u32> {
         let request_id = self.request_id().await;
         let request = FlutterDaemonRequest::DeviceForward {
             id: request_id,
@@ -170,8 +180,11 @@ impl FlutterDaemon {
             },
         };
         self.send_request(&request).await?;
-        let _: DeviceForwardResponse = self.receive_response(request_id).await?;
-        Ok(())
+        let response: DeviceForwardResponse = self.receive_response(request_id).await?;
+        response
+            .result
+            .map(|result| result.host_port)
+            .ok_or(eyre!("Could not forward port"))
     }
 
     pub async fn unforward_device(
@@ -216,15 +229,22 @@ impl FlutterDaemon {
         Ok(())
     }
 
-    pub async fn create_emulator(&self, name: Option<String>) -> Result<()> {
+    /// Creates a new Android emulator and returns its name.
+    pub async fn create_emulator(&self, name: Option<String>) -> Result<String> {
         let request_id = self.request_id().await;
         let request = FlutterDaemonRequest::CreateEmulator {
             id: request_id,
             params: CreateEmultorParams { name },
         };
         self.send_request(&request).await?;
-        let _: EmulatorCreateResponse = self.receive_response(request_id).await?;
-        Ok(())
+        let response: EmulatorCreateResponse = self.receive_response(request_id).await?;
+        let result = response.result.ok_or(eyre!("Could not create emulator"))?;
+        if !result.success {
+            return Err(eyre!(result
+                .error
+                .unwrap_or("Could not create emulator".to_string())));
+        }
+        Ok(result.emulator_name.unwrap_or_default())
     }
 
     pub async fn serve_devtools(&self) -> Result<ServeDevToolsResult> {
@@ -289,6 +309,15 @@ impl FlutterDaemon {
         Err(eyre!("Could not receive daemon event"))
     }
 
+    /// Subscribes to the events of the daemon. Unlike the `receive_*` methods, which subscribe on
+    /// every call, no event is missed between two calls of `EventReceiver::recv`.
+    pub fn subscribe_events(&self) -> EventReceiver {
+        EventReceiver {
+            rx: self.tx.subscribe(),
+            exited: self.exited.clone(),
+        }
+    }
+
     pub async fn receive_stdout(&self) -> Result<String> {
         let mut rx = self.tx.subscribe();
         while let Ok(line) = rx.recv().await {
@@ -328,6 +357,9 @@ impl FlutterDaemon {
     {
         let mut rx = self.tx.subscribe();
         while let Ok(line) = rx.recv().await {
+            if let Some(error) = parse_error_response(&line, request_id) {
+                return Err(eyre!(error));
+            }
             let response: Option<FlutterDaemonResponse<T>> = parse_response(&line, request_id);
             if let Some(res) = response {
                 return Ok(res);
@@ -347,10 +379,70 @@ impl FlutterDaemon {
     }
 }
 
+pub struct EventReceiver {
+    rx: broadcast::Receiver<String>,
+    exited: watch::Receiver<bool>,
+}
+
+impl EventReceiver {
+    /// Waits for the next event. Fails once the daemon has exited and every event it sent
+    /// before has been received.
+    pub async fn recv(&mut self) -> Result<FlutterDaemonEvent> {
+        loop {
+            tokio::select! {
+                biased;
+                line = self.rx.recv() => match line {
+                    Ok(line) => {
+                        if let Some(event) = parse_event(&line) {
+                            return Ok(event);
+                        }
+                    }
+                    Err(RecvError::Lagged(count)) => {
+                        log::warn!("Missed {} lines from the flutter daemon", count);
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                _ = self.exited.wait_for(|exited| *exited) => break,
+            }
+        }
+        Err(eyre!("The flutter daemon exited"))
+    }
+}
+
 #[cfg(test)]
 mod test {
 
-    use crate::flutter::FlutterDaemon;
+    use tokio::sync::{broadcast, watch};
+
+    use crate::flutter::{EventReceiver, FlutterDaemon};
+    use crate::io::event::FlutterDaemonEvent;
+
+    #[tokio::test]
+    async fn receive_events_sent_before_exit() {
+        let (tx, rx) = broadcast::channel::<String>(16);
+        let (exited_tx, exited) = watch::channel(false);
+        let mut events = EventReceiver { rx, exited };
+
+        tx.send("Waiting for another flutter command to release the startup lock...".to_string())
+            .unwrap();
+        tx.send(
+            r#"[{"event":"daemon.connected","params":{"version":"0.6.1","pid":1}}]"#.to_string(),
+        )
+        .unwrap();
+        tx.send(r#"[{"event":"app.stop","params":{"appId":"a"}}]"#.to_string())
+            .unwrap();
+        exited_tx.send(true).unwrap();
+
+        assert!(matches!(
+            events.recv().await,
+            Ok(FlutterDaemonEvent::Connected { .. })
+        ));
+        assert!(matches!(
+            events.recv().await,
+            Ok(FlutterDaemonEvent::AppStop { .. })
+        ));
+        assert!(events.recv().await.is_err());
+    }
 
     #[tokio::test]
     #[ignore]
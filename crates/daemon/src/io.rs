@@ -33,6 +33,22 @@ where
     None
 }
 
+/// Returns the error message when `s` is an error response to the request with `id`.
+pub fn parse_error_response(s: &str, id: u32) -> Option<String> {
+    if !(s.starts_with("[{") && s.ends_with("}]")) {
+        return None;
+    }
+    let s = s.trim_start_matches('[').trim_end_matches(']');
+    let value = serde_json::from_str::<serde_json::Value>(s).ok()?;
+    if value.get("id")?.as_u64()? != id as u64 {
+        return None;
+    }
+    match value.get("error")? {
+        serde_json::Value::String(error) => Some(error.clone()),
+        error => Some(error.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -115,4 +131,17 @@ mod tests {
         let response = parse_response::<String>(s, 1);
         assert_eq!(response, None);
     }
+
+    #[test]
+    fn test_parse_error_response() {
+        let s = r#"[{"id":3,"error":"No emulator found that matches pixel","trace":""}]"#;
+        assert_eq!(
+            parse_error_response(s, 3),
+            Some("No emulator found that matches pixel".to_string())
+        );
+        assert_eq!(parse_error_response(s, 4), None);
+
+        let s = r#"[{"id":3,"result":"1234"}]"#;
+        assert_eq!(parse_error_response(s, 3), None);
+    }
 }
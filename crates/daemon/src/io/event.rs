@@ -119,6 +119,9 @@ pub struct AppProgressEventParams {
 pub struct AppStopEventParams {
     #[serde(rename = "appId")]
     pub app_id: String,
+    /// Set when the app failed to start or crashed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -335,7 +338,20 @@ mod test {
             event,
             super::FlutterDaemonEvent::AppStop {
                 params: super::AppStopEventParams {
-                    app_id: "com.example.app".to_string()
+                    app_id: "com.example.app".to_string(),
+                    error: None,
+                }
+            }
+        );
+
+        let json = r#"{"event":"app.stop","params":{"appId":"com.example.app","error":"Gradle task assembleDebug failed with exit code 1"}}"#;
+        let event: super::FlutterDaemonEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            event,
+            super::FlutterDaemonEvent::AppStop {
+                params: super::AppStopEventParams {
+                    app_id: "com.example.app".to_string(),
+                    error: Some("Gradle task assembleDebug failed with exit code 1".to_string()),
                 }
             }
         );
@@ -38,8 +38,9 @@ pub struct RestartAppParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
 
+    /// Lets the tool merge requests that arrive in quick succession into one reload.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub debounce: Option<String>,
+    pub debounce: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -336,6 +337,22 @@ mod tests {
             serialized,
             r#"{"method":"app.restart","id":1,"params":{"appId":"com.example.app","fullRestart":false,"pause":false}}"#
         );
+
+        let method = FlutterDaemonRequest::RestartApp {
+            id: 1,
+            params: super::RestartAppParams {
+                app_id: String::from("com.example.app"),
+                full_restart: false,
+                pause: false,
+                reason: Some(String::from("save")),
+                debounce: Some(true),
+            },
+        };
+        let serialized = serde_json::to_string(&method).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"method":"app.restart","id":1,"params":{"appId":"com.example.app","fullRestart":false,"pause":false,"reason":"save","debounce":true}}"#
+        );
     }
 
     #[test]
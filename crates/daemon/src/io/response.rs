@@ -22,10 +22,34 @@ pub struct ServeDevToolsResult {
     port: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceForwardResult {
+    #[serde(rename = "hostPort")]
+    pub host_port: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmulatorCreateResult {
+    pub success: bool,
+    #[serde(rename = "emulatorName")]
+    pub emulator_name: Option<String>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RestartAppResult {
     pub code: u32,
     pub message: String,
+    #[serde(rename = "hintMessage")]
+    pub hint_message: Option<String>,
+    #[serde(rename = "hintId")]
+    pub hint_id: Option<String>,
+}
+
+impl RestartAppResult {
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
 }
 
 pub type VersionResponse = FlutterDaemonResponse<String>;
@@ -40,7 +64,7 @@ pub type DeviceEnableResponse = FlutterDaemonResponse<()>;
 
 pub type DeviceDisableResponse = FlutterDaemonResponse<()>;
 
-pub type DeviceForwardResponse = FlutterDaemonResponse<()>;
+pub type DeviceForwardResponse = FlutterDaemonResponse<DeviceForwardResult>;
 
 pub type DeviceUnforwardResponse = FlutterDaemonResponse<()>;
 
@@ -48,7 +72,7 @@ pub type GetEmulatorsResponse = FlutterDaemonResponse<Vec<Emulator>>;
 
 pub type EmulatorLaunchResponse = FlutterDaemonResponse<()>;
 
-pub type EmulatorCreateResponse = FlutterDaemonResponse<()>;
+pub type EmulatorCreateResponse = FlutterDaemonResponse<EmulatorCreateResult>;
 
 pub type ServeDevToolsResponse = FlutterDaemonResponse<ServeDevToolsResult>;
 
@@ -174,13 +198,13 @@ mod test {
 
     #[test]
     fn device_forward_response() {
-        let json = r#"{"id":1}"#;
+        let json = r#"{"id":1,"result":{"hostPort":8080}}"#;
         let response: DeviceForwardResponse = serde_json::from_str(json).unwrap();
         assert_eq!(
             response,
             DeviceForwardResponse {
                 id: 1,
-                result: None
+                result: Some(DeviceForwardResult { host_port: 8080 })
             }
         );
     }
@@ -231,13 +255,18 @@ mod test {
 
     #[test]
     fn emulator_create_response() {
-        let json = r#"{"id":1}"#;
+        let json =
+            r#"{"id":1,"result":{"success":true,"emulatorName":"flutter_emulator","error":null}}"#;
         let response: EmulatorCreateResponse = serde_json::from_str(json).unwrap();
         assert_eq!(
             response,
             EmulatorCreateResponse {
                 id: 1,
-                result: None
+                result: Some(EmulatorCreateResult {
+                    success: true,
+                    emulator_name: Some("flutter_emulator".to_string()),
+                    error: None,
+                })
             }
         );
     }
@@ -269,11 +298,26 @@ mod test {
                 result: Some(RestartAppResult {
                     code: 0,
                     message: "Success".to_string(),
+                    hint_message: None,
+                    hint_id: None,
                 })
             }
         )
     }
 
+    #[test]
+    fn restart_app_response_with_hint() {
+        let json = r#"{"id":1,"result":{"code":1,"message":"Reload rejected","hintMessage":"Try performing a hot restart instead.","hintId":"restartRecommended"}}"#;
+        let response: RestartAppResponse = serde_json::from_str(json).unwrap();
+        let result = response.result.unwrap();
+        assert!(!result.is_success());
+        assert_eq!(
+            result.hint_message,
+            Some("Try performing a hot restart instead.".to_string())
+        );
+        assert_eq!(result.hint_id, Some("restartRecommended".to_string()));
+    }
+
     #[test]
     fn stop_app_response() {
         let json = r#"{"id":1,"result":true}"#;
@@ -1,4 +1,4 @@
-use std::{path::PathBuf, process::Stdio, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, process::Stdio, sync::Arc};
 
 use color_eyre::{eyre::eyre, Result};
 use serde::de::DeserializeOwned;
@@ -14,7 +14,7 @@ use super::io::{
         AppStartedEventParams, AppStopEventParams, ConnectedEventParams, FlutterDaemonEvent,
         LogEventParams, LogMessageEventParams,
     },
-    parse_event, parse_response,
+    parse_error_response, parse_event, parse_response,
     request::{DetachAppParams, FlutterDaemonRequest, RestartAppParams, StopAppParams},
     response::{
         DetachAppResponse, FlutterDaemonResponse, RestartAppResponse, RestartAppResult,
@@ -22,6 +22,22 @@ use super::io::{
     },
 };
 
+/// Why a hot reload or hot restart was requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartReason {
+    Manual,
+    Save,
+}
+
+impl RestartReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RestartReason::Manual => "manual",
+            RestartReason::Save => "save",
+        }
+    }
+}
+
 pub struct FlutterRun {
     app_id: Arc<Mutex<Option<String>>>,
     tx: broadcast::Sender<String>,
@@ -38,15 +54,52 @@ pub struct FlutterRun {
 }
 
 impl FlutterRun {
+    /// Runs `flutter run`. `tool_args` are passed to the flutter tool before `args`, and `env`
+    /// is added to the environment of the process.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         project_root: PathBuf,
         device_id: Option<String>,
         program: Option<String>,
         flutter_mode: Option<String>,
         cwd: Option<String>,
+        tool_args: Option<Vec<String>>,
         args: Option<Vec<String>>,
+        env: Option<HashMap<String, String>>,
         use_fvm: bool,
     ) -> Result<Self> {
+        let arguments = Self::run_arguments(device_id, program, flutter_mode, tool_args, args);
+        Self::spawn(project_root, cwd, arguments, env, use_fvm)
+    }
+
+    /// Attaches to an app which is already running on the device, e.g. one started by native
+    /// tooling or an add-to-app host. Without `debug_url` the tool waits for the app to be
+    /// launched and connects to it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn attach(
+        project_root: PathBuf,
+        device_id: Option<String>,
+        program: Option<String>,
+        debug_url: Option<String>,
+        app_id: Option<String>,
+        cwd: Option<String>,
+        tool_args: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+        env: Option<HashMap<String, String>>,
+        use_fvm: bool,
+    ) -> Result<Self> {
+        let arguments =
+            Self::attach_arguments(device_id, program, debug_url, app_id, tool_args, args);
+        Self::spawn(project_root, cwd, arguments, env, use_fvm)
+    }
+
+    fn run_arguments(
+        device_id: Option<String>,
+        program: Option<String>,
+        flutter_mode: Option<String>,
+        tool_args: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+    ) -> Vec<String> {
         let mut arguments = vec!["run".to_string(), "--machine".to_string()];
         if let Some(device_id) = device_id {
             arguments.push("-d".to_string());
@@ -59,9 +112,56 @@ impl FlutterRun {
         if let Some(flutter_mode) = flutter_mode {
             arguments.push(format!("--{}", flutter_mode));
         }
+        if let Some(tool_args) = tool_args {
+            arguments.extend(tool_args);
+        }
         if let Some(args) = args {
             arguments.extend(args);
         }
+        arguments
+    }
+
+    fn attach_arguments(
+        device_id: Option<String>,
+        program: Option<String>,
+        debug_url: Option<String>,
+        app_id: Option<String>,
+        tool_args: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+    ) -> Vec<String> {
+        let mut arguments = vec!["attach".to_string(), "--machine".to_string()];
+        if let Some(device_id) = device_id {
+            arguments.push("-d".to_string());
+            arguments.push(device_id);
+        }
+        if let Some(program) = program {
+            arguments.push("-t".to_string());
+            arguments.push(program);
+        }
+        if let Some(debug_url) = debug_url {
+            arguments.push("--debug-url".to_string());
+            arguments.push(debug_url);
+        }
+        if let Some(app_id) = app_id {
+            arguments.push("--app-id".to_string());
+            arguments.push(app_id);
+        }
+        if let Some(tool_args) = tool_args {
+            arguments.extend(tool_args);
+        }
+        if let Some(args) = args {
+            arguments.extend(args);
+        }
+        arguments
+    }
+
+    fn spawn(
+        project_root: PathBuf,
+        cwd: Option<String>,
+        arguments: Vec<String>,
+        env: Option<HashMap<String, String>>,
+        use_fvm: bool,
+    ) -> Result<Self> {
         let mut command = if use_fvm {
             Command::new("fvm")
         } else {
@@ -72,6 +172,7 @@ impl FlutterRun {
         }
         let mut process = command
             .args(arguments)
+            .envs(env.unwrap_or_default())
             .kill_on_drop(true)
             .current_dir(project_root.join(cwd.unwrap_or_default()))
             .stdin(Stdio::piped())
@@ -148,12 +249,12 @@ impl FlutterRun {
         Ok(())
     }
 
-    pub async fn hot_reload(&self) -> Result<RestartAppResult> {
-        self.restart(false).await
+    pub async fn hot_reload(&self, reason: RestartReason) -> Result<RestartAppResult> {
+        self.restart(false, reason).await
     }
 
-    pub async fn hot_restart(&self) -> Result<RestartAppResult> {
-        self.restart(true).await
+    pub async fn hot_restart(&self, reason: RestartReason) -> Result<RestartAppResult> {
+        self.restart(true, reason).await
     }
 
     pub async fn detach(&self) -> Result<()> {
@@ -296,7 +397,7 @@ impl FlutterRun {
         }
     }
 
-    async fn restart(&self, full_restart: bool) -> Result<RestartAppResult> {
+    async fn restart(&self, full_restart: bool, reason: RestartReason) -> Result<RestartAppResult> {
         let request_id = self.request_id().await;
         let app_id = self
             .app_id
@@ -310,8 +411,9 @@ impl FlutterRun {
                 app_id,
                 full_restart,
                 pause: false,
-                reason: None,
-                debounce: None,
+                reason: Some(reason.as_str().to_string()),
+                // Editors often write several files at once on save.
+                debounce: Some(reason == RestartReason::Save),
             },
         };
         self.send_request(&request).await?;
@@ -339,6 +441,9 @@ impl FlutterRun {
     {
         let mut rx = self.tx.subscribe();
         while let Ok(line) = rx.recv().await {
+            if let Some(error) = parse_error_response(&line, request_id) {
+                return Err(eyre!(error));
+            }
             let response: Option<FlutterDaemonResponse<T>> = parse_response(&line, request_id);
             if let Some(res) = response {
                 return Ok(res);
@@ -357,3 +462,63 @@ impl FlutterRun {
         Err(eyre!("Could not receive daemon event"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::run::FlutterRun;
+
+    #[test]
+    fn run_arguments() {
+        let arguments = FlutterRun::run_arguments(
+            Some("emulator-5554".to_string()),
+            Some("lib/main_dev.dart".to_string()),
+            Some("profile".to_string()),
+            Some(vec![
+                "--dart-define-from-file".to_string(),
+                "config.json".to_string(),
+            ]),
+            Some(vec!["--flavor".to_string(), "dev".to_string()]),
+        );
+        assert_eq!(
+            arguments,
+            vec![
+                "run",
+                "--machine",
+                "-d",
+                "emulator-5554",
+                "-t",
+                "lib/main_dev.dart",
+                "--profile",
+                "--dart-define-from-file",
+                "config.json",
+                "--flavor",
+                "dev",
+            ]
+        );
+    }
+
+    #[test]
+    fn attach_arguments() {
+        let arguments = FlutterRun::attach_arguments(
+            Some("emulator-5554".to_string()),
+            None,
+            Some("http://127.0.0.1:50300/abc=/".to_string()),
+            Some("com.example.app".to_string()),
+            None,
+            None,
+        );
+        assert_eq!(
+            arguments,
+            vec![
+                "attach",
+                "--machine",
+                "-d",
+                "emulator-5554",
+                "--debug-url",
+                "http://127.0.0.1:50300/abc=/",
+                "--app-id",
+                "com.example.app",
+            ]
+        );
+    }
+}
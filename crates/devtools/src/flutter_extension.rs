@@ -152,10 +152,13 @@ impl FlutterExtensionProtocol for VmService {
     }
 
     async fn time_dilation(&self, isolate_id: &str, value: Option<&str>) -> Result<TimeDilation> {
-        let params = params! {
+        let mut params = params! {
             "isolateId".to_owned() => isolate_id.into(),
-            "value".to_owned() => value.into(),
         };
+        // Without a value the current one is returned instead of changed.
+        if let Some(value) = value {
+            params.insert("value".to_owned(), value.into());
+        }
         self.call("ext.flutter.timeDilation", params).await
     }
 
@@ -220,18 +223,24 @@ impl FlutterExtensionProtocol for VmService {
     }
 
     async fn platform_override(&self, isolate_id: &str, value: Option<&str>) -> Result<Value> {
-        let params = params! {
+        let mut params = params! {
             "isolateId".to_owned() => isolate_id.into(),
-            "value".to_owned() => value.into(),
         };
+        // Without a value the current one is returned instead of changed.
+        if let Some(value) = value {
+            params.insert("value".to_owned(), value.into());
+        }
         self.call("ext.flutter.platformOverride", params).await
     }
 
     async fn brightness_override(&self, isolate_id: &str, value: Option<&str>) -> Result<Value> {
-        let params = params! {
+        let mut params = params! {
             "isolateId".to_owned() => isolate_id.into(),
-            "value".to_owned() => value.into(),
         };
+        // Without a value the current one is returned instead of changed.
+        if let Some(value) = value {
+            params.insert("value".to_owned(), value.into());
+        }
         self.call("ext.flutter.brightnessOverride", params).await
     }
 
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use color_eyre::Result;
 use futures::Future;
 use serde::{Deserialize, Serialize};
@@ -619,6 +621,79 @@ pub struct Location {
     pub name: Option<String>,
 }
 
+/// Payload of the `Flutter.RebuildDirtyWidgets` extension event which is posted
+/// once per frame while `track_rebuild_dirty_widgets` is enabled.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RebuildDirtyWidgetsEvent {
+    #[serde(rename = "startTime")]
+    pub start_time: Option<i64>,
+    #[serde(rename = "frameNumber")]
+    pub frame_number: Option<i64>,
+    /// Flat list of `[location_id, count, location_id, count, ...]`.
+    pub events: Vec<i64>,
+    /// Locations that were not sent before, grouped by file.
+    /// Newer Flutter versions send them in `locations`, older ones in `newLocations`
+    /// as flat lists of `[id, line, column, id, line, column, ...]`.
+    pub locations: Option<HashMap<String, RebuildLocations>>,
+    #[serde(rename = "newLocations")]
+    pub new_locations: Option<HashMap<String, Vec<i64>>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RebuildLocations {
+    pub ids: Vec<i64>,
+    pub lines: Vec<i64>,
+    pub columns: Vec<i64>,
+    pub names: Option<Vec<String>>,
+}
+
+impl RebuildDirtyWidgetsEvent {
+    pub fn counts(&self) -> Vec<(i64, u64)> {
+        self.events
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1].max(0) as u64))
+            .collect()
+    }
+
+    pub fn new_locations(&self) -> Vec<(i64, Location)> {
+        let mut result = vec![];
+        if let Some(locations) = &self.locations {
+            for (file, locations) in locations {
+                for (index, id) in locations.ids.iter().enumerate() {
+                    result.push((
+                        *id,
+                        Location {
+                            file: file.clone(),
+                            line: locations.lines.get(index).copied().unwrap_or_default(),
+                            column: locations.columns.get(index).copied().unwrap_or_default(),
+                            name: locations
+                                .names
+                                .as_ref()
+                                .and_then(|names| names.get(index).cloned()),
+                        },
+                    ));
+                }
+            }
+        }
+        if let Some(new_locations) = &self.new_locations {
+            for (file, values) in new_locations {
+                for chunk in values.chunks_exact(3) {
+                    result.push((
+                        chunk[0],
+                        Location {
+                            file: file.clone(),
+                            line: chunk[1],
+                            column: chunk[2],
+                            name: None,
+                        },
+                    ));
+                }
+            }
+        }
+        result
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Constraints {
     pub r#type: String,
@@ -660,4 +735,55 @@ mod test {
             serde_json::from_str::<VmServiceResponse<ResultResponse<DiagnosticNode>>>(response);
         assert!(node.is_ok());
     }
+
+    #[test]
+    fn parse_rebuild_dirty_widgets_event() {
+        let json = r#"{"startTime":1000,"frameNumber":3,"events":[1,2,5,1],"locations":{"file:///app/lib/main.dart":{"ids":[1,5],"lines":[10,20],"columns":[3,7],"names":["MyApp","Text"]}}}"#;
+        let event = serde_json::from_str::<RebuildDirtyWidgetsEvent>(json).unwrap();
+        assert_eq!(event.counts(), vec![(1, 2), (5, 1)]);
+        let mut locations = event.new_locations();
+        locations.sort_by_key(|(id, _)| *id);
+        assert_eq!(
+            locations,
+            vec![
+                (
+                    1,
+                    Location {
+                        file: "file:///app/lib/main.dart".to_string(),
+                        line: 10,
+                        column: 3,
+                        name: Some("MyApp".to_string()),
+                    }
+                ),
+                (
+                    5,
+                    Location {
+                        file: "file:///app/lib/main.dart".to_string(),
+                        line: 20,
+                        column: 7,
+                        name: Some("Text".to_string()),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_legacy_rebuild_dirty_widgets_event() {
+        let json = r#"{"startTime":1000,"events":[4,10],"newLocations":{"file:///app/lib/main.dart":[4,12,9]}}"#;
+        let event = serde_json::from_str::<RebuildDirtyWidgetsEvent>(json).unwrap();
+        assert_eq!(event.counts(), vec![(4, 10)]);
+        assert_eq!(
+            event.new_locations(),
+            vec![(
+                4,
+                Location {
+                    file: "file:///app/lib/main.dart".to_string(),
+                    line: 12,
+                    column: 9,
+                    name: None,
+                }
+            )]
+        );
+    }
 }
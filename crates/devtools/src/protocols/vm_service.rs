@@ -1268,6 +1268,14 @@ pub struct ProcessMemoryItem {
 pub struct ReloadReport {
     pub r#type: String,
     pub success: bool,
+    #[serde(default)]
+    pub notices: Vec<ReloadNotice>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReloadNotice {
+    pub r#type: String,
+    pub message: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -9,7 +9,7 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{broadcast, mpsc, Mutex};
+use tokio::sync::{broadcast, broadcast::error::RecvError, mpsc, watch, Mutex};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use uuid::Uuid;
 
@@ -18,6 +18,27 @@ pub struct VmService {
     _incoming_rx: broadcast::Receiver<String>,
     outgoing_tx: mpsc::Sender<String>,
     outgoing_rx: Arc<Mutex<mpsc::Receiver<String>>>,
+    closed_tx: watch::Sender<bool>,
+}
+
+/// Converts the http uri printed by `flutter run` and DevTools (e.g.
+/// `http://127.0.0.1:8181/abcd=/`) to the websocket endpoint of the vm service.
+pub fn websocket_uri(uri: &str) -> String {
+    let uri = uri.trim();
+    let uri = if let Some(rest) = uri.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else if let Some(rest) = uri.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else {
+        uri.to_string()
+    };
+    if uri.ends_with("/ws") {
+        uri
+    } else if uri.ends_with('/') {
+        format!("{}ws", uri)
+    } else {
+        format!("{}/ws", uri)
+    }
 }
 
 impl Default for VmService {
@@ -30,29 +51,41 @@ impl VmService {
     pub fn new() -> Self {
         let (incoming_tx, _incoming_rx) = broadcast::channel::<String>(16);
         let (outgoing_tx, outgoing_rx) = mpsc::channel::<String>(16);
+        let (closed_tx, _) = watch::channel(false);
 
         Self {
             incoming_tx,
             _incoming_rx,
             outgoing_tx,
             outgoing_rx: Arc::new(Mutex::new(outgoing_rx)),
+            closed_tx,
         }
     }
 
-    pub async fn connect(&self, uri: String) {
+    pub async fn connect(&self, uri: String) -> Result<()> {
         let _incoming_tx = self.incoming_tx.clone();
         let _outgoing_rx = self.outgoing_rx.clone();
-        let Ok((stream, _)) = connect_async(uri).await else {
-            return;
+        let _closed_tx = self.closed_tx.clone();
+        let mut closed_rx = self.closed_tx.subscribe();
+        let (stream, _) = match connect_async(websocket_uri(&uri)).await {
+            Ok(connection) => connection,
+            Err(err) => {
+                self.closed_tx.send_replace(true);
+                bail!("Failed to connect to vm service: {}", err);
+            }
         };
         let (mut write, mut read) = stream.split();
         tokio::spawn(async move {
             let mut _outgoing_rx = _outgoing_rx.lock().await;
             loop {
                 tokio::select! {
-                    Some(Ok(Message::Text(next))) = read.next() => {
-                        log::info!("[<====] {}", next);
-                        _incoming_tx.send(next).unwrap();
+                    next = read.next() => match next {
+                        Some(Ok(Message::Text(next))) => {
+                            log::info!("[<====] {}", next);
+                            _incoming_tx.send(next).unwrap();
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(_)) | None => break,
                     },
                     Some(text) = _outgoing_rx.recv() => {
                         log::info!("[====>] {}", text);
@@ -60,9 +93,44 @@ impl VmService {
                             log::error!("Error sending message: {:?}", e);
                         };
                     },
+                    _ = closed_rx.changed() => {
+                        let _ = write.close().await;
+                        break;
+                    },
                 };
             }
+            _closed_tx.send_replace(true);
         });
+        Ok(())
+    }
+
+    /// Closes the connection to the vm service.
+    pub fn disconnect(&self) {
+        self.closed_tx.send_replace(true);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        *self.closed_tx.borrow()
+    }
+
+    /// Waits until the connection is closed, either by the app or by `disconnect`.
+    pub async fn wait_closed(&self) {
+        let mut closed_rx = self.closed_tx.subscribe();
+        while !*closed_rx.borrow_and_update() {
+            if closed_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Subscribes to the events of the stream. Unlike `next_event`, which subscribes on every
+    /// call, no event is missed between two calls of `EventReceiver::recv`.
+    pub fn subscribe_events(&self, stream_id: StreamId) -> EventReceiver {
+        EventReceiver {
+            stream_id,
+            rx: self.incoming_tx.subscribe(),
+            closed: self.closed_tx.subscribe(),
+        }
     }
 
     pub async fn next_event(&self, stream_id: StreamId) -> Result<Event> {
@@ -179,6 +247,40 @@ pub struct VmServiceEventParams {
     pub event: Event,
 }
 
+pub struct EventReceiver {
+    stream_id: StreamId,
+    rx: broadcast::Receiver<String>,
+    closed: watch::Receiver<bool>,
+}
+
+impl EventReceiver {
+    /// Waits for the next event of the stream. Fails once the connection is closed and every
+    /// event received before has been returned.
+    pub async fn recv(&mut self) -> Result<Event> {
+        loop {
+            tokio::select! {
+                biased;
+                line = self.rx.recv() => match line {
+                    Ok(line) => {
+                        let Ok(res) = serde_json::from_str::<VmServiceEvent>(&line) else {
+                            continue;
+                        };
+                        if res.method == "streamNotify" && res.params.stream_id == self.stream_id {
+                            return Ok(res.params.event);
+                        }
+                    }
+                    Err(RecvError::Lagged(count)) => {
+                        log::warn!("Missed {} messages from the vm service", count);
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                _ = self.closed.wait_for(|closed| *closed) => break,
+            }
+        }
+        Err(eyre!("The vm service connection is closed"))
+    }
+}
+
 impl VmServiceProtocol for VmService {
     async fn add_breakpoint(
         &self,
@@ -781,3 +883,56 @@ impl VmServiceProtocol for VmService {
         self.call("streamListen", params).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use tokio::sync::{broadcast, watch};
+
+    use super::{websocket_uri, EventReceiver};
+    use crate::protocols::vm_service::{EventKind, StreamId};
+
+    #[test]
+    fn convert_to_websocket_uri() {
+        assert_eq!(
+            websocket_uri("http://127.0.0.1:8181/abcd=/"),
+            "ws://127.0.0.1:8181/abcd=/ws"
+        );
+        assert_eq!(
+            websocket_uri("https://127.0.0.1:8181/abcd="),
+            "wss://127.0.0.1:8181/abcd=/ws"
+        );
+        assert_eq!(
+            websocket_uri("ws://127.0.0.1:8181/abcd=/ws"),
+            "ws://127.0.0.1:8181/abcd=/ws"
+        );
+    }
+
+    #[tokio::test]
+    async fn receive_stream_events_sent_before_close() {
+        let (tx, rx) = broadcast::channel::<String>(16);
+        let (closed_tx, closed) = watch::channel(false);
+        let mut events = EventReceiver {
+            stream_id: StreamId::Extension,
+            rx,
+            closed,
+        };
+
+        let event = |stream_id: &str, kind: &str| {
+            format!(
+                r#"{{"jsonrpc":"2.0","method":"streamNotify","params":{{"streamId":"{}","event":{{"type":"Event","kind":"{}","timestamp":1}}}}}}"#,
+                stream_id, kind
+            )
+        };
+        tx.send(r#"{"jsonrpc":"2.0","id":"1","result":{"type":"Success"}}"#.to_string())
+            .unwrap();
+        tx.send(event("Isolate", "IsolateStart")).unwrap();
+        tx.send(event("Extension", "Extension")).unwrap();
+        tx.send(event("Extension", "Extension")).unwrap();
+        closed_tx.send(true).unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(events.recv().await.unwrap().kind, EventKind::Extension);
+        }
+        assert!(events.recv().await.is_err());
+    }
+}
@@ -15,15 +15,23 @@ pub fn get_schemes(project_root: String) -> Result<Option<Vec<String>>> {
     let output = String::from_utf8(output.stdout)
         .map_err(|e| eyre!("failed to convert bytes to String: {}", e))?;
 
-    let info = XcodeProjectInfo::new_from_xcode_build_output(&output);
+    let schemes = parse_schemes(&output);
 
-    if info.schemes.is_empty() || info.schemes.contains(&"Runner") {
+    if schemes.is_empty() {
         return Ok(None);
     }
 
-    Ok(Some(
-        info.schemes.into_iter().map(|s| s.to_string()).collect(),
-    ))
+    Ok(Some(schemes))
+}
+
+/// Schemes other than the default `Runner` scheme, which correspond to flavors.
+fn parse_schemes(output: &str) -> Vec<String> {
+    let info = XcodeProjectInfo::new_from_xcode_build_output(output);
+    info.schemes
+        .into_iter()
+        .filter(|scheme| *scheme != "Runner")
+        .map(|scheme| scheme.to_string())
+        .collect()
 }
 
 pub struct XcodeProjectInfo<'a> {
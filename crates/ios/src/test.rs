@@ -15,3 +15,14 @@ fn get_xcode_project_info() {
     assert!(!info.targets.contains(&"Scheme3"));
     assert!(!info.targets.contains(&"Undefined"));
 }
+
+#[test]
+fn parse_schemes() {
+    let contents = include_str!("./test/xcodebuild.txt");
+    let schemes = super::parse_schemes(contents);
+    assert_eq!(schemes, vec!["Scheme1", "Scheme2", "Scheme3"]);
+
+    let contents = contents.replace("Scheme1", "Runner");
+    let schemes = super::parse_schemes(&contents);
+    assert_eq!(schemes, vec!["Scheme2", "Scheme3"]);
+}
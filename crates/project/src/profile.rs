@@ -0,0 +1,319 @@
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use toml_edit::{Array, ArrayOfTables, DocumentMut, Item, Table, Value};
+
+/// A named launch profile in `flx.toml`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct LaunchProfile {
+    pub name: String,
+    /// The main entry point, passed as `--target`.
+    pub target: Option<String>,
+    pub flavor: Option<String>,
+    /// One of `debug`, `profile` or `release`.
+    pub mode: Option<String>,
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub dart_defines: BTreeMap<String, String>,
+    pub dart_define_from_file: Option<String>,
+    /// Device id or name to run on. The flutter tool also accepts a prefix of them.
+    pub device: Option<String>,
+    pub env: Option<HashMap<String, String>>,
+    /// Shell commands run in the working directory before launching, e.g. `build_runner`.
+    #[serde(default)]
+    pub pre_launch: Vec<String>,
+    /// Any other arguments passed to `flutter run`.
+    #[serde(default)]
+    pub tool_args: Vec<String>,
+}
+
+impl LaunchProfile {
+    /// Arguments for `flutter run` other than the target, mode and device.
+    pub fn flutter_tool_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(flavor) = &self.flavor {
+            args.push("--flavor".to_string());
+            args.push(flavor.clone());
+        }
+        for (key, value) in &self.dart_defines {
+            args.push(format!("--dart-define={}={}", key, value));
+        }
+        if let Some(file) = &self.dart_define_from_file {
+            args.push(format!("--dart-define-from-file={}", file));
+        }
+        args.extend(self.tool_args.clone());
+        args
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct FlxToml {
+    #[serde(default)]
+    pub profiles: Vec<LaunchProfile>,
+}
+
+pub fn parse_launch_profiles(toml: &str) -> Result<Vec<LaunchProfile>> {
+    let flx_toml: FlxToml = toml::from_str(toml)?;
+    Ok(flx_toml.profiles)
+}
+
+/// Values of a profile edited in flx. `None` removes the key from the profile.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LaunchProfileChanges {
+    pub name: String,
+    pub target: Option<String>,
+    pub mode: Option<String>,
+    pub cwd: Option<String>,
+    pub tool_args: Option<Vec<String>>,
+}
+
+/// Writes `changes` to the profile at `index` of `flx.toml`, or appends a new profile when
+/// `index` is `None`. Comments and other keys are kept as they are.
+pub fn save_launch_profile(
+    toml: &str,
+    index: Option<usize>,
+    changes: &LaunchProfileChanges,
+) -> Result<String> {
+    let mut document = toml.parse::<DocumentMut>()?;
+    let profiles = document
+        .entry("profiles")
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or_else(|| eyre!("profiles in flx.toml is not an array of tables"))?;
+    let profile = match index {
+        Some(index) => profiles
+            .get_mut(index)
+            .ok_or_else(|| eyre!("Launch profile {} not found", index))?,
+        None => {
+            profiles.push(Table::new());
+            profiles.get_mut(profiles.len() - 1).unwrap()
+        }
+    };
+
+    let values = [
+        ("name", Some(Value::from(changes.name.clone()))),
+        ("target", changes.target.clone().map(Value::from)),
+        ("mode", changes.mode.clone().map(Value::from)),
+        ("cwd", changes.cwd.clone().map(Value::from)),
+        (
+            "tool_args",
+            changes
+                .tool_args
+                .clone()
+                .map(|args| Value::from(args.into_iter().collect::<Array>())),
+        ),
+    ];
+    for (key, new_value) in values {
+        match (
+            profile.get_mut(key).and_then(|item| item.as_value_mut()),
+            new_value,
+        ) {
+            // Keep the comments around the value.
+            (Some(current), Some(mut new_value)) => {
+                *new_value.decor_mut() = current.decor().clone();
+                *current = new_value;
+            }
+            (None, Some(new_value)) => profile[key] = Item::Value(new_value),
+            (_, None) => {
+                profile.remove(key);
+            }
+        }
+    }
+    Ok(document.to_string())
+}
+
+/// Appends a copy of the profile at `index` with all of its keys and subtables, and returns the
+/// index of the copy.
+pub fn duplicate_launch_profile(toml: &str, index: usize) -> Result<(String, usize)> {
+    let mut document = toml.parse::<DocumentMut>()?;
+    // Tables are written in the order of their positions, so the copy has to come after every
+    // table of the document to end up at the end of it.
+    let mut next_position = max_position(document.as_table()).map_or(0, |p| p + 1);
+    let profiles = document
+        .get_mut("profiles")
+        .and_then(|profiles| profiles.as_array_of_tables_mut())
+        .ok_or_else(|| eyre!("profiles not found in flx.toml"))?;
+    let mut profile = profiles
+        .get(index)
+        .ok_or_else(|| eyre!("Launch profile {} not found", index))?
+        .clone();
+    set_positions(&mut profile, &mut next_position);
+    profiles.push(profile);
+    let index = profiles.len() - 1;
+    Ok((document.to_string(), index))
+}
+
+fn max_position(table: &Table) -> Option<usize> {
+    let children = table.iter().flat_map(|(_, item)| match item {
+        Item::Table(table) => vec![max_position(table)],
+        Item::ArrayOfTables(tables) => tables.iter().map(max_position).collect(),
+        _ => vec![],
+    });
+    children.chain([table.position()]).flatten().max()
+}
+
+fn set_positions(table: &mut Table, next_position: &mut usize) {
+    table.set_position(*next_position);
+    *next_position += 1;
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Table(table) => set_positions(table, next_position),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    set_positions(table, next_position);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Removes the profile at `index` from `flx.toml`.
+pub fn remove_launch_profile(toml: &str, index: usize) -> Result<String> {
+    let mut document = toml.parse::<DocumentMut>()?;
+    let profiles = document
+        .get_mut("profiles")
+        .and_then(|profiles| profiles.as_array_of_tables_mut())
+        .ok_or_else(|| eyre!("profiles not found in flx.toml"))?;
+    if index >= profiles.len() {
+        return Err(eyre!("Launch profile {} not found", index));
+    }
+    profiles.remove(index);
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::LaunchProfile;
+
+    #[test]
+    fn parse_launch_profiles() {
+        let contents = include_str!("./test/flx.toml");
+        let result = super::parse_launch_profiles(contents).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0],
+            LaunchProfile {
+                name: "Development".to_string(),
+                target: Some("lib/main_development.dart".to_string()),
+                flavor: Some("development".to_string()),
+                mode: None,
+                cwd: None,
+                dart_defines: BTreeMap::from([
+                    ("API_URL".to_string(), "https://dev.example.com".to_string()),
+                    ("FEATURE_X".to_string(), "true".to_string()),
+                ]),
+                dart_define_from_file: Some("config/development.json".to_string()),
+                device: Some("iPhone 15".to_string()),
+                env: Some(HashMap::from([(
+                    "API_KEY".to_string(),
+                    "secret".to_string()
+                )])),
+                pre_launch: vec![
+                    "dart run build_runner build --delete-conflicting-outputs".to_string()
+                ],
+                tool_args: vec![],
+            }
+        );
+        assert_eq!(result[1].mode, Some("release".to_string()));
+        assert_eq!(result[1].cwd, Some("app".to_string()));
+    }
+
+    #[test]
+    fn flutter_tool_args() {
+        let contents = include_str!("./test/flx.toml");
+        let result = super::parse_launch_profiles(contents).unwrap();
+        assert_eq!(
+            result[0].flutter_tool_args(),
+            vec![
+                "--flavor",
+                "development",
+                "--dart-define=API_URL=https://dev.example.com",
+                "--dart-define=FEATURE_X=true",
+                "--dart-define-from-file=config/development.json",
+            ]
+        );
+        assert_eq!(
+            result[1].flutter_tool_args(),
+            vec!["--flavor", "production", "--no-pub"]
+        );
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(super::parse_launch_profiles("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn save_launch_profile() {
+        let contents = "# Launch profiles\n[[profiles]]\nname = \"Development\" # The default\nflavor = \"development\"\nmode = \"debug\"\n";
+        let changes = super::LaunchProfileChanges {
+            name: "Development".to_string(),
+            target: Some("lib/main.dart".to_string()),
+            mode: None,
+            cwd: None,
+            tool_args: Some(vec!["--no-pub".to_string()]),
+        };
+        let result = super::save_launch_profile(contents, Some(0), &changes).unwrap();
+        assert!(result.starts_with("# Launch profiles\n"));
+        assert!(result.contains("# The default"));
+        let profiles = super::parse_launch_profiles(&result).unwrap();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].flavor, Some("development".to_string()));
+        assert_eq!(profiles[0].target, Some("lib/main.dart".to_string()));
+        assert_eq!(profiles[0].mode, None);
+        assert_eq!(profiles[0].tool_args, vec!["--no-pub"]);
+
+        let changes = super::LaunchProfileChanges {
+            name: "Release".to_string(),
+            mode: Some("release".to_string()),
+            ..Default::default()
+        };
+        let result = super::save_launch_profile(&result, None, &changes).unwrap();
+        let profiles = super::parse_launch_profiles(&result).unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles[1].name, "Release");
+        assert_eq!(profiles[1].mode, Some("release".to_string()));
+
+        let result = super::save_launch_profile("", None, &changes).unwrap();
+        assert_eq!(super::parse_launch_profiles(&result).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn remove_launch_profile() {
+        let contents = include_str!("./test/flx.toml");
+        let result = super::remove_launch_profile(contents, 0).unwrap();
+        let profiles = super::parse_launch_profiles(&result).unwrap();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].name, "Production");
+    }
+
+    #[test]
+    fn duplicate_launch_profile() {
+        let contents = include_str!("./test/flx.toml");
+        let (result, index) = super::duplicate_launch_profile(contents, 0).unwrap();
+        assert_eq!(index, 2);
+        let profiles = super::parse_launch_profiles(&result).unwrap();
+        assert_eq!(profiles.len(), 3);
+        assert_eq!(profiles[2], profiles[0]);
+        assert!(result.starts_with(contents.trim_end()));
+        assert!(result.trim_end().ends_with(
+            r#"[[profiles]]
+name = "Development"
+target = "lib/main_development.dart"
+flavor = "development"
+dart_define_from_file = "config/development.json"
+device = "iPhone 15"
+pre_launch = ["dart run build_runner build --delete-conflicting-outputs"]
+
+[profiles.dart_defines]
+API_URL = "https://dev.example.com"
+FEATURE_X = "true"
+
+[profiles.env]
+API_KEY = "secret""#
+        ));
+    }
+}
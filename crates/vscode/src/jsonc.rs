@@ -0,0 +1,279 @@
+//! Minimal scanner for JSON with comments and trailing commas, used to edit `launch.json` in
+//! place without losing the comments and formatting around the edited values.
+
+use color_eyre::{eyre::eyre, Result};
+
+/// A member of an object. All positions are byte offsets into the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub key: String,
+    pub key_start: usize,
+    pub value_start: usize,
+    pub value_end: usize,
+}
+
+/// An element of an array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub start: usize,
+    pub end: usize,
+}
+
+pub fn skip_whitespace_and_comments(s: &str, mut i: usize) -> usize {
+    let bytes = s.as_bytes();
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() {
+            i += 1;
+        } else if s[i..].starts_with("//") {
+            i = s[i..].find('\n').map(|n| i + n + 1).unwrap_or(s.len());
+        } else if s[i..].starts_with("/*") {
+            i = s[i + 2..].find("*/").map(|n| i + n + 4).unwrap_or(s.len());
+        } else {
+            break;
+        }
+    }
+    i
+}
+
+fn scan_string(s: &str, start: usize) -> Result<usize> {
+    let bytes = s.as_bytes();
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' {
+            i += 2;
+        } else if bytes[i] == quote {
+            return Ok(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+    Err(eyre!("Unterminated string at {}", start))
+}
+
+/// Returns the end of the value which starts at `start`.
+pub fn scan_value(s: &str, start: usize) -> Result<usize> {
+    let bytes = s.as_bytes();
+    match bytes.get(start) {
+        Some(b'"') | Some(b'\'') => scan_string(s, start),
+        Some(b'{') | Some(b'[') => {
+            let mut depth = 0;
+            let mut i = start;
+            while i < bytes.len() {
+                i = skip_whitespace_and_comments(s, i);
+                match bytes.get(i) {
+                    Some(b'"') | Some(b'\'') => i = scan_string(s, i)?,
+                    Some(b'{') | Some(b'[') => {
+                        depth += 1;
+                        i += 1;
+                    }
+                    Some(b'}') | Some(b']') => {
+                        depth -= 1;
+                        i += 1;
+                        if depth == 0 {
+                            return Ok(i);
+                        }
+                    }
+                    Some(_) => i += 1,
+                    None => break,
+                }
+            }
+            Err(eyre!("Unterminated value at {}", start))
+        }
+        Some(_) => {
+            let mut i = start;
+            while i < bytes.len()
+                && !matches!(bytes[i], b',' | b'}' | b']' | b'/')
+                && !bytes[i].is_ascii_whitespace()
+            {
+                i += 1;
+            }
+            Ok(i)
+        }
+        None => Err(eyre!("Unexpected end of input")),
+    }
+}
+
+/// Returns the members of the object which starts at `start`.
+pub fn object_members(s: &str, start: usize) -> Result<Vec<Member>> {
+    let bytes = s.as_bytes();
+    if bytes.get(start) != Some(&b'{') {
+        return Err(eyre!("Expected an object at {}", start));
+    }
+    let mut members = vec![];
+    let mut i = start + 1;
+    loop {
+        i = skip_whitespace_and_comments(s, i);
+        match bytes.get(i) {
+            Some(b'}') => return Ok(members),
+            Some(b',') => {
+                i += 1;
+                continue;
+            }
+            None => return Err(eyre!("Unterminated object at {}", start)),
+            _ => {}
+        }
+        let key_start = i;
+        let key = if matches!(bytes[i], b'"' | b'\'') {
+            i = scan_string(s, i)?;
+            s[key_start + 1..i - 1].to_string()
+        } else {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            s[key_start..i].to_string()
+        };
+        i = skip_whitespace_and_comments(s, i);
+        if bytes.get(i) != Some(&b':') {
+            return Err(eyre!("Expected ':' at {}", i));
+        }
+        let value_start = skip_whitespace_and_comments(s, i + 1);
+        let value_end = scan_value(s, value_start)?;
+        members.push(Member {
+            key,
+            key_start,
+            value_start,
+            value_end,
+        });
+        i = value_end;
+    }
+}
+
+/// Returns the elements of the array which starts at `start`.
+pub fn array_elements(s: &str, start: usize) -> Result<Vec<Element>> {
+    let bytes = s.as_bytes();
+    if bytes.get(start) != Some(&b'[') {
+        return Err(eyre!("Expected an array at {}", start));
+    }
+    let mut elements = vec![];
+    let mut i = start + 1;
+    loop {
+        i = skip_whitespace_and_comments(s, i);
+        match bytes.get(i) {
+            Some(b']') => return Ok(elements),
+            Some(b',') => {
+                i += 1;
+                continue;
+            }
+            None => return Err(eyre!("Unterminated array at {}", start)),
+            _ => {}
+        }
+        let end = scan_value(s, i)?;
+        elements.push(Element { start: i, end });
+        i = end;
+    }
+}
+
+/// Returns the indentation of the line which contains `position`.
+pub fn indentation_at(s: &str, position: usize) -> String {
+    let line_start = s[..position].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    s[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+/// Returns the ranges to remove for the item spanning `start..end` in an object or array, with
+/// its separating comma and the rest of its line, including a `//` comment after it. The ranges
+/// are ordered from the end of `s`, so that they can be removed one after another.
+pub fn removal_ranges(
+    s: &str,
+    start: usize,
+    end: usize,
+    previous_end: Option<usize>,
+) -> Vec<(usize, usize)> {
+    let bytes = s.as_bytes();
+    let line_start = s[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let own_line = s[line_start..start].trim().is_empty();
+    let after = skip_whitespace_and_comments(s, end);
+    if bytes.get(after) == Some(&b',') {
+        let start = if own_line { line_start } else { start };
+        let mut end = after + 1;
+        let line_end = s[end..].find('\n').map(|n| end + n).unwrap_or(s.len());
+        let rest_of_line = s[end..line_end].trim();
+        if rest_of_line.is_empty() || rest_of_line.starts_with("//") {
+            end = (line_end + 1).min(s.len());
+        }
+        vec![(start, end)]
+    } else if let Some(previous_end) = previous_end {
+        // The last item: remove the comma after the previous one instead.
+        let comma = s[previous_end..start]
+            .find(',')
+            .map(|n| previous_end + n)
+            .unwrap_or(previous_end);
+        let line_end = s[end..].find('\n').map(|n| end + n).unwrap_or(s.len());
+        let rest_of_line = s[end..line_end].trim();
+        if own_line && (rest_of_line.is_empty() || rest_of_line.starts_with("//")) {
+            // Keep the comment after the previous item, and the line break before the end of
+            // the object.
+            vec![(line_start.saturating_sub(1), line_end), (comma, comma + 1)]
+        } else {
+            vec![(previous_end, end)]
+        }
+    } else {
+        vec![(start, end)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan_members_with_comments() {
+        let s = r#"{
+            // comment
+            "a": [1, { "b": "}" }], /* comment */
+            c: 'x',
+        }"#;
+        let members = object_members(s, 0).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].key, "a");
+        assert_eq!(
+            &s[members[0].value_start..members[0].value_end],
+            r#"[1, { "b": "}" }]"#
+        );
+        assert_eq!(members[1].key, "c");
+        assert_eq!(&s[members[1].value_start..members[1].value_end], "'x'");
+
+        let elements = array_elements(s, members[0].value_start).unwrap();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(&s[elements[1].start..elements[1].end], r#"{ "b": "}" }"#);
+    }
+
+    fn remove_member(s: &str, key: &str) -> String {
+        let members = object_members(s, 0).unwrap();
+        let position = members.iter().position(|m| m.key == key).unwrap();
+        let member = &members[position];
+        let previous_end = position.checked_sub(1).map(|i| members[i].value_end);
+        let mut s = s.to_string();
+        for (start, end) in removal_ranges(&s, member.key_start, member.value_end, previous_end) {
+            s.replace_range(start..end, "");
+        }
+        s
+    }
+
+    #[test]
+    fn remove_members_with_trailing_comments() {
+        let s = r#"{
+    "a": 1, // about a
+    "b": 2, // about b
+    "c": 3 // about c
+}"#;
+        assert_eq!(
+            remove_member(s, "b"),
+            r#"{
+    "a": 1, // about a
+    "c": 3 // about c
+}"#
+        );
+        assert_eq!(
+            remove_member(s, "c"),
+            r#"{
+    "a": 1, // about a
+    "b": 2 // about b
+}"#
+        );
+        assert_eq!(remove_member(r#"{ "a": 1, "b": 2 }"#, "b"), r#"{ "a": 1 }"#);
+    }
+}
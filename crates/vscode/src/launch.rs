@@ -1,5 +1,19 @@
-use color_eyre::Result;
-use serde::{Deserialize, Serialize};
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::jsonc::{
+    array_elements, indentation_at, object_members, removal_ranges, skip_whitespace_and_comments,
+};
+
+const DEFAULT_LAUNCH_JSON: &str = r#"{
+    "version": "0.2.0",
+    "configurations": []
+}
+"#;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum VSCodeLaunchConfigurationRequest {
@@ -19,6 +33,47 @@ pub struct VSCodeLaunchConfiguration {
     pub cwd: Option<String>,
     #[serde(rename = "flutterMode")]
     pub flutter_mode: Option<String>,
+    #[serde(rename = "vmServiceUri", alias = "observatoryUri")]
+    pub vm_service_uri: Option<String>,
+    #[serde(rename = "appId")]
+    pub app_id: Option<String>,
+    /// Arguments passed to the flutter tool, e.g. `--dart-define-from-file`.
+    #[serde(rename = "toolArgs")]
+    pub tool_args: Option<Vec<String>>,
+    pub env: Option<HashMap<String, String>>,
+    #[serde(rename = "deviceId")]
+    pub device_id: Option<String>,
+    pub console: Option<String>,
+    /// Paths which this configuration is a template for. Templates are not launched by
+    /// themselves, but are merged into the configurations of the programs under these paths.
+    #[serde(
+        rename = "templateFor",
+        default,
+        deserialize_with = "deserialize_template_for"
+    )]
+    pub template_for: Option<Vec<String>>,
+}
+
+/// Dart-Code writes `templateFor` as a single path, but a list of paths is accepted too.
+fn deserialize_template_for<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TemplateFor {
+        Path(String),
+        Paths(Vec<String>),
+    }
+
+    Ok(
+        Option::<TemplateFor>::deserialize(deserializer)?.map(|template_for| match template_for {
+            TemplateFor::Path(path) => vec![path],
+            TemplateFor::Paths(paths) => paths,
+        }),
+    )
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -32,8 +87,353 @@ pub fn parse_launch_configuration(json: &str) -> Result<Vec<VSCodeLaunchConfigur
     Ok(json.configurations)
 }
 
+/// Values of a configuration edited in flx. `None` removes the attribute from the configuration.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LaunchConfigurationChanges {
+    pub name: String,
+    pub program: Option<String>,
+    pub flutter_mode: Option<String>,
+    /// Arguments passed to the app.
+    pub args: Option<Vec<String>>,
+    /// Arguments passed to `flutter run`.
+    pub tool_args: Option<Vec<String>>,
+    pub cwd: Option<String>,
+}
+
+impl LaunchConfigurationChanges {
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        let string = |value: &String| serde_json::to_string(value).unwrap_or_default();
+        let array = |values: &Vec<String>| {
+            format!(
+                "[{}]",
+                values.iter().map(string).collect::<Vec<_>>().join(", ")
+            )
+        };
+        vec![
+            ("name", Some(string(&self.name))),
+            ("program", self.program.as_ref().map(string)),
+            ("flutterMode", self.flutter_mode.as_ref().map(string)),
+            ("cwd", self.cwd.as_ref().map(string)),
+            ("args", self.args.as_ref().map(array)),
+            ("toolArgs", self.tool_args.as_ref().map(array)),
+        ]
+    }
+}
+
+/// Writes `changes` to the configuration at `index` of `launch.json`, or appends a new
+/// configuration when `index` is `None`. Comments and other attributes are kept as they are.
+pub fn save_launch_configuration(
+    json: &str,
+    index: Option<usize>,
+    changes: &LaunchConfigurationChanges,
+) -> Result<String> {
+    let mut json = if json.trim().is_empty() {
+        DEFAULT_LAUNCH_JSON.to_string()
+    } else {
+        json.to_string()
+    };
+    let array_start = configurations_start(&json)?;
+    let elements = array_elements(&json, array_start)?;
+
+    let Some(index) = index else {
+        let indentation = match elements.last() {
+            Some(last) => indentation_at(&json, last.start),
+            None => format!("{}    ", indentation_at(&json, array_start)),
+        };
+        let mut values = changes
+            .values()
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect::<Vec<_>>();
+        values.insert(1, ("request", "\"launch\"".to_string()));
+        values.insert(2, ("type", "\"dart\"".to_string()));
+        let members = values
+            .into_iter()
+            .map(|(key, value)| format!("{}    \"{}\": {}", indentation, key, value))
+            .collect::<Vec<_>>();
+        let object = format!("{{\n{}\n{}}}", members.join(",\n"), indentation);
+        match elements.last() {
+            Some(last) => insert_after(&mut json, last.end, &indentation, &object),
+            None => {
+                let insert_at = array_start + 1;
+                let text = format!(
+                    "\n{}{}\n{}",
+                    indentation,
+                    object,
+                    indentation_at(&json, array_start)
+                );
+                let close = skip_whitespace_and_comments(&json, insert_at);
+                json.replace_range(insert_at..close, "");
+                json.insert_str(insert_at, &text);
+            }
+        }
+        return Ok(json);
+    };
+
+    let element = elements
+        .get(index)
+        .ok_or_else(|| eyre!("Launch configuration {} not found", index))?;
+    let object_start = element.start;
+    for (key, value) in changes.values() {
+        json = set_member(&json, object_start, key, value)?;
+    }
+    Ok(json)
+}
+
+/// Appends a copy of the configuration at `index` with all of its attributes and comments, and
+/// returns the index of the copy.
+pub fn duplicate_launch_configuration(json: &str, index: usize) -> Result<(String, usize)> {
+    let array_start = configurations_start(json)?;
+    let elements = array_elements(json, array_start)?;
+    let element = elements
+        .get(index)
+        .ok_or_else(|| eyre!("Launch configuration {} not found", index))?;
+    let last = &elements[elements.len() - 1];
+    let indentation = indentation_at(json, last.start);
+    let object = json[element.start..element.end].to_string();
+    let mut json = json.to_string();
+    insert_after(&mut json, last.end, &indentation, &object);
+    Ok((json, elements.len()))
+}
+
+/// Inserts `object` as the next element after the element ending at `end`.
+fn insert_after(json: &mut String, end: usize, indentation: &str, object: &str) {
+    let after = skip_whitespace_and_comments(json, end);
+    if json.as_bytes().get(after) == Some(&b',') {
+        json.insert_str(after + 1, &format!("\n{}{},", indentation, object));
+    } else {
+        json.insert_str(end, &format!(",\n{}{}", indentation, object));
+    }
+}
+
+/// Removes the configuration at `index` from `launch.json`.
+pub fn remove_launch_configuration(json: &str, index: usize) -> Result<String> {
+    let array_start = configurations_start(json)?;
+    let elements = array_elements(json, array_start)?;
+    let element = elements
+        .get(index)
+        .ok_or_else(|| eyre!("Launch configuration {} not found", index))?;
+    let previous_end = index.checked_sub(1).map(|i| elements[i].end);
+    let mut json = json.to_string();
+    for (start, end) in removal_ranges(&json, element.start, element.end, previous_end) {
+        json.replace_range(start..end, "");
+    }
+    Ok(json)
+}
+
+/// Compares a value as it is written in `launch.json` with a value formatted as JSON.
+fn same_value(current: &str, value: &str) -> bool {
+    match (
+        json5::from_str::<serde_json::Value>(current),
+        serde_json::from_str::<serde_json::Value>(value),
+    ) {
+        (Ok(current), Ok(value)) => current == value,
+        _ => false,
+    }
+}
+
+fn configurations_start(json: &str) -> Result<usize> {
+    let start = skip_whitespace_and_comments(json, 0);
+    let members = object_members(json, start)?;
+    let member = members
+        .iter()
+        .find(|m| m.key == "configurations")
+        .ok_or_else(|| eyre!("configurations not found in launch.json"))?;
+    Ok(member.value_start)
+}
+
+/// Sets the value of `key` in the object at `object_start`, or removes it when `value` is `None`.
+/// A value which doesn't change is left as it is written, with its formatting and comments.
+fn set_member(json: &str, object_start: usize, key: &str, value: Option<String>) -> Result<String> {
+    let members = object_members(json, object_start)?;
+    let mut json = json.to_string();
+    let position = members.iter().position(|m| m.key == key);
+    match (position, value) {
+        (Some(position), Some(value)) => {
+            let member = &members[position];
+            let current = &json[member.value_start..member.value_end];
+            if !same_value(current, &value) {
+                json.replace_range(member.value_start..member.value_end, &value);
+            }
+        }
+        (Some(position), None) => {
+            let member = &members[position];
+            let previous_end = position.checked_sub(1).map(|i| members[i].value_end);
+            for (start, end) in
+                removal_ranges(&json, member.key_start, member.value_end, previous_end)
+            {
+                json.replace_range(start..end, "");
+            }
+        }
+        (None, Some(value)) => {
+            let Some(last) = members.last() else {
+                let indentation = indentation_at(&json, object_start);
+                json.insert_str(
+                    object_start + 1,
+                    &format!(
+                        "\n{}    \"{}\": {}\n{}",
+                        indentation, key, value, indentation
+                    ),
+                );
+                return Ok(json);
+            };
+            let indentation = indentation_at(&json, last.key_start);
+            let after = skip_whitespace_and_comments(&json, last.value_end);
+            if json.as_bytes().get(after) == Some(&b',') {
+                json.insert_str(
+                    after + 1,
+                    &format!("\n{}\"{}\": {},", indentation, key, value),
+                );
+            } else {
+                json.insert_str(
+                    last.value_end,
+                    &format!(",\n{}\"{}\": {}", indentation, key, value),
+                );
+            }
+        }
+        (None, None) => {}
+    }
+    Ok(json)
+}
+
+/// Substitutes the variables in the configurations and merges the templates into the
+/// configurations they apply to. Templates themselves are removed from the result.
+pub fn resolve_launch_configurations(
+    configurations: Vec<VSCodeLaunchConfiguration>,
+    workspace_folder: &Path,
+) -> Vec<VSCodeLaunchConfiguration> {
+    let configurations = configurations
+        .into_iter()
+        .map(|c| substitute_configuration(c, workspace_folder))
+        .collect::<Vec<_>>();
+    let (templates, configurations): (Vec<_>, Vec<_>) = configurations
+        .into_iter()
+        .partition(|c| c.template_for.is_some());
+
+    configurations
+        .into_iter()
+        .map(|configuration| {
+            let templates = templates
+                .iter()
+                .filter(|template| is_template_for(template, &configuration, workspace_folder))
+                .collect::<Vec<_>>();
+            templates.into_iter().fold(configuration, apply_template)
+        })
+        .collect()
+}
+
+/// `templateFor` is relative to the workspace folder, while `program` and `cwd` may be absolute
+/// after `${workspaceFolder}` is substituted, so both are compared relative to the folder.
+fn is_template_for(
+    template: &VSCodeLaunchConfiguration,
+    configuration: &VSCodeLaunchConfiguration,
+    workspace_folder: &Path,
+) -> bool {
+    let relative = |path: PathBuf| match path.strip_prefix(workspace_folder) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => path,
+    };
+    let path = relative(
+        Path::new(configuration.cwd.as_deref().unwrap_or_default())
+            .join(configuration.program.as_deref().unwrap_or_default()),
+    );
+    template.template_for.iter().flatten().any(|prefix| {
+        let prefix = substitute_variables(prefix, workspace_folder);
+        path.starts_with(relative(PathBuf::from(prefix.trim_start_matches("./"))))
+    })
+}
+
+fn apply_template(
+    configuration: VSCodeLaunchConfiguration,
+    template: &VSCodeLaunchConfiguration,
+) -> VSCodeLaunchConfiguration {
+    let concat = |template: &Option<Vec<String>>, values: Option<Vec<String>>| match (
+        template.clone(),
+        values,
+    ) {
+        (Some(template), Some(values)) => Some([template, values].concat()),
+        (template, values) => values.or(template),
+    };
+    let env = match (template.env.clone(), configuration.env) {
+        (Some(mut template), Some(env)) => {
+            template.extend(env);
+            Some(template)
+        }
+        (template, env) => env.or(template),
+    };
+    VSCodeLaunchConfiguration {
+        args: concat(&template.args, configuration.args),
+        tool_args: concat(&template.tool_args, configuration.tool_args),
+        env,
+        flutter_mode: configuration
+            .flutter_mode
+            .or_else(|| template.flutter_mode.clone()),
+        device_id: configuration
+            .device_id
+            .or_else(|| template.device_id.clone()),
+        console: configuration.console.or_else(|| template.console.clone()),
+        ..configuration
+    }
+}
+
+fn substitute_configuration(
+    configuration: VSCodeLaunchConfiguration,
+    workspace_folder: &Path,
+) -> VSCodeLaunchConfiguration {
+    let substitute = |value: String| substitute_variables(&value, workspace_folder);
+    let substitute_all = |values: Option<Vec<String>>| {
+        values.map(|values| values.into_iter().map(substitute).collect())
+    };
+    VSCodeLaunchConfiguration {
+        program: configuration.program.map(substitute),
+        cwd: configuration.cwd.map(substitute),
+        args: substitute_all(configuration.args),
+        tool_args: substitute_all(configuration.tool_args),
+        env: configuration.env.map(|env| {
+            env.into_iter()
+                .map(|(key, value)| (key, substitute(value)))
+                .collect()
+        }),
+        vm_service_uri: configuration.vm_service_uri.map(substitute),
+        ..configuration
+    }
+}
+
+/// Replaces `${workspaceFolder}`, `${workspaceFolderBasename}`, `${userHome}` and `${env:NAME}`
+/// in `value`. Unknown variables are left as they are.
+pub fn substitute_variables(value: &str, workspace_folder: &Path) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let variable = &rest[start + 2..start + end];
+        let replacement = match variable {
+            "workspaceFolder" | "workspaceRoot" => Some(workspace_folder.display().to_string()),
+            "workspaceFolderBasename" => workspace_folder
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string()),
+            "userHome" => std::env::var("HOME").ok(),
+            _ => variable
+                .strip_prefix("env:")
+                .map(|name| std::env::var(name).unwrap_or_default()),
+        };
+        match replacement {
+            Some(replacement) => result.push_str(&replacement),
+            None => result.push_str(&rest[start..start + end + 1]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
 #[cfg(test)]
 mod test {
+    use std::{collections::HashMap, path::Path};
+
     use crate::launch::VSCodeLaunchConfiguration;
 
     #[test]
@@ -57,6 +457,13 @@ mod test {
                 ]),
                 cwd: None,
                 flutter_mode: None,
+                vm_service_uri: None,
+                app_id: None,
+                tool_args: None,
+                env: None,
+                device_id: None,
+                console: None,
+                template_for: None,
             }
         );
         assert_eq!(
@@ -74,6 +481,13 @@ mod test {
                 ]),
                 cwd: None,
                 flutter_mode: Some("release".to_string()),
+                vm_service_uri: None,
+                app_id: None,
+                tool_args: None,
+                env: None,
+                device_id: None,
+                console: None,
+                template_for: None,
             }
         );
         assert_eq!(
@@ -86,7 +500,365 @@ mod test {
                 args: None,
                 cwd: None,
                 flutter_mode: None,
+                vm_service_uri: None,
+                app_id: None,
+                tool_args: None,
+                env: None,
+                device_id: None,
+                console: None,
+                template_for: None,
             }
         )
     }
+
+    #[test]
+    fn parse_launch_attach() {
+        let contents = include_str!("./test/launch_attach.json");
+        let result = super::parse_launch_configuration(contents).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0],
+            VSCodeLaunchConfiguration {
+                name: "Attach".to_string(),
+                r#type: "dart".to_string(),
+                request: super::VSCodeLaunchConfigurationRequest::Attach,
+                program: None,
+                args: None,
+                cwd: None,
+                flutter_mode: None,
+                vm_service_uri: Some("ws://127.0.0.1:8181/abcd=/ws".to_string()),
+                app_id: Some("com.example.app".to_string()),
+                tool_args: None,
+                env: None,
+                device_id: None,
+                console: None,
+                template_for: None,
+            }
+        );
+        assert_eq!(
+            result[1].vm_service_uri,
+            Some("http://127.0.0.1:8182/efgh=/".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_launch_with_tool_args_and_env() {
+        let contents = include_str!("./test/launch_tool_args.json");
+        let result = super::parse_launch_configuration(contents).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[1].tool_args,
+            Some(vec![
+                "--dart-define-from-file".to_string(),
+                "${workspaceFolder}/config/dev.json".to_string(),
+            ])
+        );
+        assert_eq!(
+            result[1].env,
+            Some(HashMap::from([(
+                "API_KEY".to_string(),
+                "${env:FLX_TEST_API_KEY}".to_string()
+            )]))
+        );
+        assert_eq!(result[1].device_id, Some("macos".to_string()));
+        assert_eq!(result[1].console, Some("terminal".to_string()));
+        assert_eq!(result[0].template_for, Some(vec!["".to_string()]));
+    }
+
+    #[test]
+    fn resolve_launch_configurations() {
+        std::env::set_var("FLX_TEST_API_KEY", "secret");
+        let contents = include_str!("./test/launch_tool_args.json");
+        let configurations = super::parse_launch_configuration(contents).unwrap();
+        let result = super::resolve_launch_configurations(configurations, Path::new("/work/app"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].tool_args,
+            Some(vec![
+                "--verbose".to_string(),
+                "--dart-define-from-file".to_string(),
+                "/work/app/config/dev.json".to_string(),
+            ])
+        );
+        assert_eq!(
+            result[0].env,
+            Some(HashMap::from([
+                ("API_KEY".to_string(), "secret".to_string()),
+                ("LOG_LEVEL".to_string(), "debug".to_string()),
+            ]))
+        );
+        assert_eq!(result[1].tool_args, Some(vec!["--verbose".to_string()]));
+        assert_eq!(
+            result[1].env,
+            Some(HashMap::from([(
+                "LOG_LEVEL".to_string(),
+                "info".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn parse_template_for_as_path_or_paths() {
+        let contents = r#"{
+            "version": "0.2.0",
+            "configurations": [
+                {
+                    "name": "Foo",
+                    "type": "dart",
+                    "request": "launch",
+                    "templateFor": "lib/foo"
+                },
+                {
+                    "name": "Foo and bar",
+                    "type": "dart",
+                    "request": "launch",
+                    "templateFor": ["lib/foo", "lib/bar"]
+                },
+                {
+                    "name": "Launch",
+                    "type": "dart",
+                    "request": "launch",
+                    "program": "lib/foo/main.dart"
+                }
+            ]
+        }"#;
+        let result = super::parse_launch_configuration(contents).unwrap();
+        assert_eq!(result[0].template_for, Some(vec!["lib/foo".to_string()]));
+        assert_eq!(
+            result[1].template_for,
+            Some(vec!["lib/foo".to_string(), "lib/bar".to_string()])
+        );
+        assert_eq!(result[2].template_for, None);
+    }
+
+    #[test]
+    fn match_templates_with_workspace_folder_variable() {
+        let contents = r#"{
+            "version": "0.2.0",
+            "configurations": [
+                {
+                    "name": "Template",
+                    "type": "dart",
+                    "request": "launch",
+                    "templateFor": ["lib"],
+                    "toolArgs": ["--verbose"]
+                },
+                {
+                    "name": "Development",
+                    "type": "dart",
+                    "request": "launch",
+                    "program": "${workspaceFolder}/lib/main_development.dart"
+                },
+                {
+                    "name": "Gallery",
+                    "type": "dart",
+                    "request": "launch",
+                    "cwd": "${workspaceFolder}/gallery",
+                    "program": "lib/main.dart"
+                }
+            ]
+        }"#;
+        let configurations = super::parse_launch_configuration(contents).unwrap();
+        let result = super::resolve_launch_configurations(configurations, Path::new("/work/app"));
+        assert_eq!(
+            result[0].program,
+            Some("/work/app/lib/main_development.dart".to_string())
+        );
+        assert_eq!(result[0].tool_args, Some(vec!["--verbose".to_string()]));
+        assert_eq!(result[1].tool_args, None);
+    }
+
+    #[test]
+    fn substitute_variables() {
+        let workspace_folder = Path::new("/work/app");
+        assert_eq!(
+            super::substitute_variables("${workspaceFolder}/lib/main.dart", workspace_folder),
+            "/work/app/lib/main.dart"
+        );
+        assert_eq!(
+            super::substitute_variables("${workspaceFolderBasename}", workspace_folder),
+            "app"
+        );
+        assert_eq!(
+            super::substitute_variables("${unknown} ${env:FLX_TEST_UNDEFINED}", workspace_folder),
+            "${unknown} "
+        );
+    }
+
+    #[test]
+    fn save_launch_configuration() {
+        let contents = include_str!("./test/launch_edit.json");
+        let changes = super::LaunchConfigurationChanges {
+            name: "Development".to_string(),
+            program: Some("lib/main_development.dart".to_string()),
+            flutter_mode: Some("profile".to_string()),
+            args: None,
+            tool_args: Some(vec![
+                "--dart-define-from-file".to_string(),
+                "config.json".to_string(),
+            ]),
+            cwd: None,
+        };
+        let result = super::save_launch_configuration(contents, Some(0), &changes).unwrap();
+        assert_eq!(
+            result,
+            r#"{
+    // Comments are kept.
+    "version": "0.2.0",
+    "configurations": [
+        {
+            "name": "Development",
+            "request": "launch",
+            "type": "dart",
+            "program": "lib/main_development.dart", // The entry point.
+            "toolArgs": ["--dart-define-from-file", "config.json"],
+            "flutterMode": "profile"
+        },
+        /* The production app. */
+        {
+            "name": "Production",
+            "request": "launch",
+            "type": "dart",
+            "flutterMode": "release",
+        },
+    ]
+}
+"#
+        );
+
+        let changes = super::LaunchConfigurationChanges {
+            name: "Production".to_string(),
+            program: None,
+            flutter_mode: None,
+            args: Some(vec!["--verbose-logs".to_string()]),
+            tool_args: Some(vec!["--flavor".to_string(), "production".to_string()]),
+            cwd: None,
+        };
+        let result = super::save_launch_configuration(&result, Some(1), &changes).unwrap();
+        assert!(result.contains(
+            r#"            "type": "dart",
+            "args": ["--verbose-logs"],
+            "toolArgs": ["--flavor", "production"],
+        },"#
+        ));
+        assert!(!result.contains("release"));
+        assert_eq!(super::parse_launch_configuration(&result).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn add_launch_configuration() {
+        let contents = include_str!("./test/launch_edit.json");
+        let changes = super::LaunchConfigurationChanges {
+            name: "Staging".to_string(),
+            program: Some("lib/main.dart".to_string()),
+            flutter_mode: None,
+            args: None,
+            tool_args: Some(vec!["--flavor".to_string(), "staging".to_string()]),
+            cwd: None,
+        };
+        let result = super::save_launch_configuration(contents, None, &changes).unwrap();
+        assert!(result.contains(
+            r#"        },
+        {
+            "name": "Staging",
+            "request": "launch",
+            "type": "dart",
+            "program": "lib/main.dart",
+            "toolArgs": ["--flavor", "staging"]
+        },
+    ]"#
+        ));
+        let configurations = super::parse_launch_configuration(&result).unwrap();
+        assert_eq!(configurations.len(), 3);
+        assert_eq!(configurations[2].name, "Staging");
+
+        let result = super::save_launch_configuration("", None, &changes).unwrap();
+        let configurations = super::parse_launch_configuration(&result).unwrap();
+        assert_eq!(configurations.len(), 1);
+        assert_eq!(configurations[0].program, Some("lib/main.dart".to_string()));
+    }
+
+    #[test]
+    fn keep_unchanged_values_as_written() {
+        let contents = r#"{
+    "version": "0.2.0",
+    "configurations": [
+        {
+            "name": "Development",
+            "request": "launch",
+            "type": "dart",
+            "program": "lib/main.dart",
+            "toolArgs": [
+                // Keys for the development backend.
+                "--dart-define-from-file",
+                "config/dev.json",
+            ],
+        },
+    ]
+}
+"#;
+        let changes = super::LaunchConfigurationChanges {
+            name: "Development".to_string(),
+            program: Some("lib/main.dart".to_string()),
+            flutter_mode: Some("profile".to_string()),
+            tool_args: Some(vec![
+                "--dart-define-from-file".to_string(),
+                "config/dev.json".to_string(),
+            ]),
+            ..Default::default()
+        };
+        let result = super::save_launch_configuration(contents, Some(0), &changes).unwrap();
+        assert_eq!(
+            result,
+            contents.replace(
+                "            ],
+",
+                "            ],
+            \"flutterMode\": \"profile\",
+"
+            )
+        );
+    }
+
+    #[test]
+    fn duplicate_launch_configuration() {
+        let contents = include_str!("./test/launch_edit.json");
+        let (result, index) = super::duplicate_launch_configuration(contents, 0).unwrap();
+        assert_eq!(index, 2);
+        let changes = super::LaunchConfigurationChanges {
+            name: "Launch Development (copy)".to_string(),
+            program: Some("lib/main.dart".to_string()),
+            tool_args: Some(vec![
+                "--dart-define-from-file".to_string(),
+                "config.json".to_string(),
+            ]),
+            ..Default::default()
+        };
+        let result = super::save_launch_configuration(&result, Some(index), &changes).unwrap();
+        let configurations = super::parse_launch_configuration(&result).unwrap();
+        assert_eq!(configurations.len(), 3);
+        assert_eq!(
+            configurations[2],
+            super::VSCodeLaunchConfiguration {
+                name: "Launch Development (copy)".to_string(),
+                ..configurations[0].clone()
+            }
+        );
+        assert_eq!(result.matches("// The entry point.").count(), 2);
+    }
+
+    #[test]
+    fn remove_launch_configuration() {
+        let contents = include_str!("./test/launch_edit.json");
+        let result = super::remove_launch_configuration(contents, 0).unwrap();
+        let configurations = super::parse_launch_configuration(&result).unwrap();
+        assert_eq!(configurations.len(), 1);
+        assert_eq!(configurations[0].name, "Production");
+        assert!(result.contains("// Comments are kept."));
+
+        let result = super::remove_launch_configuration(contents, 1).unwrap();
+        let configurations = super::parse_launch_configuration(&result).unwrap();
+        assert_eq!(configurations.len(), 1);
+        assert_eq!(configurations[0].name, "Launch Development");
+    }
 }
@@ -1 +1,2 @@
+mod jsonc;
 pub mod launch;
@@ -1,5 +1,6 @@
 use color_eyre::eyre::{eyre, Result};
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
+use ratatui::layout::Position;
 use ratatui::prelude::Rect;
 use ratatui::prelude::*;
 use ratatui::widgets::Clear;
@@ -14,48 +15,69 @@ use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc::{self, UnboundedSender};
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{watch, Mutex, RwLock};
 
 use crate::components;
 use crate::components::app::AppComponent;
+use crate::components::app_settings::AppSettingsComponent;
+use crate::components::command_palette::CommandPaletteComponent;
 use crate::components::device::DeviceComponent;
 use crate::components::devices::DevicesComponent;
+use crate::components::dump::DumpComponent;
+use crate::components::dumps::DumpsComponent;
 use crate::components::frame_analysis::FrameAnalysisComponent;
 use crate::components::frames::FramesComponent;
+use crate::components::help::HelpComponent;
 use crate::components::inspector::InspectorComponent;
+use crate::components::launch_configuration_editor::LaunchConfigurationEditorComponent;
 use crate::components::launch_configurations::LaunchConfigurationsComponent;
 use crate::components::logs::LogsComponent;
 use crate::components::network::NetworkComponent;
 use crate::components::network_request::NetworkRequestComponent;
+use crate::components::notifications::NotificationsComponent;
 use crate::components::performance::PerformanceComponent;
+use crate::components::port_forwards::PortForwardsComponent;
 use crate::components::project::ProjectComponent;
 use crate::components::pubspec::PubspecComponent;
+use crate::components::rebuild_location::RebuildLocationComponent;
+use crate::components::rebuild_stats::RebuildStatsComponent;
+use crate::components::reloads::ReloadsComponent;
 use crate::components::runners::RunnersComponent;
 use crate::components::sdk_version::SdkVersionComponent;
 use crate::components::select_device_popup::SelectDevicePopupComponent;
 use crate::components::select_launch_configuration_popup::SelectLaunchConfigurationPopupComponent;
 use crate::components::select_tab_handler::SelectTabControllerComponent;
+use crate::components::status_bar::StatusBarComponent;
 use crate::components::widget_details::WidgetDetailsComponent;
+#[cfg(unix)]
+use crate::control::{ControlSocket, CONTROL_SOCKET_PATH};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::selector::project_state::project_state_selector;
 use crate::redux::state::{
-    DevTools, Focus, Home, SelectDevicePopupState, SelectLaunchConfigurationPopupState, State,
+    DevTools, Focus, Home, PopUp, SelectDevicePopupState, SelectLaunchConfigurationPopupState,
+    State,
 };
 use crate::redux::thunk::context::Context;
+use crate::redux::thunk::save_project_state::SaveProjectStateThunk;
 use crate::redux::thunk::watch_devices::WatchDevicesThunk;
 use crate::redux::thunk::{thunk_impl, ThunkAction};
 use crate::session::session_manager::SessionManager;
-use crate::utils::centered_rect;
+use crate::utils::{centered_rect, resolve_project_root};
 use crate::{
     action::TuiAction,
     components::Component,
+    config::{Command, Config},
     redux::{reducer::reducer, ActionOrThunk},
     tui::{self, Tui},
 };
 use daemon::flutter::FlutterDaemon;
 
-#[derive(Hash, Eq, PartialEq, Debug)]
+const UNFORWARD_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
 pub enum ComponentId {
     Project,
     Runners,
@@ -69,13 +91,25 @@ pub enum ComponentId {
     NetworkRequest,
     SelectTabController,
     SelectFlavorPopup,
+    LaunchConfigurationEditor,
+    CommandPalette,
+    Help,
+    StatusBar,
     Pubspec,
     App,
+    AppSettings,
     Performance,
+    RebuildStats,
+    RebuildLocation,
+    Dumps,
+    Dump,
     Inspector,
     WidgetDetails,
     LaunchConfigurations,
     SdkVersion,
+    PortForwards,
+    Reloads,
+    Notifications,
 }
 
 pub struct App {
@@ -83,28 +117,41 @@ pub struct App {
     pub frame_rate: f64,
     pub project_root: PathBuf,
     pub use_fvm: bool,
+    pub hot_reload_on_save: bool,
+    pub vm_service_uri: Option<String>,
+    /// Listen on the control socket, at the given path or the default one of the project.
+    pub control_socket: Option<Option<String>>,
+    pub config: Config,
     pub components: HashMap<ComponentId, Box<dyn Component>>,
+    /// The panes drawn in the last frame with their areas, in drawing order.
+    pub panes: Vec<(ComponentId, Rect)>,
     pub should_quit: bool,
     pub should_suspend: bool,
 }
 
 impl App {
-    pub fn new(project_root: Option<String>, use_fvm: bool) -> Result<Self> {
-        let project_root = if let Some(project_root) = project_root.clone() {
-            let path = Path::new(&project_root).to_path_buf();
-            if !path.exists() {
-                return Err(eyre!("Invalid project root"));
-            }
-            path
-        } else {
-            env::current_dir()?
-        };
+    pub fn new(
+        project_root: Option<String>,
+        use_fvm: bool,
+        hot_reload_on_save: bool,
+        vm_service_uri: Option<String>,
+        control_socket: Option<Option<String>>,
+    ) -> Result<Self> {
+        let project_root = resolve_project_root(project_root)?;
+        #[cfg(not(unix))]
+        if control_socket.is_some() {
+            return Err(eyre!("--control-socket is only supported on Unix"));
+        }
 
         Ok(Self {
             tick_rate: 4.0,
             frame_rate: 24.0,
             project_root: project_root.clone(),
             use_fvm,
+            hot_reload_on_save,
+            vm_service_uri,
+            control_socket,
+            config: Config::new()?,
             components: HashMap::from([
                 (
                     ComponentId::Project,
@@ -146,6 +193,22 @@ impl App {
                     ComponentId::SelectFlavorPopup,
                     Box::new(SelectLaunchConfigurationPopupComponent::new()) as Box<dyn Component>,
                 ),
+                (
+                    ComponentId::LaunchConfigurationEditor,
+                    Box::new(LaunchConfigurationEditorComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::CommandPalette,
+                    Box::new(CommandPaletteComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::Help,
+                    Box::new(HelpComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::StatusBar,
+                    Box::new(StatusBarComponent::new()) as Box<dyn Component>,
+                ),
                 (
                     ComponentId::Pubspec,
                     Box::new(PubspecComponent::new(project_root)) as Box<dyn Component>,
@@ -154,10 +217,30 @@ impl App {
                     ComponentId::App,
                     Box::new(AppComponent::new()) as Box<dyn Component>,
                 ),
+                (
+                    ComponentId::AppSettings,
+                    Box::new(AppSettingsComponent::new()) as Box<dyn Component>,
+                ),
                 (
                     ComponentId::Performance,
                     Box::new(PerformanceComponent::new()) as Box<dyn Component>,
                 ),
+                (
+                    ComponentId::RebuildStats,
+                    Box::new(RebuildStatsComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::RebuildLocation,
+                    Box::new(RebuildLocationComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::Dumps,
+                    Box::new(DumpsComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::Dump,
+                    Box::new(DumpComponent::new()) as Box<dyn Component>,
+                ),
                 (
                     ComponentId::Inspector,
                     Box::new(InspectorComponent::new()) as Box<dyn Component>,
@@ -182,7 +265,20 @@ impl App {
                     ComponentId::SdkVersion,
                     Box::new(SdkVersionComponent::new()) as Box<dyn Component>,
                 ),
+                (
+                    ComponentId::PortForwards,
+                    Box::new(PortForwardsComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::Reloads,
+                    Box::new(ReloadsComponent::new()) as Box<dyn Component>,
+                ),
+                (
+                    ComponentId::Notifications,
+                    Box::new(NotificationsComponent::new()) as Box<dyn Component>,
+                ),
             ]),
+            panes: vec![],
             should_quit: false,
             should_suspend: false,
         })
@@ -199,192 +295,350 @@ impl App {
         let (tui_action_tx, mut tui_action_rx) = mpsc::unbounded_channel::<TuiAction>();
         let (redux_action_tx, mut redux_action_rx) = mpsc::unbounded_channel::<ActionOrThunk>();
 
-        redux_action_tx.send(ThunkAction::WatchDevices.into())?;
-        redux_action_tx.send(ThunkAction::LoadEmulators.into())?;
-        redux_action_tx.send(ThunkAction::LoadVSCodeLaunchSetting.into())?;
-        redux_action_tx.send(
-            ThunkAction::LoadSdkVersions {
-                use_fvm: self.use_fvm,
+        // Cleaned up below also when the loop fails.
+        let mut control_socket_path: Option<PathBuf> = None;
+        let result: Result<()> = async {
+            redux_action_tx.send(ThunkAction::WatchDevices.into())?;
+            redux_action_tx.send(ThunkAction::LoadEmulators.into())?;
+            redux_action_tx.send(ThunkAction::LoadLaunchConfigurations.into())?;
+            redux_action_tx.send(ThunkAction::DiscoverFlavors.into())?;
+            redux_action_tx.send(ThunkAction::LoadProjectState.into())?;
+            redux_action_tx.send(
+                ThunkAction::LoadSdkVersions {
+                    use_fvm: self.use_fvm,
+                }
+                .into(),
+            )?;
+            if self.hot_reload_on_save {
+                redux_action_tx.send(Action::SetHotReloadOnSave { enabled: true }.into())?;
+                redux_action_tx.send(ThunkAction::WatchFiles.into())?;
+            }
+            if let Some(uri) = self.vm_service_uri.clone() {
+                redux_action_tx.send(ThunkAction::ConnectVmService { uri }.into())?;
             }
-            .into(),
-        )?;
 
-        let mut tui = tui::Tui::new()?
-            .tick_rate(self.tick_rate)
-            .frame_rate(self.frame_rate);
-        // tui.mouse(true);
-        tui.enter()?;
+            // The socket reads the state which the loop below publishes.
+            let mut state_tx: Option<watch::Sender<State>> = None;
+            #[cfg(unix)]
+            if let Some(path) = &self.control_socket {
+                let path = path
+                    .as_ref()
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| self.project_root.join(CONTROL_SOCKET_PATH));
+                let (tx, rx) = watch::channel(store.state_cloned().await);
+                let socket = ControlSocket::bind(&path, redux_action_tx.clone(), rx)?;
+                tokio::spawn(socket.serve());
+                state_tx = Some(tx);
+                control_socket_path = Some(path);
+            }
 
-        for (_, component) in self.components.iter_mut() {
-            component.register_action_handler(redux_action_tx.clone())?;
-        }
+            let mut tui = tui::Tui::new()?
+                .tick_rate(self.tick_rate)
+                .frame_rate(self.frame_rate)
+                .mouse(true);
+            tui.enter()?;
 
-        for (_, component) in self.components.iter_mut() {
-            component.init(tui.size()?)?;
-        }
+            for (_, component) in self.components.iter_mut() {
+                component.register_action_handler(redux_action_tx.clone())?;
+            }
 
-        loop {
-            let state = store.state_cloned().await;
-            for event in tui.next().await {
-                match event {
-                    tui::Event::Quit => tui_action_tx.send(TuiAction::Quit)?,
-                    tui::Event::Tick => tui_action_tx.send(TuiAction::Tick)?,
-                    tui::Event::Render => tui_action_tx.send(TuiAction::Render)?,
-                    tui::Event::Resize(x, y) => tui_action_tx.send(TuiAction::Resize(x, y))?,
-                    tui::Event::Key(key) => match key.code {
-                        KeyCode::Char('q') => tui_action_tx.send(TuiAction::Quit)?,
-                        KeyCode::Char('z') => tui_action_tx.send(TuiAction::Suspend)?,
-                        _ => {}
-                    },
-                    _ => {}
-                }
-                for (_, component) in self.components.iter_mut() {
-                    component.handle_events(&event, &state)?;
-                }
+            for (_, component) in self.components.iter_mut() {
+                component.register_config_handler(self.config.clone())?;
             }
 
-            let mut rendered = false;
-            while let Ok(action) = tui_action_rx.try_recv() {
-                match action {
-                    TuiAction::Quit => self.should_quit = true,
-                    TuiAction::Suspend => self.should_suspend = true,
-                    TuiAction::Resume => self.should_suspend = false,
-                    TuiAction::Resize(w, h) => {
-                        tui.resize(Rect::new(0, 0, w, h))?;
-                        self.draw(&mut tui, &state)?;
-                    }
-                    TuiAction::Render => {
-                        if !rendered {
-                            rendered = true;
-                            self.draw(&mut tui, &state)?;
+            for (_, component) in self.components.iter_mut() {
+                component.init(tui.size()?)?;
+            }
+
+            let mut saved_project_state = None;
+            loop {
+                let state = store.state_cloned().await;
+                if let Some(state_tx) = &state_tx {
+                    // Subscribers are only woken up when something changed.
+                    state_tx.send_if_modified(|published| {
+                        let modified = *published != state;
+                        if modified {
+                            *published = state.clone();
                         }
+                        modified
+                    });
+                }
+                if state.project_state_restored {
+                    let project_state = project_state_selector(&state);
+                    if saved_project_state.as_ref() != Some(&project_state) {
+                        redux_action_tx.send(
+                            ThunkAction::SaveProjectState {
+                                project_state: project_state.clone(),
+                            }
+                            .into(),
+                        )?;
+                        saved_project_state = Some(project_state);
                     }
-                    _ => {}
                 }
-                for (_, component) in self.components.iter_mut() {
-                    if let Some(action) = component.update(action.clone())? {
-                        tui_action_tx.send(action)?
-                    };
+                for event in tui.next().await {
+                    match event {
+                        tui::Event::Quit => tui_action_tx.send(TuiAction::Quit)?,
+                        tui::Event::Tick => tui_action_tx.send(TuiAction::Tick)?,
+                        tui::Event::Render => tui_action_tx.send(TuiAction::Render)?,
+                        tui::Event::Resize(x, y) => tui_action_tx.send(TuiAction::Resize(x, y))?,
+                        tui::Event::Key(key) => match self.config.command(&key, &state) {
+                            Some(Command::Quit) => tui_action_tx.send(TuiAction::Quit)?,
+                            Some(Command::Suspend) => tui_action_tx.send(TuiAction::Suspend)?,
+                            Some(
+                                command @ (Command::Zoom
+                                | Command::ShrinkSidebar
+                                | Command::GrowSidebar),
+                            ) if state.popup.is_none() => {
+                                let action = match command {
+                                    Command::Zoom => Action::ToggleZoom,
+                                    Command::ShrinkSidebar => Action::ResizeSidebar { delta: -5 },
+                                    _ => Action::ResizeSidebar { delta: 5 },
+                                };
+                                redux_action_tx.send(action.into())?;
+                            }
+                            _ => {}
+                        },
+                        // Mouse events only go to the pane under the cursor. Popups are keyboard only.
+                        tui::Event::Mouse(mouse) => {
+                            if state.popup.is_none() {
+                                let position = Position::new(mouse.column, mouse.row);
+                                if let Some((id, _)) = self
+                                    .panes
+                                    .iter()
+                                    .rev()
+                                    .find(|(_, area)| area.contains(position))
+                                {
+                                    let id = *id;
+                                    self.component(&id).handle_mouse_events(&mouse, &state)?;
+                                }
+                            }
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    for (_, component) in self.components.iter_mut() {
+                        component.handle_events(&event, &state)?;
+                    }
                 }
-            }
-            while let Ok(action) = redux_action_rx.try_recv() {
-                match action {
-                    ActionOrThunk::Action(action) => {
-                        store.dispatch(action).await;
+
+                let mut rendered = false;
+                while let Ok(action) = tui_action_rx.try_recv() {
+                    match action {
+                        TuiAction::Quit => self.should_quit = true,
+                        TuiAction::Suspend => self.should_suspend = true,
+                        TuiAction::Resume => self.should_suspend = false,
+                        TuiAction::Resize(w, h) => {
+                            tui.resize(Rect::new(0, 0, w, h))?;
+                            self.draw(&mut tui, &state)?;
+                        }
+                        TuiAction::Render => {
+                            if !rendered {
+                                rendered = true;
+                                self.draw(&mut tui, &state)?;
+                            }
+                        }
+                        _ => {}
                     }
-                    ActionOrThunk::Thunk(action) => {
-                        store
-                            .dispatch(thunk::ActionOrThunk::Thunk(thunk_impl(
-                                action,
-                                context.clone(),
-                            )))
-                            .await;
+                    for (_, component) in self.components.iter_mut() {
+                        if let Some(action) = component.update(action.clone())? {
+                            tui_action_tx.send(action)?
+                        };
                     }
                 }
+                while let Ok(action) = redux_action_rx.try_recv() {
+                    match action {
+                        ActionOrThunk::Action(action) => {
+                            store.dispatch(action).await;
+                        }
+                        ActionOrThunk::Thunk(action) => {
+                            store
+                                .dispatch(thunk::ActionOrThunk::Thunk(thunk_impl(
+                                    action,
+                                    context.clone(),
+                                )))
+                                .await;
+                        }
+                    }
+                }
+
+                if self.should_suspend {
+                    tui.suspend()?;
+                    tui_action_tx.send(TuiAction::Resume)?;
+                    tui = tui::Tui::new()?
+                        .tick_rate(self.tick_rate)
+                        .frame_rate(self.frame_rate)
+                        .mouse(true);
+                    tui.enter()?;
+                } else if self.should_quit {
+                    tui.stop()?;
+                    break;
+                }
             }
+            tui.exit()?;
+            Ok(())
+        }
+        .await;
 
-            if self.should_suspend {
-                tui.suspend()?;
-                tui_action_tx.send(TuiAction::Resume)?;
-                tui = tui::Tui::new()?
-                    .tick_rate(self.tick_rate)
-                    .frame_rate(self.frame_rate);
-                // tui.mouse(true);
-                tui.enter()?;
-            } else if self.should_quit {
-                tui.stop()?;
-                break;
+        if let Some(path) = control_socket_path {
+            if let Err(e) = std::fs::remove_file(&path) {
+                log::error!(
+                    "Failed to remove control socket {}: {:?}",
+                    path.display(),
+                    e
+                );
             }
         }
-        tui.exit()?;
-        Ok(())
+
+        let state = store.state_cloned().await;
+        // The last changes may still be waiting to be written.
+        if state.project_state_restored {
+            SaveProjectStateThunk::new(context.clone(), project_state_selector(&state))
+                .save(&state.project_root)
+                .await;
+        }
+
+        // Port forwards outlive the daemon, so remove them before exiting. A daemon which
+        // doesn't respond must not keep flx from exiting.
+        for forward in state.port_forwards {
+            let port = forward.port;
+            let unforward =
+                daemon.unforward_device(forward.device_id, forward.port, forward.host_port);
+            match tokio::time::timeout(UNFORWARD_TIMEOUT, unforward).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => log::error!("Failed to remove port forward {}: {:?}", port, e),
+                Err(_) => log::error!("Timed out removing port forward {}", port),
+            }
+        }
+        result
     }
 
     fn component(&mut self, id: &ComponentId) -> &mut Box<dyn Component> {
         self.components.get_mut(id).unwrap()
     }
 
+    /// Draws a component which receives the mouse events in its area.
+    fn draw_pane(&mut self, f: &mut Frame<'_>, id: ComponentId, area: Rect, state: &State) {
+        self.panes.push((id, area));
+        self.component(&id).draw(f, area, state);
+    }
+
     fn draw(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
+        self.panes.clear();
         match state.focus {
-            Focus::Home(_) => self.draw_home(tui, state),
-            Focus::DevTools(_) => self.draw_devtools(tui, state),
+            Focus::Home(_) => self.draw_home(tui, state)?,
+            Focus::DevTools(_) => self.draw_devtools(tui, state)?,
+        }
+        for (_, component) in self.components.iter_mut() {
+            component.draw_graphics(tui, state)?;
         }
+        Ok(())
     }
 
-    fn draw_home(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
-        tui.draw(|f| {
-            let layout = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
-                .split(f.size());
-            let tab_layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Percentage(50),
-                    Constraint::Percentage(50),
-                ])
-                .split(layout[0]);
-
-            self.component(&ComponentId::Project)
-                .draw(f, tab_layout[0], state);
-            self.component(&ComponentId::Runners)
-                .draw(f, tab_layout[1], state);
-            self.component(&ComponentId::Devices)
-                .draw(f, tab_layout[2], state);
-
-            if state.focus == Focus::Home(Home::Runners) {
-                if let Some(session) = current_session_selector(state) {
-                    if !session.started {
-                        self.component(&ComponentId::Logs).draw(f, layout[1], state);
-                    } else {
-                        let vertical_layout = Layout::default()
-                            .direction(Direction::Vertical)
-                            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
-                            .split(layout[1]);
-                        let horizontal_layout = Layout::default()
-                            .direction(Direction::Horizontal)
-                            .constraints([
-                                Constraint::Fill(1),
-                                Constraint::Fill(1),
-                                Constraint::Fill(1),
-                            ])
-                            .split(vertical_layout[1]);
-                        self.component(&ComponentId::Frames)
-                            .draw(f, vertical_layout[0], state);
-                        self.component(&ComponentId::Inspector).draw(
-                            f,
-                            horizontal_layout[0],
-                            state,
-                        );
-                        self.component(&ComponentId::Network)
-                            .draw(f, horizontal_layout[1], state);
-                        self.component(&ComponentId::Logs)
-                            .draw(f, horizontal_layout[2], state);
-                    }
+    /// The pane which fills the screen when zoomed. The logs are zoomed for the tabs which show them.
+    fn zoomed_pane(state: &State) -> ComponentId {
+        match state.focus {
+            Focus::Home(Home::Project) => ComponentId::Pubspec,
+            Focus::Home(Home::Runners) if state.session_id.is_some() => ComponentId::Logs,
+            Focus::Home(Home::Runners) => ComponentId::LaunchConfigurations,
+            Focus::Home(Home::Devices) => ComponentId::Devices,
+            Focus::Home(Home::PortForwards) => ComponentId::PortForwards,
+            Focus::DevTools(DevTools::App) => ComponentId::Logs,
+            Focus::DevTools(DevTools::AppSettings) => ComponentId::AppSettings,
+            Focus::DevTools(DevTools::Performance) => ComponentId::Performance,
+            Focus::DevTools(DevTools::RebuildStats) => ComponentId::RebuildStats,
+            Focus::DevTools(DevTools::Dumps) => ComponentId::Dumps,
+            Focus::DevTools(DevTools::DumpDetails) => ComponentId::Dump,
+            Focus::DevTools(DevTools::Inspector) => ComponentId::Inspector,
+            Focus::DevTools(DevTools::WidgetDetails) => ComponentId::WidgetDetails,
+            Focus::DevTools(DevTools::Network) => ComponentId::Network,
+            Focus::DevTools(DevTools::NetworkRequest) => ComponentId::NetworkRequest,
+        }
+    }
+
+    fn draw_home_panes(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let sidebar_width = state.pane_layout.home_sidebar_width;
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(sidebar_width),
+                Constraint::Percentage(100 - sidebar_width),
+            ])
+            .split(area);
+        let tab_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Percentage(50),
+                Constraint::Percentage(50),
+            ])
+            .split(layout[0]);
+
+        self.draw_pane(f, ComponentId::Project, tab_layout[0], state);
+        self.draw_pane(f, ComponentId::Runners, tab_layout[1], state);
+        self.draw_pane(f, ComponentId::Devices, tab_layout[2], state);
+
+        if state.focus == Focus::Home(Home::Runners) {
+            if let Some(session) = current_session_selector(state) {
+                if !session.started {
+                    self.draw_pane(f, ComponentId::Logs, layout[1], state);
                 } else {
+                    let vertical_layout = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+                        .split(layout[1]);
                     let horizontal_layout = Layout::default()
                         .direction(Direction::Horizontal)
-                        .constraints([Constraint::Fill(1), Constraint::Fill(1)])
-                        .split(layout[1]);
-                    self.component(&ComponentId::LaunchConfigurations).draw(
-                        f,
-                        horizontal_layout[0],
-                        state,
-                    );
-                    self.component(&ComponentId::SdkVersion)
-                        .draw(f, horizontal_layout[1], state);
+                        .constraints([
+                            Constraint::Fill(1),
+                            Constraint::Fill(1),
+                            Constraint::Fill(1),
+                        ])
+                        .split(vertical_layout[1]);
+                    self.draw_pane(f, ComponentId::Frames, vertical_layout[0], state);
+                    self.draw_pane(f, ComponentId::Inspector, horizontal_layout[0], state);
+                    self.draw_pane(f, ComponentId::Network, horizontal_layout[1], state);
+                    self.draw_pane(f, ComponentId::Logs, horizontal_layout[2], state);
                 }
+            } else {
+                let horizontal_layout = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Fill(1), Constraint::Fill(1)])
+                    .split(layout[1]);
+                self.draw_pane(
+                    f,
+                    ComponentId::LaunchConfigurations,
+                    horizontal_layout[0],
+                    state,
+                );
+                self.draw_pane(f, ComponentId::SdkVersion, horizontal_layout[1], state);
             }
+        }
 
-            if state.focus == Focus::Home(Home::Project) {
-                self.component(&ComponentId::Pubspec)
-                    .draw(f, layout[1], state);
-            }
+        if state.focus == Focus::Home(Home::Project) {
+            self.draw_pane(f, ComponentId::Pubspec, layout[1], state);
+        }
 
-            if state.focus == Focus::Home(Home::Devices) {
-                self.component(&ComponentId::Device)
-                    .draw(f, layout[1], state);
+        if matches!(
+            state.focus,
+            Focus::Home(Home::Devices) | Focus::Home(Home::PortForwards)
+        ) {
+            let vertical_layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(9), Constraint::Fill(1)])
+                .split(layout[1]);
+            self.draw_pane(f, ComponentId::Device, vertical_layout[0], state);
+            self.draw_pane(f, ComponentId::PortForwards, vertical_layout[1], state);
+        }
+    }
+
+    fn draw_home(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
+        tui.draw(|f| {
+            let screen =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(f.size());
+            if state.pane_layout.zoomed {
+                self.draw_pane(f, Self::zoomed_pane(state), screen[0], state);
+            } else {
+                self.draw_home_panes(f, screen[0], state);
             }
 
             let popup_area = centered_rect(60, 20, f.size());
@@ -394,81 +648,133 @@ impl App {
             let popup_area = centered_rect(60, 40, f.size());
             self.component(&ComponentId::SelectFlavorPopup)
                 .draw(f, popup_area, state);
+            self.component(&ComponentId::LaunchConfigurationEditor)
+                .draw(f, popup_area, state);
+
+            let popup_area = centered_rect(60, 50, f.size());
+            self.component(&ComponentId::CommandPalette)
+                .draw(f, popup_area, state);
+            let popup_area = centered_rect(50, 70, f.size());
+            self.component(&ComponentId::Help)
+                .draw(f, popup_area, state);
+
+            self.component(&ComponentId::StatusBar)
+                .draw(f, screen[1], state);
+
+            self.component(&ComponentId::Notifications)
+                .draw(f, f.size(), state);
         })?;
         Ok(())
     }
 
+    fn draw_devtools_panes(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let sidebar_width = state.pane_layout.devtools_sidebar_width;
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(sidebar_width),
+                Constraint::Percentage(100 - sidebar_width),
+            ])
+            .split(area);
+        let tabs = [
+            ComponentId::Inspector,
+            ComponentId::Performance,
+            ComponentId::RebuildStats,
+            ComponentId::Dumps,
+            ComponentId::Network,
+        ];
+        let expanded_tab = match state.focus {
+            Focus::DevTools(DevTools::Inspector) | Focus::DevTools(DevTools::WidgetDetails) => {
+                Some(ComponentId::Inspector)
+            }
+            Focus::DevTools(DevTools::Performance) => Some(ComponentId::Performance),
+            Focus::DevTools(DevTools::RebuildStats) => Some(ComponentId::RebuildStats),
+            Focus::DevTools(DevTools::Dumps) | Focus::DevTools(DevTools::DumpDetails) => {
+                Some(ComponentId::Dumps)
+            }
+            Focus::DevTools(DevTools::Network) | Focus::DevTools(DevTools::NetworkRequest) => {
+                Some(ComponentId::Network)
+            }
+            _ => None,
+        };
+        let tab_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [Constraint::Length(3)]
+                    .into_iter()
+                    .chain(tabs.iter().map(|tab| match &expanded_tab {
+                        Some(expanded_tab) if expanded_tab != tab => Constraint::Length(2),
+                        _ => Constraint::Fill(1),
+                    }))
+                    .collect::<Vec<_>>(),
+            )
+            .split(layout[0]);
+
+        self.draw_pane(f, ComponentId::App, tab_layout[0], state);
+        for (index, tab) in tabs.iter().enumerate() {
+            self.draw_pane(f, *tab, tab_layout[index + 1], state);
+        }
+
+        match state.focus {
+            Focus::DevTools(DevTools::Performance) => {
+                let vertical_layout = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+                    .split(layout[1]);
+                self.draw_pane(f, ComponentId::Frames, vertical_layout[0], state);
+                self.draw_pane(f, ComponentId::FrameAnalysis, vertical_layout[1], state)
+            }
+            Focus::DevTools(DevTools::App) | Focus::DevTools(DevTools::AppSettings) => {
+                let vertical_layout = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(5),
+                        Constraint::Length(7),
+                        Constraint::Fill(1),
+                    ])
+                    .split(layout[1]);
+                self.draw_pane(f, ComponentId::AppSettings, vertical_layout[0], state);
+                self.draw_pane(f, ComponentId::Reloads, vertical_layout[1], state);
+                self.draw_pane(f, ComponentId::Logs, vertical_layout[2], state);
+            }
+            Focus::DevTools(DevTools::RebuildStats) => {
+                self.draw_pane(f, ComponentId::RebuildLocation, layout[1], state);
+            }
+            Focus::DevTools(DevTools::Dumps) | Focus::DevTools(DevTools::DumpDetails) => {
+                self.draw_pane(f, ComponentId::Dump, layout[1], state);
+            }
+            Focus::DevTools(DevTools::Network) | Focus::DevTools(DevTools::NetworkRequest) => {
+                self.draw_pane(f, ComponentId::NetworkRequest, layout[1], state);
+            }
+            Focus::DevTools(DevTools::Inspector) | Focus::DevTools(DevTools::WidgetDetails) => {
+                self.draw_pane(f, ComponentId::WidgetDetails, layout[1], state);
+            }
+            _ => {}
+        }
+    }
+
     fn draw_devtools(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
         tui.draw(|f| {
-            let layout = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
-                .split(f.size());
-            let tab_layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints(match state.focus {
-                    Focus::DevTools(DevTools::Inspector)
-                    | Focus::DevTools(DevTools::WidgetDetails) => vec![
-                        Constraint::Length(3),
-                        Constraint::Fill(1),
-                        Constraint::Length(2),
-                        Constraint::Length(2),
-                    ],
-                    Focus::DevTools(DevTools::Performance) => vec![
-                        Constraint::Length(3),
-                        Constraint::Length(2),
-                        Constraint::Fill(1),
-                        Constraint::Length(2),
-                    ],
-                    Focus::DevTools(DevTools::Network)
-                    | Focus::DevTools(DevTools::NetworkRequest) => vec![
-                        Constraint::Length(3),
-                        Constraint::Length(2),
-                        Constraint::Length(2),
-                        Constraint::Fill(1),
-                    ],
-                    _ => vec![
-                        Constraint::Length(3),
-                        Constraint::Fill(1),
-                        Constraint::Fill(1),
-                        Constraint::Fill(1),
-                    ],
-                })
-                .split(layout[0]);
-
-            self.component(&ComponentId::App)
-                .draw(f, tab_layout[0], state);
-            self.component(&ComponentId::Inspector)
-                .draw(f, tab_layout[1], state);
-            self.component(&ComponentId::Performance)
-                .draw(f, tab_layout[2], state);
-            self.component(&ComponentId::Network)
-                .draw(f, tab_layout[3], state);
-
-            match state.focus {
-                Focus::DevTools(DevTools::Performance) => {
-                    let vertical_layout = Layout::default()
-                        .direction(Direction::Vertical)
-                        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
-                        .split(layout[1]);
-                    self.component(&ComponentId::Frames)
-                        .draw(f, vertical_layout[0], state);
-                    self.component(&ComponentId::FrameAnalysis)
-                        .draw(f, vertical_layout[1], state)
-                }
-                Focus::DevTools(DevTools::App) => {
-                    self.component(&ComponentId::Logs).draw(f, layout[1], state);
-                }
-                Focus::DevTools(DevTools::Network) | Focus::DevTools(DevTools::NetworkRequest) => {
-                    self.component(&ComponentId::NetworkRequest)
-                        .draw(f, layout[1], state);
-                }
-                Focus::DevTools(DevTools::Inspector) | Focus::DevTools(DevTools::WidgetDetails) => {
-                    self.component(&ComponentId::WidgetDetails)
-                        .draw(f, layout[1], state);
-                }
-                _ => {}
+            let screen =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(f.size());
+            if state.pane_layout.zoomed {
+                self.draw_pane(f, Self::zoomed_pane(state), screen[0], state);
+            } else {
+                self.draw_devtools_panes(f, screen[0], state);
             }
+
+            let popup_area = centered_rect(60, 50, f.size());
+            self.component(&ComponentId::CommandPalette)
+                .draw(f, popup_area, state);
+            let popup_area = centered_rect(50, 70, f.size());
+            self.component(&ComponentId::Help)
+                .draw(f, popup_area, state);
+
+            self.component(&ComponentId::StatusBar)
+                .draw(f, screen[1], state);
+
+            self.component(&ComponentId::Notifications)
+                .draw(f, f.size(), state);
         })?;
         Ok(())
     }
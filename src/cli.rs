@@ -1,20 +1,114 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
 use crate::utils::version;
 
 #[derive(Parser, Debug)]
 #[command(author, version = version(), about)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
     #[arg(
         short,
         long,
+        global = true,
         value_name = "String",
         help = "Path to the Flutter project"
     )]
     pub project_root: Option<String>,
 
-    #[arg(long, value_name = "bool", help = "Enable FVM support")]
+    #[arg(long, global = true, value_name = "bool", help = "Enable FVM support")]
     pub fvm: bool,
+
+    #[arg(long, help = "Hot reload running apps when dart files are saved")]
+    pub hot_reload_on_save: bool,
+
+    #[arg(
+        long,
+        value_name = "URI",
+        help = "Connect to the VM service of an already running app"
+    )]
+    pub vm_service_uri: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Accept JSON-RPC requests on a Unix socket (.dart_tool/flx/control.sock by default)"
+    )]
+    pub control_socket: Option<Option<String>>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Run an app without the TUI and print its logs, frames, requests and lifecycle events.
+    /// Type `r` to hot reload, `R` to hot restart and `q` to quit.
+    Run(RunArgs),
+    /// List the connected devices.
+    Devices(DevicesArgs),
+    /// List the available emulators, or launch one of them.
+    Emulators(EmulatorsArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    #[arg(
+        short,
+        long,
+        value_name = "NAME",
+        help = "Launch configuration or flx.toml profile to run"
+    )]
+    pub config: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        value_name = "ID",
+        help = "Device to run on, instead of the one of the launch configuration"
+    )]
+    pub device: Option<String>,
+
+    #[arg(long, help = "Print events as newline-delimited JSON")]
+    pub json: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct DevicesArgs {
+    #[arg(long, help = "Print the devices as JSON")]
+    pub json: bool,
+
+    #[arg(
+        long,
+        help = "Keep running and print devices as they are added and removed"
+    )]
+    pub watch: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct EmulatorsArgs {
+    #[command(subcommand)]
+    pub command: Option<EmulatorsCommands>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Print the emulators or the launched device as JSON"
+    )]
+    pub json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum EmulatorsCommands {
+    /// Launch an emulator and wait until its device is connected.
+    Launch {
+        #[arg(value_name = "ID")]
+        id: String,
+
+        #[arg(
+            long,
+            help = "Cold boot the emulator instead of restoring its snapshot"
+        )]
+        cold: bool,
+    },
 }
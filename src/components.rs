@@ -5,28 +5,41 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     action::TuiAction,
+    config::Config,
     redux::{state::State, ActionOrThunk},
-    tui::{Event, Frame},
+    tui::{Event, Frame, Tui},
 };
 
 pub mod app;
+pub mod app_settings;
+pub mod command_palette;
 pub mod device;
 pub mod devices;
+pub mod dump;
+pub mod dumps;
 pub mod frame_analysis;
 pub mod frames;
+pub mod help;
 pub mod inspector;
+pub mod launch_configuration_editor;
 pub mod launch_configurations;
 pub mod logs;
 pub mod network;
 pub mod network_request;
+pub mod notifications;
 pub mod performance;
+pub mod port_forwards;
 pub mod project;
 pub mod pubspec;
+pub mod rebuild_location;
+pub mod rebuild_stats;
+pub mod reloads;
 pub mod runners;
 pub mod sdk_version;
 pub mod select_device_popup;
 pub mod select_launch_configuration_popup;
 pub mod select_tab_handler;
+pub mod status_bar;
 pub mod widget_details;
 
 /// `Component` is a trait that represents a visual and interactive element of the user interface.
@@ -46,6 +59,19 @@ pub trait Component {
     fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
         Ok(())
     }
+    /// Register a configuration handler that provides configuration settings if necessary.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration settings.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An Ok result or an error.
+    #[allow(unused_variables)]
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        Ok(())
+    }
     /// Initialize the component with a specified area if necessary.
     ///
     /// # Arguments
@@ -125,4 +151,19 @@ pub trait Component {
     ///
     /// * `Result<()>` - An Ok result or an error.
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State);
+    /// Write terminal graphics (kitty or sixel images) directly to the backend after the frame
+    /// has been drawn. Most components don't need this.
+    ///
+    /// # Arguments
+    ///
+    /// * `tui` - The terminal the frame has been drawn to.
+    /// * `state` - The state the frame has been drawn with.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An Ok result or an error.
+    #[allow(unused_variables)]
+    fn draw_graphics(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
+        Ok(())
+    }
 }
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::{Command, Config};
+use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::state::{DevTools, Focus, State};
 use crate::redux::thunk::ThunkAction;
@@ -19,6 +21,7 @@ use super::Component;
 #[derive(Default)]
 pub struct AppComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
 }
 
 impl AppComponent {
@@ -105,26 +108,46 @@ impl Component for AppComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if !matches!(state.focus, Focus::DevTools(_)) || state.popup.is_some() {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Char('r') => self.hot_reload()?,
-            KeyCode::Char('R') => self.hot_restart()?,
-            KeyCode::Char('i') => self.toggle_widget_inspector()?,
-            KeyCode::Char('p') => self.toggle_debug_paint()?,
-            KeyCode::Char('P') => self.toggle_show_performance_overlay()?,
-            KeyCode::Char('b') => self.toggle_debug_paint_baselines()?,
-            KeyCode::Char('I') => self.toggle_invert_overized_images()?,
-            KeyCode::Char('o') => self.toggle_repaint_rainbow()?,
-            KeyCode::Char('s') => self.toggle_slow_animations()?,
+        match self.config.command(key, state) {
+            Some(Command::HotReload) => self.hot_reload()?,
+            Some(Command::HotRestart) => self.hot_restart()?,
+            Some(Command::ToggleWidgetInspector) => self.toggle_widget_inspector()?,
+            Some(Command::ToggleDebugPaint) => self.toggle_debug_paint()?,
+            Some(Command::TogglePerformanceOverlay) => self.toggle_show_performance_overlay()?,
+            Some(Command::ToggleDebugPaintBaselines) => self.toggle_debug_paint_baselines()?,
+            Some(Command::ToggleInvertOversizedImages) => self.toggle_invert_overized_images()?,
+            Some(Command::ToggleRepaintRainbow) => self.toggle_repaint_rainbow()?,
+            Some(Command::ToggleSlowAnimations) => self.toggle_slow_animations()?,
             _ => {}
         }
         Ok(())
     }
 
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            self.action_tx
+                .as_ref()
+                .ok_or_else(|| eyre!("action_tx is None"))?
+                .send(
+                    Action::SetFocus {
+                        focus: Focus::DevTools(DevTools::App),
+                    }
+                    .into(),
+                )?;
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let Some(session) = current_session_selector(state) else {
             return;
@@ -132,9 +155,9 @@ impl Component for AppComponent {
 
         let border_color = if state.focus == Focus::DevTools(DevTools::App) && state.popup.is_none()
         {
-            Color::Green
+            self.config.theme.focus
         } else {
-            Color::White
+            self.config.theme.border
         };
 
         let device = if let Some(device_id) = &session.device_id {
@@ -143,20 +166,20 @@ impl Component for AppComponent {
             None
         };
         let status_color = if session.hot_reloading {
-            Color::Yellow
+            self.config.theme.warning
         } else if session.hot_restarting {
-            Color::LightMagenta
+            self.config.theme.accent
         } else if !session.started {
-            Color::DarkGray
+            self.config.theme.hint
         } else {
-            Color::White
+            self.config.theme.text
         };
         let name = format!(
             " {} ",
             if let Some(device) = device {
                 device.name.clone()
             } else {
-                "".to_string()
+                session.vm_service_uri.clone().unwrap_or_default()
             }
         );
 
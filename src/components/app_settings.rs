@@ -0,0 +1,292 @@
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::{DevTools, Focus, PopUp, SessionState, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+pub const PLATFORMS: [&str; 6] = ["android", "iOS", "macOS", "windows", "linux", "fuchsia"];
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Setting {
+    #[default]
+    Platform,
+    Brightness,
+    TimeDilation,
+}
+
+impl Setting {
+    const ALL: [Setting; 3] = [
+        Setting::Platform,
+        Setting::Brightness,
+        Setting::TimeDilation,
+    ];
+
+    fn title(&self) -> &'static str {
+        match self {
+            Setting::Platform => "Platform",
+            Setting::Brightness => "Brightness",
+            Setting::TimeDilation => "Time dilation",
+        }
+    }
+
+    fn value(&self, session: &SessionState) -> Option<String> {
+        match self {
+            Setting::Platform => session.target_platform.clone(),
+            Setting::Brightness => session
+                .brightness
+                .as_ref()
+                .map(|b| b.trim_start_matches("Brightness.").to_string()),
+            Setting::TimeDilation => session.time_dilation.as_ref().map(|t| format!("{}x", t)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct AppSettingsComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    selected: Setting,
+    time_dilation_input: String,
+}
+
+impl AppSettingsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
+    fn next(&mut self) {
+        let index = Setting::ALL.iter().position(|s| s == &self.selected);
+        if let Some(setting) = index.and_then(|i| Setting::ALL.get(i + 1)) {
+            self.selected = *setting;
+        }
+    }
+
+    fn previous(&mut self) {
+        let index = Setting::ALL.iter().position(|s| s == &self.selected);
+        if let Some(setting) = index.and_then(|i| i.checked_sub(1)) {
+            self.selected = Setting::ALL[setting];
+        }
+    }
+
+    fn change(&mut self, session: &SessionState) -> Result<()> {
+        match self.selected {
+            Setting::Platform => {
+                let index = session
+                    .target_platform
+                    .as_ref()
+                    .and_then(|platform| PLATFORMS.iter().position(|p| p == platform));
+                let next = index.map(|i| (i + 1) % PLATFORMS.len()).unwrap_or(0);
+                self.send(
+                    ThunkAction::OverridePlatform {
+                        platform: PLATFORMS[next].to_string(),
+                    }
+                    .into(),
+                )
+            }
+            Setting::Brightness => {
+                let brightness = if session.brightness.as_deref() == Some("Brightness.dark") {
+                    "Brightness.light"
+                } else {
+                    "Brightness.dark"
+                };
+                self.send(
+                    ThunkAction::OverrideBrightness {
+                        brightness: brightness.to_string(),
+                    }
+                    .into(),
+                )
+            }
+            Setting::TimeDilation => {
+                self.time_dilation_input = session.time_dilation.clone().unwrap_or_default();
+                self.send(Action::ShowTimeDilationPopup.into())
+            }
+        }
+    }
+
+    fn reset(&self) -> Result<()> {
+        let thunk = match self.selected {
+            Setting::Platform => ThunkAction::OverridePlatform {
+                platform: "default".to_string(),
+            },
+            Setting::Brightness => ThunkAction::OverrideBrightness {
+                brightness: "default".to_string(),
+            },
+            Setting::TimeDilation => ThunkAction::OverrideTimeDilation {
+                factor: "1.0".to_string(),
+            },
+        };
+        self.send(thunk.into())
+    }
+
+    fn handle_time_dilation_key_events(&mut self, key: &KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
+                self.time_dilation_input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.time_dilation_input.pop();
+            }
+            KeyCode::Enter => {
+                let factor = self.time_dilation_input.trim().to_string();
+                if !factor.parse::<f64>().is_ok_and(|f| f > 0.0) {
+                    return Ok(());
+                }
+                self.time_dilation_input.clear();
+                self.send(Action::HideTimeDilationPopup.into())?;
+                self.send(ThunkAction::OverrideTimeDilation { factor }.into())?;
+            }
+            KeyCode::Esc => {
+                self.time_dilation_input.clear();
+                self.send(Action::HideTimeDilationPopup.into())?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Component for AppSettingsComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        if state.focus == Focus::DevTools(DevTools::App)
+            && state.popup.is_none()
+            && self.config.command(key, state) == Some(Command::Select)
+        {
+            if let Some(session_id) = state.session_id.clone() {
+                self.send(Action::EnterAppSettings.into())?;
+                self.send(ThunkAction::LoadAppSettings { session_id }.into())?;
+            }
+            return Ok(());
+        }
+        if state.focus != Focus::DevTools(DevTools::AppSettings) {
+            return Ok(());
+        }
+        if state.popup == Some(PopUp::TimeDilation) {
+            return self.handle_time_dilation_key_events(key);
+        }
+        if state.popup.is_some() {
+            return Ok(());
+        }
+        let Some(session) = current_session_selector(state) else {
+            return Ok(());
+        };
+
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous(),
+            Some(Command::Down) => self.next(),
+            Some(Command::Select) => self.change(session)?,
+            Some(Command::Reset) => self.reset()?,
+            Some(Command::Back) => self.send(Action::ExitAppSettings.into())?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if state.focus != Focus::DevTools(DevTools::AppSettings) =>
+            {
+                if let Some(session_id) = state.session_id.clone() {
+                    self.send(Action::EnterAppSettings.into())?;
+                    self.send(ThunkAction::LoadAppSettings { session_id }.into())?;
+                }
+            }
+            MouseEventKind::ScrollUp => self.previous(),
+            MouseEventKind::ScrollDown => self.next(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let focused = state.focus == Focus::DevTools(DevTools::AppSettings);
+        let border_color = if focused && state.popup.is_none() {
+            self.config.theme.focus
+        } else {
+            self.config.theme.border
+        };
+        let mut block = Block::default()
+            .title("App Settings")
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .borders(Borders::ALL);
+
+        if state.popup == Some(PopUp::TimeDilation) {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(
+                    "Time dilation: ",
+                    Style::default().fg(self.config.theme.key),
+                ),
+                Span::raw(self.time_dilation_input.clone()),
+            ]));
+        } else {
+            let commands: &[(Command, &str)] = if focused {
+                &[
+                    (Command::Select, "change"),
+                    (Command::Reset, "reset to default"),
+                ]
+            } else {
+                &[(Command::Select, "edit settings")]
+            };
+            let hint = self
+                .config
+                .keybindings
+                .hint(&KeyContext::focus_stack(&state.focus), commands);
+            block = block.title_bottom(Line::styled(
+                hint,
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+
+        let key_style = Style::default().fg(self.config.theme.key).bold();
+        let rows = Setting::ALL.iter().map(|setting| {
+            let row = Row::new([
+                Cell::from(setting.title()).style(key_style),
+                Cell::from(setting.value(session).unwrap_or_else(|| "-".to_string())),
+            ]);
+            if focused && setting == &self.selected {
+                row.style(Style::default().bg(self.config.theme.highlight))
+            } else {
+                row
+            }
+        });
+
+        let widths = [Constraint::Length(16), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(block);
+        f.render_widget(table, area);
+    }
+}
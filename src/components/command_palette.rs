@@ -0,0 +1,487 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::{DevTools, Focus, Home, PopUp, SessionState, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use crate::utils::fuzzy_match;
+use color_eyre::eyre::{eyre, Result};
+
+use super::app_settings::PLATFORMS;
+use super::Component;
+
+struct PaletteEntry {
+    title: String,
+    /// The command whose keybinding is shown next to the entry, with the focus in which it is
+    /// bound.
+    command: Option<(Command, Focus)>,
+    actions: Vec<ActionOrThunk>,
+}
+
+impl PaletteEntry {
+    fn new(
+        title: impl Into<String>,
+        command: Option<(Command, Focus)>,
+        action: ActionOrThunk,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            command,
+            actions: vec![action],
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CommandPaletteComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    query: String,
+    selected: usize,
+    list_state: ListState,
+}
+
+impl CommandPaletteComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn session_name(state: &State, session: &SessionState) -> String {
+        state
+            .devices
+            .iter()
+            .find(|d| Some(&d.id) == session.device_id.as_ref())
+            .map(|d| d.name.clone())
+            .or(session.vm_service_uri.clone())
+            .unwrap_or_default()
+    }
+
+    /// Everything which can be done in the current state.
+    fn entries(state: &State) -> Vec<PaletteEntry> {
+        let mut entries = vec![];
+
+        // The app commands are bound in the runners pane and in DevTools.
+        let runners = match state.focus {
+            Focus::Home(_) => Focus::Home(Home::Runners),
+            Focus::DevTools(_) => state.focus.clone(),
+        };
+
+        if let Some(session) = current_session_selector(state).filter(|s| !s.stopped) {
+            for (title, command, thunk) in [
+                ("Hot reload", Command::HotReload, ThunkAction::HotReload),
+                ("Hot restart", Command::HotRestart, ThunkAction::HotRestart),
+                ("Stop app", Command::StopApp, ThunkAction::StopApp),
+                (
+                    "Toggle widget inspector",
+                    Command::ToggleWidgetInspector,
+                    ThunkAction::ToggleWidgetInspector,
+                ),
+                (
+                    "Toggle debug paint",
+                    Command::ToggleDebugPaint,
+                    ThunkAction::ToggleDebugPaint,
+                ),
+                (
+                    "Toggle performance overlay",
+                    Command::TogglePerformanceOverlay,
+                    ThunkAction::ToggleShowPerformanceOverlay,
+                ),
+                (
+                    "Toggle debug paint baselines",
+                    Command::ToggleDebugPaintBaselines,
+                    ThunkAction::ToggleDebugPaintBaselines,
+                ),
+                (
+                    "Toggle invert oversized images",
+                    Command::ToggleInvertOversizedImages,
+                    ThunkAction::ToggleInvertOversizedImages,
+                ),
+                (
+                    "Toggle repaint rainbow",
+                    Command::ToggleRepaintRainbow,
+                    ThunkAction::ToggleRepaintRainbow,
+                ),
+                (
+                    "Toggle slow animations",
+                    Command::ToggleSlowAnimations,
+                    ThunkAction::ToggleSlowAnimations,
+                ),
+            ] {
+                entries.push(PaletteEntry::new(
+                    title,
+                    Some((command, runners.clone())),
+                    thunk.into(),
+                ));
+            }
+            entries.extend([
+                PaletteEntry::new(
+                    "Track widget rebuilds",
+                    Some((
+                        Command::TrackRebuilds,
+                        Focus::DevTools(DevTools::RebuildStats),
+                    )),
+                    ThunkAction::ToggleTrackRebuildWidgets.into(),
+                ),
+                PaletteEntry::new(
+                    "Track widget repaints",
+                    Some((
+                        Command::TrackRepaints,
+                        Focus::DevTools(DevTools::RebuildStats),
+                    )),
+                    ThunkAction::ToggleTrackRepaintWidgets.into(),
+                ),
+            ]);
+            entries.push(match state.focus {
+                Focus::Home(_) => {
+                    PaletteEntry::new("Open DevTools", None, Action::EnterDevTools.into())
+                }
+                Focus::DevTools(_) => {
+                    PaletteEntry::new("Close DevTools", None, Action::ExitDevTools.into())
+                }
+            });
+            if state.focus == Focus::DevTools(DevTools::DumpDetails) {
+                entries.push(PaletteEntry::new(
+                    "Save dump",
+                    Some((Command::Save, Focus::DevTools(DevTools::DumpDetails))),
+                    ThunkAction::SaveDump.into(),
+                ));
+            }
+            if session.widget_screenshot.is_some() {
+                entries.push(PaletteEntry::new(
+                    "Save widget screenshot",
+                    Some((Command::Save, Focus::DevTools(DevTools::WidgetDetails))),
+                    ThunkAction::SaveWidgetScreenshot.into(),
+                ));
+            }
+
+            for platform in PLATFORMS {
+                entries.push(PaletteEntry::new(
+                    format!("Override platform: {}", platform),
+                    None,
+                    ThunkAction::OverridePlatform {
+                        platform: platform.to_string(),
+                    }
+                    .into(),
+                ));
+            }
+            for brightness in ["light", "dark"] {
+                entries.push(PaletteEntry::new(
+                    format!("Override brightness: {}", brightness),
+                    None,
+                    ThunkAction::OverrideBrightness {
+                        brightness: format!("Brightness.{}", brightness),
+                    }
+                    .into(),
+                ));
+            }
+            entries.push(PaletteEntry {
+                title: "Override time dilation".to_string(),
+                command: None,
+                actions: vec![
+                    Action::EnterAppSettings.into(),
+                    ThunkAction::LoadAppSettings {
+                        session_id: session.id.clone(),
+                    }
+                    .into(),
+                    Action::ShowTimeDilationPopup.into(),
+                ],
+            });
+            entries.push(PaletteEntry {
+                title: "Reset app settings".to_string(),
+                command: Some((Command::Reset, Focus::DevTools(DevTools::AppSettings))),
+                actions: vec![
+                    ThunkAction::OverridePlatform {
+                        platform: "default".to_string(),
+                    }
+                    .into(),
+                    ThunkAction::OverrideBrightness {
+                        brightness: "default".to_string(),
+                    }
+                    .into(),
+                    ThunkAction::OverrideTimeDilation {
+                        factor: "1.0".to_string(),
+                    }
+                    .into(),
+                ],
+            });
+        }
+
+        for session in state
+            .sessions
+            .iter()
+            .filter(|s| Some(&s.id) != state.session_id.as_ref())
+        {
+            entries.push(PaletteEntry::new(
+                format!("Switch to {}", Self::session_name(state, session)),
+                None,
+                Action::SelectSession {
+                    session_id: session.id.clone(),
+                }
+                .into(),
+            ));
+        }
+
+        entries.extend([
+            PaletteEntry::new(
+                "Run new app",
+                None,
+                if state.launch_configurations.is_empty() {
+                    Action::ShowSelectDevicePopUp.into()
+                } else {
+                    Action::ShowSelectLaunchConfigurationPopup.into()
+                },
+            ),
+            PaletteEntry::new(
+                "Launch configurations",
+                Some((Command::LaunchConfigurations, Focus::Home(Home::Runners))),
+                Action::ShowSelectLaunchConfigurationPopup.into(),
+            ),
+            PaletteEntry::new(
+                "New launch configuration",
+                None,
+                ThunkAction::EditLaunchConfiguration {
+                    index: None,
+                    duplicate: false,
+                }
+                .into(),
+            ),
+            PaletteEntry::new(
+                "Toggle broadcast reload",
+                Some((Command::ToggleBroadcastReload, Focus::Home(Home::Runners))),
+                Action::ToggleBroadcastReload.into(),
+            ),
+        ]);
+
+        for (title, command, action) in [
+            (
+                if state.pane_layout.zoomed {
+                    "Unzoom pane"
+                } else {
+                    "Zoom pane"
+                },
+                Command::Zoom,
+                Action::ToggleZoom,
+            ),
+            (
+                "Narrow sidebar",
+                Command::ShrinkSidebar,
+                Action::ResizeSidebar { delta: -5 },
+            ),
+            (
+                "Widen sidebar",
+                Command::GrowSidebar,
+                Action::ResizeSidebar { delta: 5 },
+            ),
+        ] {
+            entries.push(PaletteEntry {
+                title: title.to_string(),
+                command: Some((command, state.focus.clone())),
+                actions: vec![action.into()],
+            });
+        }
+
+        let enabled = !state.hot_reload_on_save;
+        entries.push(PaletteEntry {
+            title: if enabled {
+                "Enable hot reload on save".to_string()
+            } else {
+                "Disable hot reload on save".to_string()
+            },
+            command: Some((Command::ToggleHotReloadOnSave, Focus::Home(Home::Runners))),
+            actions: vec![
+                Action::SetHotReloadOnSave { enabled }.into(),
+                ThunkAction::WatchFiles.into(),
+            ],
+        });
+
+        for device in &state.devices {
+            entries.push(PaletteEntry {
+                title: format!("Forward port on {}", device.name),
+                command: Some((Command::New, Focus::Home(Home::PortForwards))),
+                actions: vec![
+                    Action::SelectDeviceOrEmulator {
+                        id: device.id.clone(),
+                    }
+                    .into(),
+                    Action::EnterPortForwards.into(),
+                    Action::ShowAddPortForwardPopup.into(),
+                ],
+            });
+        }
+
+        entries.push(PaletteEntry {
+            title: "Create emulator".to_string(),
+            command: Some((Command::NewEmulator, Focus::Home(Home::Devices))),
+            actions: vec![
+                Action::SetFocus {
+                    focus: Focus::Home(Home::Devices),
+                }
+                .into(),
+                Action::ShowCreateEmulatorPopup.into(),
+            ],
+        });
+        for emulator in &state.emulators {
+            for (title, cold_boot) in [("Launch emulator", false), ("Cold boot emulator", true)] {
+                entries.push(PaletteEntry {
+                    title: format!("{} {}", title, emulator.name),
+                    command: None,
+                    actions: vec![
+                        Action::SelectDeviceOrEmulator {
+                            id: emulator.id.clone(),
+                        }
+                        .into(),
+                        ThunkAction::LaunchEmulator { cold_boot }.into(),
+                    ],
+                });
+            }
+        }
+
+        entries
+    }
+
+    /// The entries which match the query, best match first.
+    fn matches(&self, state: &State) -> Vec<PaletteEntry> {
+        let mut matches = Self::entries(state)
+            .into_iter()
+            .filter_map(|entry| Some((fuzzy_match(&self.query, &entry.title)?, entry)))
+            .collect::<Vec<_>>();
+        // Keep the order of the entries for the same score.
+        matches.sort_by_key(|(score, _)| -score);
+        matches.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
+    fn run(&mut self, state: &State) -> Result<()> {
+        let Some(entry) = self.matches(state).into_iter().nth(self.selected) else {
+            return Ok(());
+        };
+        self.send(Action::HideCommandPalette.into())?;
+        for action in entry.actions {
+            self.send(action)?;
+        }
+        Ok(())
+    }
+
+    fn handle_input_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.send(Action::HideCommandPalette.into())?,
+            KeyCode::Enter => self.run(state)?,
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('p') if ctrl => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => self.selected += 1,
+            KeyCode::Char('n') if ctrl => self.selected += 1,
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        let count = self.matches(state).len();
+        self.selected = self.selected.min(count.saturating_sub(1));
+        Ok(())
+    }
+}
+
+impl Component for CommandPaletteComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        if state.popup == Some(PopUp::CommandPalette) {
+            return self.handle_input_key_events(key, state);
+        }
+        if state.popup.is_none() && self.config.command(key, state) == Some(Command::CommandPalette)
+        {
+            self.query.clear();
+            self.selected = 0;
+            self.send(Action::ShowCommandPalette.into())?;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        if state.popup != Some(PopUp::CommandPalette) {
+            return;
+        }
+
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title("Commands")
+            .padding(Padding::horizontal(1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title_bottom(Line::styled(
+                "↑↓: select, enter: run, esc: close",
+                Style::default().fg(self.config.theme.hint),
+            ));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(inner);
+        f.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("> ", Style::default().fg(self.config.theme.key).bold()),
+                Span::raw(format!("{}_", self.query)),
+            ])),
+            layout[0],
+        );
+
+        let width = layout[1].width as usize;
+        let items = self
+            .matches(state)
+            .into_iter()
+            .map(|entry| {
+                let key = entry
+                    .command
+                    .and_then(|(command, focus)| {
+                        let contexts = KeyContext::focus_stack(&focus);
+                        self.config.keybindings.key_for(&contexts, command)
+                    })
+                    .map(|key| key.to_string())
+                    .unwrap_or_default();
+                let padding = width.saturating_sub(entry.title.chars().count() + key.len());
+                ListItem::new(Line::from(vec![
+                    Span::raw(entry.title),
+                    Span::raw(" ".repeat(padding)),
+                    Span::styled(key, Style::default().fg(self.config.theme.key)),
+                ]))
+            })
+            .collect::<Vec<_>>();
+        let empty = items.is_empty();
+
+        self.list_state.select((!empty).then_some(self.selected));
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .add_modifier(Modifier::REVERSED)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_stateful_widget(list, layout[1], &mut self.list_state);
+    }
+}
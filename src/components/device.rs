@@ -6,6 +6,7 @@ use redux_rs::StoreApi;
 use tokio::sync::{mpsc::UnboundedSender, Mutex};
 
 use crate::{
+    config::Config,
     redux::{
         action::Action,
         selector::device_or_emulators::{self, device_or_emulators_selector, DeviceOrEmulator},
@@ -21,6 +22,7 @@ use super::Component;
 
 pub struct DeviceComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
 }
 
 impl Default for DeviceComponent {
@@ -31,11 +33,19 @@ impl Default for DeviceComponent {
 
 impl DeviceComponent {
     pub fn new() -> Self {
-        Self { action_tx: None }
+        Self {
+            action_tx: None,
+            config: Config::default(),
+        }
     }
 }
 
 impl Component for DeviceComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let block = Block::default()
             .padding(Padding::horizontal(1))
@@ -62,49 +72,55 @@ impl Component for DeviceComponent {
             match device_or_emulator {
                 DeviceOrEmulator::Device(device) => vec![
                     Row::new([
-                        Cell::from("ID").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("ID").style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.id.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Name").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Name").style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.name.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Platform").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Platform")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.platform.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Category").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Category")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.category.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Is emulator").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Is emulator")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.emulator.to_string()),
                     ]),
                     Row::new([
-                        Cell::from("Is ephemeral").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Is ephemeral")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.ephemeral.to_string()),
                     ]),
                     Row::new([
-                        Cell::from("SDK").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("SDK").style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(device.sdk.to_string()),
                     ]),
                 ],
                 DeviceOrEmulator::Emulator(emulator) => vec![
                     Row::new([
-                        Cell::from("ID").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("ID").style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(emulator.id.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Name").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Name").style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(emulator.name.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Platform").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Platform")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(emulator.platform_type.clone()),
                     ]),
                     Row::new([
-                        Cell::from("Category").style(Style::default().fg(Color::Yellow).bold()),
+                        Cell::from("Category")
+                            .style(Style::default().fg(self.config.theme.key).bold()),
                         Cell::from(emulator.category.clone()),
                     ]),
                 ],
@@ -1,19 +1,21 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::StoreApi;
 use tokio::sync::{mpsc::UnboundedSender, Mutex};
 
+use crate::config::{Command, Config, KeyContext};
 use crate::{
     redux::{
         action::Action,
         selector::device_or_emulators::{self, device_or_emulators_selector, DeviceOrEmulator},
-        state::{Focus, Home, State},
+        state::{Focus, Home, PopUp, State},
         thunk::ThunkAction,
         ActionOrThunk,
     },
     tui::Frame,
+    utils::list_item_at,
 };
 use color_eyre::eyre::{eyre, Result};
 use daemon::flutter::FlutterDaemon;
@@ -23,7 +25,10 @@ use super::Component;
 #[derive(Default)]
 pub struct DevicesComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     state: ListState,
+    area: Rect,
+    emulator_name_input: String,
 }
 
 impl DevicesComponent {
@@ -47,11 +52,49 @@ impl DevicesComponent {
         Ok(())
     }
 
-    fn launch_emulator(&self) -> Result<()> {
+    fn launch_emulator(&self, cold_boot: bool) -> Result<()> {
         self.action_tx
             .as_ref()
             .ok_or_else(|| eyre!("action_tx is None"))?
-            .send(ThunkAction::LaunchEmulator.into())?;
+            .send(ThunkAction::LaunchEmulator { cold_boot }.into())?;
+        Ok(())
+    }
+
+    fn show_create_emulator_popup(&mut self) -> Result<()> {
+        self.emulator_name_input.clear();
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::ShowCreateEmulatorPopup.into())?;
+        Ok(())
+    }
+
+    fn handle_emulator_name_key_events(&mut self, key: &KeyEvent) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        match key.code {
+            // AVD names may only contain these characters.
+            KeyCode::Char(c) if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.') => {
+                self.emulator_name_input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.emulator_name_input.pop();
+            }
+            KeyCode::Enter => {
+                let name = Some(self.emulator_name_input.trim().to_string())
+                    .filter(|name| !name.is_empty());
+                self.emulator_name_input.clear();
+                action_tx.send(Action::HideCreateEmulatorPopup.into())?;
+                action_tx.send(ThunkAction::CreateEmulator { name }.into())?;
+            }
+            KeyCode::Esc => {
+                self.emulator_name_input.clear();
+                action_tx.send(Action::HideCreateEmulatorPopup.into())?;
+            }
+            _ => {}
+        }
         Ok(())
     }
 }
@@ -62,25 +105,69 @@ impl Component for DevicesComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
-        if state.focus != Focus::Home(Home::Devices) || state.popup.is_some() {
+        if state.focus != Focus::Home(Home::Devices) {
+            return Ok(());
+        }
+        if state.popup == Some(PopUp::CreateEmulator) {
+            return self.handle_emulator_name_key_events(key);
+        }
+        if state.popup.is_some() {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
-            KeyCode::Enter => self.launch_emulator()?,
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Select) => self.launch_emulator(false)?,
+            Some(Command::ColdBoot) => self.launch_emulator(true)?,
+            Some(Command::NewEmulator) => self.show_create_emulator_popup()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                action_tx.send(
+                    Action::SetFocus {
+                        focus: Focus::Home(Home::Devices),
+                    }
+                    .into(),
+                )?;
+                let device_or_emulators = device_or_emulators_selector(state);
+                let rows = vec![1; device_or_emulators.len()];
+                if let Some(index) = list_item_at(self.area, mouse.row, self.state.offset(), rows) {
+                    let id = match &device_or_emulators[index] {
+                        DeviceOrEmulator::Device(device) => device.id.clone(),
+                        DeviceOrEmulator::Emulator(emulator) => emulator.id.clone(),
+                    };
+                    action_tx.send(Action::SelectDeviceOrEmulator { id }.into())?;
+                }
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
             _ => {}
         }
         Ok(())
     }
 
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.area = area;
         let border_color = if state.focus == Focus::Home(Home::Devices) && state.popup.is_none() {
-            Color::Green
+            self.config.theme.focus
         } else {
-            Color::White
+            self.config.theme.border
         };
 
         let device_or_emulators = device_or_emulators_selector(state);
@@ -98,12 +185,33 @@ impl Component for DevicesComponent {
         };
         self.state.select(selected_index);
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title("Devices")
             .padding(Padding::horizontal(1))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(border_color));
+        if state.popup == Some(PopUp::CreateEmulator) {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(
+                    "Emulator name: ",
+                    Style::default().fg(self.config.theme.key),
+                ),
+                Span::raw(self.emulator_name_input.clone()),
+            ]));
+        } else if state.focus == Focus::Home(Home::Devices) {
+            let hint = self.config.keybindings.hint(
+                &KeyContext::focus_stack(&state.focus),
+                &[
+                    (Command::ColdBoot, "cold boot"),
+                    (Command::NewEmulator, "new emulator"),
+                ],
+            );
+            block = block.title_bottom(Line::styled(
+                hint,
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
 
         let items: Vec<ListItem> = device_or_emulators
             .iter()
@@ -119,9 +227,9 @@ impl Component for DevicesComponent {
             ScrollbarState::new(items.len()).position(selected_index.unwrap_or(0));
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
         let list = List::new(items)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(self.config.theme.text))
             .highlight_style(if state.focus == Focus::Home(Home::Devices) {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(self.config.theme.highlight)
             } else {
                 Style::default()
             })
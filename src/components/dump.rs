@@ -0,0 +1,323 @@
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::{DevTools, Focus, PopUp, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::theme::Theme;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+const TREE_CHARACTERS: &[char] = &[
+    ' ', '│', '├', '└', '─', '╎', '┊', '┄', '╘', '╞', '═', '║', '╟', '╚', '╠', '╔',
+];
+
+#[derive(Default)]
+pub struct DumpComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    state: ListState,
+    selected_line: usize,
+}
+
+impl DumpComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
+    fn lines(state: &State) -> Vec<&str> {
+        let Some(session) = current_session_selector(state) else {
+            return vec![];
+        };
+        session
+            .dumps
+            .get(&session.selected_dump_kind)
+            .map(|dump| dump.lines().collect())
+            .unwrap_or_default()
+    }
+
+    fn matches(line: &str, query: &str) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return vec![];
+        }
+        // ASCII lowercasing keeps byte offsets, so the ranges are valid for the original line.
+        line.to_ascii_lowercase()
+            .match_indices(&query.to_ascii_lowercase())
+            .map(|(start, matched)| start..start + matched.len())
+            .collect()
+    }
+
+    fn jump_to_match(&mut self, state: &State, forward: bool) {
+        let query = &state.dump_search_query;
+        let lines = Self::lines(state);
+        let matched_lines = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !Self::matches(line, query).is_empty())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let next = if forward {
+            matched_lines
+                .iter()
+                .find(|index| **index > self.selected_line)
+                .or(matched_lines.first())
+        } else {
+            matched_lines
+                .iter()
+                .rev()
+                .find(|index| **index < self.selected_line)
+                .or(matched_lines.last())
+        };
+        if let Some(next) = next {
+            self.selected_line = *next;
+        }
+    }
+
+    /// Splits a dump line into the tree prefix, property names and object descriptions.
+    fn styled_segments(theme: &Theme, line: &str) -> Vec<(String, Style)> {
+        let body_start = line
+            .char_indices()
+            .find(|(_, c)| !TREE_CHARACTERS.contains(c))
+            .map(|(index, _)| index)
+            .unwrap_or(line.len());
+        let (prefix, body) = line.split_at(body_start);
+
+        let mut segments = vec![(prefix.to_string(), Style::default().fg(theme.hint))];
+        let (name, value) = match body.find(": ") {
+            Some(index) if !body[..index].contains(['(', '<', '"']) => body.split_at(index + 1),
+            _ => ("", body),
+        };
+        if !name.is_empty() {
+            segments.push((name.to_string(), Style::default().fg(theme.key)));
+        }
+        for word in value.split_inclusive(' ') {
+            let is_object =
+                word.contains('#') && word.chars().next().is_some_and(|c| c.is_ascii_alphabetic());
+            let style = if is_object {
+                Style::default().fg(theme.value)
+            } else {
+                Style::default()
+            };
+            match segments.last_mut() {
+                Some((content, last_style)) if *last_style == style => content.push_str(word),
+                _ => segments.push((word.to_string(), style)),
+            }
+        }
+        segments
+    }
+
+    fn highlight<'a>(theme: &Theme, line: &str, query: &str) -> Line<'a> {
+        let matches = Self::matches(line, query);
+        let mut spans = vec![];
+        let mut offset = 0;
+        for (content, style) in Self::styled_segments(theme, line) {
+            let end = offset + content.len();
+            let mut cursor = offset;
+            for range in matches.iter() {
+                let start = range.start.clamp(cursor, end);
+                let stop = range.end.clamp(cursor, end);
+                if start >= stop {
+                    continue;
+                }
+                if cursor < start {
+                    spans.push(Span::styled(line[cursor..start].to_string(), style));
+                }
+                spans.push(Span::styled(
+                    line[start..stop].to_string(),
+                    style.fg(theme.search_match_text).bg(theme.search_match),
+                ));
+                cursor = stop;
+            }
+            if cursor < end {
+                spans.push(Span::styled(line[cursor..end].to_string(), style));
+            }
+            offset = end;
+        }
+        Line::from(spans)
+    }
+
+    fn handle_search_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        let mut query = state.dump_search_query.clone();
+        match key.code {
+            KeyCode::Char(c) => query.push(c),
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Enter => {
+                self.send(Action::HideSearchDumpPopup.into())?;
+                self.jump_to_match(state, true);
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.send(Action::HideSearchDumpPopup.into())?;
+                query.clear();
+            }
+            _ => return Ok(()),
+        }
+        self.send(Action::SetDumpSearchQuery { query }.into())
+    }
+}
+
+impl Component for DumpComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        if state.focus == Focus::DevTools(DevTools::Dumps)
+            && self.config.command(key, state) == Some(Command::Select)
+        {
+            self.selected_line = 0;
+            *self.state.offset_mut() = 0;
+        }
+        if state.focus != Focus::DevTools(DevTools::DumpDetails) {
+            return Ok(());
+        }
+        if state.popup == Some(PopUp::SearchDump) {
+            return self.handle_search_key_events(key, state);
+        }
+        if state.popup.is_some() {
+            return Ok(());
+        }
+
+        let line_count = Self::lines(state).len();
+        match self.config.command(key, state) {
+            Some(Command::Up) => {
+                self.selected_line = self.selected_line.saturating_sub(1);
+            }
+            Some(Command::Down) => {
+                self.selected_line = (self.selected_line + 1).min(line_count.saturating_sub(1));
+            }
+            Some(Command::Top) => self.selected_line = 0,
+            Some(Command::Bottom) => self.selected_line = line_count.saturating_sub(1),
+            Some(Command::Search) => self.send(Action::ShowSearchDumpPopup.into())?,
+            Some(Command::NextMatch) => self.jump_to_match(state, true),
+            Some(Command::PreviousMatch) => self.jump_to_match(state, false),
+            Some(Command::Save) => self.send(ThunkAction::SaveDump.into())?,
+            Some(Command::Back) => self.send(Action::ExitDumpDetails.into())?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.send(
+                Action::SetFocus {
+                    focus: Focus::DevTools(DevTools::DumpDetails),
+                }
+                .into(),
+            )?,
+            MouseEventKind::ScrollUp => self.selected_line = self.selected_line.saturating_sub(1),
+            MouseEventKind::ScrollDown => {
+                let line_count = Self::lines(state).len();
+                self.selected_line = (self.selected_line + 1).min(line_count.saturating_sub(1));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let border_color = if state.focus == Focus::DevTools(DevTools::DumpDetails) {
+            self.config.theme.focus
+        } else {
+            self.config.theme.border
+        };
+        let mut block = Block::default()
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .borders(Borders::ALL);
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+        block = block.title(session.selected_dump_kind.title());
+
+        if state.popup == Some(PopUp::SearchDump) {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled("/", Style::default().fg(self.config.theme.key)),
+                Span::raw(state.dump_search_query.clone()),
+            ]));
+        } else if let Some(path) = &session.saved_dump_path {
+            block = block.title_bottom(Line::from(format!("Saved to {}", path.display())));
+        } else if state.focus == Focus::DevTools(DevTools::DumpDetails) {
+            let hint = self.config.keybindings.hint(
+                &KeyContext::focus_stack(&state.focus),
+                &[
+                    (Command::Search, "search"),
+                    (Command::NextMatch, "next match"),
+                    (Command::PreviousMatch, "previous match"),
+                    (Command::Save, "save to file"),
+                ],
+            );
+            block = block.title_bottom(Line::styled(
+                hint,
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
+
+        let lines = Self::lines(state);
+        if lines.is_empty() {
+            let text = Paragraph::new("Press \"Enter\" to load the dump")
+                .style(Style::default().fg(self.config.theme.hint))
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        self.selected_line = self.selected_line.min(lines.len() - 1);
+        self.state.select(Some(self.selected_line));
+
+        let items = lines
+            .iter()
+            .map(|line| Self::highlight(&self.config.theme, line, &state.dump_search_query))
+            .collect::<Vec<_>>();
+        let mut scrollbar_state = ScrollbarState::new(items.len()).position(self.selected_line);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(if state.focus == Focus::DevTools(DevTools::DumpDetails) {
+                Style::default().bg(self.config.theme.highlight)
+            } else {
+                Style::default()
+            })
+            .highlight_spacing(HighlightSpacing::Never);
+
+        f.render_stateful_widget(list, area, &mut self.state);
+        f.render_stateful_widget(
+            scrollbar,
+            area.inner(&Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config};
+use crate::redux::action::Action;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::{DevTools, DumpKind, Focus, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+#[derive(Default)]
+pub struct DumpsComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    state: ListState,
+}
+
+impl DumpsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::NextDumpKind.into())?;
+        Ok(())
+    }
+
+    fn previous(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::PreviousDumpKind.into())?;
+        Ok(())
+    }
+
+    fn load_dump(&self) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        action_tx.send(ThunkAction::LoadDump.into())?;
+        action_tx.send(Action::EnterDumpDetails.into())?;
+        Ok(())
+    }
+}
+
+impl Component for DumpsComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        if state.focus != Focus::DevTools(DevTools::Dumps) || state.popup.is_some() {
+            return Ok(());
+        }
+
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Select) => self.load_dump()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.action_tx
+                    .as_ref()
+                    .ok_or_else(|| eyre!("action_tx is None"))?
+                    .send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::Dumps),
+                        }
+                        .into(),
+                    )?;
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let border_color =
+            if state.focus == Focus::DevTools(DevTools::Dumps) && state.popup.is_none() {
+                self.config.theme.focus
+            } else {
+                self.config.theme.border
+            };
+        let block = Block::default()
+            .title("Dumps")
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .borders(Borders::ALL);
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+
+        let selected_index = DumpKind::ALL
+            .iter()
+            .position(|kind| kind == &session.selected_dump_kind);
+        self.state.select(selected_index);
+
+        let items = DumpKind::ALL.iter().map(|kind| {
+            let loaded = session.dumps.contains_key(kind);
+            Line::from(vec![
+                Span::styled(
+                    if loaded { "● " } else { "○ " },
+                    Style::default().fg(if loaded {
+                        self.config.theme.success
+                    } else {
+                        self.config.theme.hint
+                    }),
+                ),
+                Span::raw(kind.title()),
+            ])
+        });
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                if state.focus == Focus::DevTools(DevTools::Dumps)
+                    || state.focus == Focus::DevTools(DevTools::DumpDetails)
+                {
+                    Style::default().bg(self.config.theme.highlight)
+                } else {
+                    Style::default()
+                },
+            )
+            .highlight_spacing(HighlightSpacing::Never);
+
+        f.render_stateful_widget(list, area, &mut self.state);
+    }
+}
@@ -4,6 +4,7 @@ use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 
+use crate::config::Config;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::state::{Focus, Home, State};
 use crate::tui::Frame;
@@ -13,7 +14,9 @@ use daemon::flutter::FlutterDaemon;
 use super::Component;
 
 #[derive(Default)]
-pub struct FramesComponent {}
+pub struct FramesComponent {
+    config: Config,
+}
 
 impl FramesComponent {
     pub fn new() -> Self {
@@ -30,6 +33,11 @@ const BAR_WIDTH: u16 = 3;
 const GROUP_GAP: u16 = 1;
 
 impl Component for FramesComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let block = Block::default()
             .title("Frames")
@@ -113,9 +121,9 @@ impl Component for FramesComponent {
                     })
                     .style(Style::default().fg(match session.selected_frame_number {
                         Some(selected_frame_number) if selected_frame_number == frame.number => {
-                            Color::Yellow
+                            self.config.theme.key
                         }
-                        _ => Color::White,
+                        _ => self.config.theme.text,
                     }))
                     .centered(),
                 )
@@ -148,7 +156,7 @@ impl Component for FramesComponent {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(self.config.theme.text)),
         );
 
         f.render_widget(barchart, area);
@@ -0,0 +1,105 @@
+use crossterm::event::KeyEvent;
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::state::{PopUp, State};
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+/// Lists the keys of the focused pane or popup, generated from the keybindings.
+#[derive(Default)]
+pub struct HelpComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+}
+
+impl HelpComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn send(&self, action: Action) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action.into())?;
+        Ok(())
+    }
+}
+
+impl Component for HelpComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        match (self.config.command(key, state), &state.popup) {
+            (Some(Command::Help) | Some(Command::Back), Some(PopUp::Help)) => {
+                self.send(Action::HideHelp)?
+            }
+            (Some(Command::Help), _) => self.send(Action::ShowHelp)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        if state.popup != Some(PopUp::Help) {
+            return;
+        }
+
+        f.render_widget(Clear, area);
+
+        let contexts = KeyContext::stack_for(&state.focus, state.popup_under_help.as_ref());
+        let bindings = self.config.keybindings.bindings(&contexts);
+        let keys = bindings
+            .iter()
+            .map(|(_, keys)| {
+                keys.iter()
+                    .map(|key| key.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        let key_width = keys.iter().map(|keys| keys.len()).max().unwrap_or_default();
+
+        let items = bindings
+            .iter()
+            .zip(keys)
+            .map(|((command, _), keys)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}  ", keys, width = key_width),
+                        Style::default().fg(self.config.theme.key).bold(),
+                    ),
+                    Span::raw(command.description()),
+                ]))
+            })
+            .collect::<Vec<_>>();
+
+        let block = Block::default()
+            .title("Keys")
+            .padding(Padding::horizontal(1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title_bottom(Line::styled(
+                "esc: close",
+                Style::default().fg(self.config.theme.hint),
+            ));
+
+        f.render_widget(List::new(items).block(block), area);
+    }
+}
@@ -1,18 +1,20 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use devtools::protocols::flutter_extension::DiagnosticNode;
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::{Command, Config};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
-use crate::redux::state::{DevTools, Focus, Home, State};
+use crate::redux::state::{DevTools, Focus, Home, RebuildCount, State};
 use crate::redux::thunk::ThunkAction;
 use crate::redux::ActionOrThunk;
+use crate::theme::Theme;
 use crate::tui::Frame;
 use crate::widgets::tree::{Node, Tree, TreeState};
 use color_eyre::eyre::{eyre, Result};
@@ -23,6 +25,7 @@ use super::Component;
 #[derive(Default)]
 pub struct InspectorComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     state: TreeState,
 }
 
@@ -31,20 +34,33 @@ impl InspectorComponent {
         Self::default()
     }
 
-    fn item_builder(item: &DiagnosticNode) -> Node {
+    fn item_builder<'a>(
+        theme: &Theme,
+        item: &'a DiagnosticNode,
+        rebuild_counts: &HashMap<i64, RebuildCount>,
+    ) -> Node<'a> {
+        let mut spans = vec![Span::raw(item.description.clone().unwrap_or_default())];
+        if let Some(count) = item
+            .location_id
+            .and_then(|location_id| rebuild_counts.get(&location_id))
+        {
+            spans.push(Span::styled(
+                format!(" {}/{}", count.last_frame, count.total),
+                Style::default().fg(if count.last_frame > 0 {
+                    theme.warning
+                } else {
+                    theme.hint
+                }),
+            ));
+        }
         if let Some(children) = item.children.as_ref() {
-            let children = children.iter().map(Self::item_builder).collect();
-            Node::new(
-                &item.value_id.clone().unwrap_or_default(),
-                vec![Span::raw(item.description.clone().unwrap_or_default())],
-                children,
-            )
+            let children = children
+                .iter()
+                .map(|child| Self::item_builder(theme, child, rebuild_counts))
+                .collect();
+            Node::new(&item.value_id.clone().unwrap_or_default(), spans, children)
         } else {
-            Node::new(
-                &item.value_id.clone().unwrap_or_default(),
-                vec![Span::raw(item.description.clone().unwrap_or_default())],
-                vec![],
-            )
+            Node::new(&item.value_id.clone().unwrap_or_default(), spans, vec![])
         }
     }
 
@@ -56,7 +72,7 @@ impl InspectorComponent {
         let Some(ref summary_tree) = session.widget_summary_tree else {
             return;
         };
-        let root = Self::item_builder(summary_tree);
+        let root = Self::item_builder(&self.config.theme, summary_tree, &session.rebuild_counts);
         let state = TreeState::new()
             .with_opened(session.opened_widget_value_ids.clone())
             .with_selected(session.selected_widget_value_id.clone());
@@ -108,7 +124,7 @@ impl InspectorComponent {
         let Some(ref summary_tree) = session.widget_summary_tree else {
             return;
         };
-        let root = Self::item_builder(summary_tree);
+        let root = Self::item_builder(&self.config.theme, summary_tree, &session.rebuild_counts);
         let state = TreeState::new()
             .with_opened(session.opened_widget_value_ids.clone())
             .with_selected(session.selected_widget_value_id.clone());
@@ -172,6 +188,14 @@ impl InspectorComponent {
             .unwrap();
     }
 
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
     fn enter_widget_details(&self) -> Result<()> {
         self.action_tx
             .as_ref()
@@ -187,16 +211,63 @@ impl Component for InspectorComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.focus != Focus::DevTools(DevTools::Inspector) || state.popup.is_some() {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.next(state),
-            KeyCode::Char('k') | KeyCode::Up => self.previous(state),
-            KeyCode::Tab => self.toggle(state),
-            KeyCode::Enter => self.enter_widget_details()?,
+        match self.config.command(key, state) {
+            Some(Command::Down) => self.next(state),
+            Some(Command::Up) => self.previous(state),
+            Some(Command::Toggle) => self.toggle(state),
+            Some(Command::Select) => self.enter_widget_details()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if matches!(state.focus, Focus::DevTools(_)) {
+                    self.send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::Inspector),
+                        }
+                        .into(),
+                    )?;
+                }
+                let Some(session) = current_session_selector(state) else {
+                    return Ok(());
+                };
+                let Some((id, on_marker)) = self.state.node_at(mouse.column, mouse.row) else {
+                    return Ok(());
+                };
+                if on_marker {
+                    self.send(
+                        Action::ToggleOpenWidgetValueId {
+                            session_id: session.id.clone(),
+                            id: id.clone(),
+                        }
+                        .into(),
+                    )?;
+                }
+                self.send(
+                    Action::SelectWidgetValueId {
+                        session_id: session.id.clone(),
+                        id: id.clone(),
+                    }
+                    .into(),
+                )?;
+                self.send(ThunkAction::LoadDetailsSubtree { value_id: id }.into())?;
+            }
+            MouseEventKind::ScrollDown => self.next(state),
+            MouseEventKind::ScrollUp => self.previous(state),
             _ => {}
         }
         Ok(())
@@ -205,9 +276,9 @@ impl Component for InspectorComponent {
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let border_color =
             if state.focus == Focus::DevTools(DevTools::Inspector) && state.popup.is_none() {
-                Color::Green
+                self.config.theme.focus
             } else {
-                Color::White
+                self.config.theme.border
             };
         let block = Block::default()
             .title("Flutter Inspector")
@@ -226,12 +297,12 @@ impl Component for InspectorComponent {
             return;
         };
 
-        let root = Self::item_builder(summary_tree);
+        let root = Self::item_builder(&self.config.theme, summary_tree, &session.rebuild_counts);
         let tree = Tree::new(root).block(block).highlight_style(
             if state.focus == Focus::DevTools(DevTools::Inspector)
                 || state.focus == Focus::DevTools(DevTools::WidgetDetails)
             {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(self.config.theme.highlight)
             } else {
                 Style::default()
             },
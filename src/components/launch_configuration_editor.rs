@@ -0,0 +1,275 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::Config;
+use crate::redux::action::Action;
+use crate::redux::state::{
+    LaunchConfigurationForm, LaunchConfigurationSource, NotificationLevel, PopUp, State,
+};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+const MODES: [&str; 4] = ["", "debug", "profile", "release"];
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    File,
+    #[default]
+    Name,
+    Program,
+    Mode,
+    ToolArgs,
+    Args,
+    Cwd,
+}
+
+impl Field {
+    const ALL: [Field; 7] = [
+        Field::File,
+        Field::Name,
+        Field::Program,
+        Field::Mode,
+        Field::ToolArgs,
+        Field::Args,
+        Field::Cwd,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Field::File => "File",
+            Field::Name => "Name",
+            Field::Program => "Program",
+            Field::Mode => "Mode",
+            Field::ToolArgs => "Tool args",
+            Field::Args => "Args",
+            Field::Cwd => "Cwd",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct LaunchConfigurationEditorComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    /// The configuration being edited. Copied from the state when the editor opens.
+    form: Option<LaunchConfigurationForm>,
+    field: Field,
+    config: Config,
+}
+
+impl LaunchConfigurationEditorComponent {
+    pub fn new() -> Self {
+        Self { ..Self::default() }
+    }
+
+    /// The file can only be chosen for new configurations, and only `.vscode/launch.json` has
+    /// arguments for the app.
+    fn fields(form: &LaunchConfigurationForm) -> Vec<Field> {
+        Field::ALL
+            .into_iter()
+            .filter(|field| match field {
+                Field::File => form.index.is_none() && form.duplicate_of.is_none(),
+                Field::Args => form.source == LaunchConfigurationSource::VSCode,
+                _ => true,
+            })
+            .collect()
+    }
+
+    fn move_field(&mut self, offset: isize) {
+        let Some(form) = &self.form else {
+            return;
+        };
+        let fields = Self::fields(form);
+        let current = fields.iter().position(|f| *f == self.field).unwrap_or(0) as isize;
+        let next = (current + offset).rem_euclid(fields.len() as isize) as usize;
+        self.field = fields[next];
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        let form = self.form.as_mut()?;
+        match self.field {
+            Field::Name => Some(&mut form.name),
+            Field::Program => Some(&mut form.program),
+            Field::ToolArgs => Some(&mut form.tool_args),
+            Field::Args => Some(&mut form.args),
+            Field::Cwd => Some(&mut form.cwd),
+            Field::File | Field::Mode => None,
+        }
+    }
+
+    fn toggle(&mut self, forward: bool) {
+        let Some(form) = self.form.as_mut() else {
+            return;
+        };
+        match self.field {
+            Field::File => {
+                form.source = match form.source {
+                    LaunchConfigurationSource::VSCode => LaunchConfigurationSource::Flx,
+                    _ => LaunchConfigurationSource::VSCode,
+                };
+            }
+            Field::Mode => {
+                let current = MODES.iter().position(|m| *m == form.mode).unwrap_or(0);
+                let next = if forward {
+                    (current + 1) % MODES.len()
+                } else {
+                    (current + MODES.len() - 1) % MODES.len()
+                };
+                form.mode = MODES[next].to_string();
+            }
+            _ => {}
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(form) = self.form.clone() else {
+            return Ok(());
+        };
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        if form.name.trim().is_empty() {
+            action_tx.send(
+                Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: "Name is required".to_string(),
+                }
+                .into(),
+            )?;
+            return Ok(());
+        }
+        action_tx.send(ThunkAction::SaveLaunchConfiguration { form }.into())?;
+        Ok(())
+    }
+
+    fn hide(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::HideLaunchConfigurationEditor.into())?;
+        Ok(())
+    }
+
+    fn sync_form(&mut self, state: &State) {
+        if state.popup != Some(PopUp::EditLaunchConfiguration) {
+            self.form = None;
+            return;
+        }
+        if self.form.is_none() {
+            self.form = state.launch_configuration_form.clone();
+            self.field = Field::Name;
+        }
+    }
+}
+
+impl Component for LaunchConfigurationEditorComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        self.sync_form(state);
+        if self.form.is_none() {
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::BackTab => self.move_field(-1),
+            KeyCode::Down | KeyCode::Tab => self.move_field(1),
+            KeyCode::Left => self.toggle(false),
+            KeyCode::Right => self.toggle(true),
+            KeyCode::Char(' ') if self.input().is_none() => self.toggle(true),
+            KeyCode::Char(c) => {
+                if let Some(input) = self.input() {
+                    input.push(c);
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(input) = self.input() {
+                    input.pop();
+                }
+            }
+            KeyCode::Enter => self.save()?,
+            KeyCode::Esc => self.hide()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.sync_form(state);
+        let Some(form) = &self.form else {
+            return;
+        };
+
+        f.render_widget(Clear, area);
+
+        let rows = Self::fields(form)
+            .into_iter()
+            .map(|field| {
+                let value = match field {
+                    Field::File => match form.source {
+                        LaunchConfigurationSource::VSCode => ".vscode/launch.json".to_string(),
+                        _ => "flx.toml".to_string(),
+                    },
+                    Field::Name => form.name.clone(),
+                    Field::Program => form.program.clone(),
+                    Field::Mode => form.mode.clone(),
+                    Field::ToolArgs => form.tool_args.clone(),
+                    Field::Args => form.args.clone(),
+                    Field::Cwd => form.cwd.clone(),
+                };
+                let selected = field == self.field;
+                let value = if selected && matches!(field, Field::File | Field::Mode) {
+                    format!("< {} >", value)
+                } else if selected {
+                    format!("{}_", value)
+                } else {
+                    value
+                };
+                let line = Line::from(vec![
+                    Span::styled(
+                        format!("{:<9} ", field.label()),
+                        Style::default().fg(self.config.theme.key).bold(),
+                    ),
+                    Span::raw(value),
+                ]);
+                if selected {
+                    ListItem::new(line).add_modifier(Modifier::REVERSED)
+                } else {
+                    ListItem::new(line)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let title = if form.index.is_some() {
+            "Edit launch configuration"
+        } else {
+            "New launch configuration"
+        };
+        let block = Block::default()
+            .title(title)
+            .padding(Padding::horizontal(1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title_bottom(Line::styled(
+                "↑↓: field, ←→: change, enter: save, esc: cancel",
+                Style::default().fg(self.config.theme.hint),
+            ));
+
+        f.render_widget(List::new(rows).block(block), area);
+    }
+}
@@ -7,6 +7,7 @@ use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::TuiAction;
+use crate::config::Config;
 use crate::redux::action::Action;
 use crate::redux::selector::selected_device::selected_device_selector;
 use crate::redux::state::{Home, PopUp, State};
@@ -21,6 +22,7 @@ use super::Component;
 #[derive(Default)]
 pub struct LaunchConfigurationsComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
 }
 
 impl LaunchConfigurationsComponent {
@@ -30,6 +32,11 @@ impl LaunchConfigurationsComponent {
 }
 
 impl Component for LaunchConfigurationsComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let items = state
             .launch_configurations
@@ -39,34 +46,71 @@ impl Component for LaunchConfigurationsComponent {
                 let mut items = vec![ListItem::new(format!(" {} ", config.name.clone())).bold()];
                 if let Some(program) = &config.program {
                     items.push(ListItem::new(Line::from(vec![
-                        Span::from("  Program   ").style(Style::default().fg(Color::Yellow)),
+                        Span::from("  Program    ")
+                            .style(Style::default().fg(self.config.theme.key)),
                         Span::from(program),
                     ])));
                 }
                 if let Some(mode) = &config.flutter_mode {
                     items.push(ListItem::new(Line::from(vec![
-                        Span::from("  Mode      ").style(Style::default().fg(Color::Yellow)),
+                        Span::from("  Mode       ")
+                            .style(Style::default().fg(self.config.theme.key)),
                         Span::from(mode),
                     ])));
                 }
                 if let Some(cwd) = &config.cwd {
                     items.push(ListItem::new(Line::from(vec![
-                        Span::from("  Directory ").style(Style::default().fg(Color::Yellow)),
+                        Span::from("  Directory  ")
+                            .style(Style::default().fg(self.config.theme.key)),
                         Span::from(cwd),
                     ])));
                 }
+                if let Some(pre_launch) = &config.pre_launch {
+                    for command in pre_launch {
+                        items.push(ListItem::new(Line::from(vec![
+                            Span::from("  Pre-launch ")
+                                .style(Style::default().fg(self.config.theme.key)),
+                            Span::from(command),
+                        ])));
+                    }
+                }
+                if let Some(device_id) = &config.device_id {
+                    items.push(ListItem::new(Line::from(vec![
+                        Span::from("  Device     ")
+                            .style(Style::default().fg(self.config.theme.key)),
+                        Span::from(device_id),
+                    ])));
+                }
+                if let Some(tool_args) = &config.tool_args {
+                    items.push(ListItem::new(Line::from(vec![
+                        Span::from("  Tool args  ")
+                            .style(Style::default().fg(self.config.theme.key)),
+                        Span::from(tool_args.join(" ")),
+                    ])));
+                }
                 if let Some(args) = &config.args {
                     items.push(ListItem::new(Line::from(vec![
-                        Span::from("  Args      ").style(Style::default().fg(Color::Yellow)),
+                        Span::from("  Args       ")
+                            .style(Style::default().fg(self.config.theme.key)),
                         Span::from(args.join(" ")),
                     ])));
                 }
+                if let Some(env) = &config.env {
+                    // Only the names are shown since the values are often secrets.
+                    let mut names = env.keys().cloned().collect::<Vec<_>>();
+                    names.sort();
+                    items.push(ListItem::new(Line::from(vec![
+                        Span::from("  Env        ")
+                            .style(Style::default().fg(self.config.theme.key)),
+                        Span::from(names.join(", ")),
+                    ])));
+                }
                 items
             })
             .collect::<Vec<_>>();
 
         let block = Block::default()
-            .title("Launch configurations (from .vscode/launch.json)")
+            .title("Launch configurations (from flx.toml and .vscode/launch.json)")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded);
 
@@ -3,18 +3,20 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use color_eyre::owo_colors::OwoColorize;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::{Command, Config};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::selector::current_session_logs::current_session_logs_selector;
 use crate::redux::state::{DevTools, Focus, Home, SessionLog, State};
 use crate::redux::ActionOrThunk;
 use crate::tui::Frame;
+use crate::utils::list_item_at;
 use color_eyre::eyre::{eyre, Result};
 use daemon::flutter::FlutterDaemon;
 
@@ -23,8 +25,12 @@ use super::Component;
 #[derive(Default)]
 pub struct LogsComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     wrapped_logs: HashMap<String, Vec<String>>,
     state: ListState,
+    area: Rect,
+    /// The number of lines of each log, which differs when the logs are wrapped.
+    heights: Vec<usize>,
 }
 
 impl LogsComponent {
@@ -70,19 +76,20 @@ impl Component for LogsComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
-        match key.code {
-            KeyCode::Right | KeyCode::Char('l')
-                if state.focus == Focus::DevTools(DevTools::Network) =>
-            {
+        match self.config.command(key, state) {
+            Some(Command::NextTab) if state.focus == Focus::DevTools(DevTools::Network) => {
                 *self.state.offset_mut() = 0;
             }
-            KeyCode::Left | KeyCode::Char('h')
-                if state.focus == Focus::DevTools(DevTools::Inspector) =>
-            {
+            Some(Command::PreviousTab) if state.focus == Focus::DevTools(DevTools::Inspector) => {
                 *self.state.offset_mut() = 0;
             }
-            KeyCode::Enter
+            Some(Command::Select)
                 if state.focus == Focus::Home(Home::Runners) && state.session_id.is_some() =>
             {
                 *self.state.offset_mut() = 0;
@@ -93,15 +100,51 @@ impl Component for LogsComponent {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if matches!(state.focus, Focus::DevTools(_)) {
+                    action_tx.send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::App),
+                        }
+                        .into(),
+                    )?;
+                }
+                let heights = self.heights.iter().copied();
+                if let Some(index) =
+                    list_item_at(self.area, mouse.row, self.state.offset(), heights)
+                {
+                    action_tx.send(
+                        Action::SelectLog {
+                            index: index as u64,
+                        }
+                        .into(),
+                    )?;
+                }
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
             _ => {}
         }
         Ok(())
     }
 
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.area = area;
+        self.heights.clear();
         let block = Block::default()
             .title("Logs")
             .padding(Padding::horizontal(1))
@@ -144,9 +187,10 @@ impl Component for LogsComponent {
                                 .map(|line| Line::raw(line.to_string()))
                                 .collect::<Vec<_>>();
                             let text = Text::from(lines);
-                            ListItem::new(text).style(Style::default().fg(Color::Red))
+                            ListItem::new(text).style(Style::default().fg(self.config.theme.error))
                         } else {
-                            ListItem::new(line.clone()).style(Style::default().fg(Color::Red))
+                            ListItem::new(line.clone())
+                                .style(Style::default().fg(self.config.theme.error))
                         }
                     }
                     SessionLog::Progress {
@@ -180,13 +224,15 @@ impl Component for LogsComponent {
                 .collect::<Vec<_>>(),
         };
 
+        self.heights = lines.iter().map(ListItem::height).collect();
+
         let mut scrollbar_state = ScrollbarState::new(lines.len()).position(selected_index);
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
 
         let list = List::new(lines)
             .block(block)
             .highlight_style(if state.focus == Focus::DevTools(DevTools::App) {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(self.config.theme.highlight)
             } else {
                 Style::default()
             })
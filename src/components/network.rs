@@ -1,10 +1,12 @@
+use crate::config::{Command, Config};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::state::{DevTools, Focus, Home, State};
 use crate::redux::ActionOrThunk;
 use crate::tui::Frame;
+use crate::utils::list_item_at;
 use color_eyre::eyre::{eyre, Result};
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use daemon::flutter::FlutterDaemon;
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
@@ -19,7 +21,9 @@ use super::Component;
 #[derive(Default)]
 pub struct NetworkComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     state: TableState,
+    area: Rect,
 }
 
 impl NetworkComponent {
@@ -69,14 +73,17 @@ impl Component for NetworkComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
-        match key.code {
-            KeyCode::Right | KeyCode::Char('l')
-                if state.focus == Focus::DevTools(DevTools::Performance) =>
-            {
+        match self.config.command(key, state) {
+            Some(Command::NextTab) if state.focus == Focus::DevTools(DevTools::Dumps) => {
                 *self.state.offset_mut() = 0;
             }
-            KeyCode::Left | KeyCode::Char('h') if state.focus == Focus::DevTools(DevTools::App) => {
+            Some(Command::PreviousTab) if state.focus == Focus::DevTools(DevTools::App) => {
                 *self.state.offset_mut() = 0;
             }
             _ => {}
@@ -85,21 +92,63 @@ impl Component for NetworkComponent {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
-            KeyCode::Enter => self.enter_network_request()?,
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Select) => self.enter_network_request()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if matches!(state.focus, Focus::DevTools(_))
+                    && state.focus != Focus::DevTools(DevTools::Network)
+                {
+                    // The tab is collapsed until it is focused.
+                    if state.focus != Focus::DevTools(DevTools::NetworkRequest) {
+                        *self.state.offset_mut() = 0;
+                    }
+                    action_tx.send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::Network),
+                        }
+                        .into(),
+                    )?;
+                }
+                let Some(session) = current_session_selector(state) else {
+                    return Ok(());
+                };
+                let rows = vec![1; session.requests.len()];
+                if let Some(index) = list_item_at(self.area, mouse.row, self.state.offset(), rows) {
+                    action_tx.send(
+                        Action::SelectRequest {
+                            id: session.requests[index].id.clone(),
+                        }
+                        .into(),
+                    )?;
+                }
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
             _ => {}
         }
         Ok(())
     }
 
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.area = area;
         let border_color =
             if state.focus == Focus::DevTools(DevTools::Network) && state.popup.is_none() {
-                Color::Green
+                self.config.theme.focus
             } else {
-                Color::White
+                self.config.theme.border
             };
         let block = Block::default()
             .title("Network")
@@ -135,10 +184,10 @@ impl Component for NetworkComponent {
                 None => None,
             };
             let status_color = match statu_code {
-                Some(code) if (200..300).contains(&code) => Color::Blue,
-                Some(code) if (300..400).contains(&code) => Color::Yellow,
-                Some(code) if (400..600).contains(&code) => Color::Red,
-                _ => Color::White,
+                Some(code) if (200..300).contains(&code) => self.config.theme.info,
+                Some(code) if (300..400).contains(&code) => self.config.theme.warning,
+                Some(code) if (400..600).contains(&code) => self.config.theme.error,
+                _ => self.config.theme.text,
             };
             let last_uri_path_and_query_string = match Url::parse(&request.uri) {
                 Ok(url) => match url.path_segments() {
@@ -152,11 +201,11 @@ impl Component for NetworkComponent {
                 Err(_) => "-".to_string(),
             };
             let method_color = match request.method.as_str() {
-                "GET" => Color::Green,
-                "POST" => Color::Yellow,
-                "PUT" => Color::Blue,
-                "DELETE" => Color::Red,
-                _ => Color::White,
+                "GET" => self.config.theme.success,
+                "POST" => self.config.theme.warning,
+                "PUT" => self.config.theme.info,
+                "DELETE" => self.config.theme.error,
+                _ => self.config.theme.text,
             };
             let time = match &request.request {
                 Some(req) => match req.events.last() {
@@ -197,7 +246,7 @@ impl Component for NetworkComponent {
                 if state.focus == Focus::DevTools(DevTools::Network)
                     || state.focus == Focus::DevTools(DevTools::NetworkRequest)
                 {
-                    Style::default().bg(Color::DarkGray)
+                    Style::default().bg(self.config.theme.highlight)
                 } else {
                     Style::default()
                 },
@@ -1,3 +1,4 @@
+use crate::config::{Command, Config, KeyContext};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::state::{DevTools, Focus, Home, State};
@@ -6,7 +7,7 @@ use crate::redux::ActionOrThunk;
 use crate::tui::Frame;
 use color_eyre::eyre::{eyre, Result};
 use color_eyre::owo_colors::OwoColorize;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use daemon::flutter::FlutterDaemon;
 use daemon::io::request;
 use devtools::protocols::io_extension::{HttpProfileRequest, HttpProfileRequestRef};
@@ -22,7 +23,7 @@ use url::Url;
 
 use super::Component;
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 enum Tab {
     #[default]
     Headers,
@@ -34,6 +35,7 @@ enum Tab {
 #[derive(Default)]
 pub struct NetworkRequestComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     headers_table_state: TableState,
     payload_list_state: ListState,
     response_list_state: ListState,
@@ -297,23 +299,21 @@ impl NetworkRequestComponent {
         );
 
         let mut rows: Vec<Row> = vec![Row::new(vec![
-            Cell::from(" General").style(Style::default().fg(Color::Yellow).bold())
+            Cell::from(" General").style(Style::default().fg(self.config.theme.key).bold())
         ])];
         for (k, v) in general {
             rows.push(Row::new(vec![Cell::new(format!("   {}", k)), Cell::new(v)]));
         }
         if !response_headers.is_empty() {
-            rows.push(Row::new(vec![
-                Cell::from(" Response Headers").style(Style::default().fg(Color::Yellow).bold())
-            ]));
+            rows.push(Row::new(vec![Cell::from(" Response Headers")
+                .style(Style::default().fg(self.config.theme.key).bold())]));
             for (k, v) in response_headers {
                 rows.push(Row::new(vec![Cell::new(format!("   {}", k)), Cell::new(v)]));
             }
         }
         if !request_headers.is_empty() {
-            rows.push(Row::new(vec![
-                Cell::from(" Request Headers").style(Style::default().fg(Color::Yellow).bold())
-            ]));
+            rows.push(Row::new(vec![Cell::from(" Request Headers")
+                .style(Style::default().fg(self.config.theme.key).bold())]));
             for (k, v) in request_headers {
                 rows.push(Row::new(vec![Cell::new(format!("   {}", k)), Cell::new(v)]));
             }
@@ -325,7 +325,7 @@ impl NetworkRequestComponent {
         let widths = [Constraint::Fill(1), Constraint::Fill(3)];
         let list = Table::new(rows, widths)
             .highlight_spacing(HighlightSpacing::Never)
-            .highlight_style(Style::default().bg(Color::DarkGray));
+            .highlight_style(Style::default().bg(self.config.theme.highlight));
 
         f.render_stateful_widget(list, area, &mut self.headers_table_state);
         f.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
@@ -357,7 +357,7 @@ impl NetworkRequestComponent {
             .position(self.payload_list_state.selected().unwrap_or(0));
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
         let list = List::new(items)
-            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_style(Style::default().bg(self.config.theme.highlight))
             .highlight_spacing(HighlightSpacing::Never);
 
         f.render_stateful_widget(
@@ -397,7 +397,7 @@ impl NetworkRequestComponent {
             .position(self.response_list_state.selected().unwrap_or(0));
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
         let list = List::new(items)
-            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_style(Style::default().bg(self.config.theme.highlight))
             .highlight_spacing(HighlightSpacing::Never);
         f.render_stateful_widget(
             list,
@@ -465,30 +465,36 @@ impl Component for NetworkRequestComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.focus != Focus::DevTools(DevTools::NetworkRequest) || state.popup.is_some() {
             return Ok(());
         }
-        match key.code {
-            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => self.next_tab(),
-            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => self.previous_tab(),
-            KeyCode::Esc => self.exit_network_request()?,
+        let command = self.config.command(key, state);
+        match command {
+            Some(Command::NextTab) => self.next_tab(),
+            Some(Command::PreviousTab) => self.previous_tab(),
+            Some(Command::Back) => self.exit_network_request()?,
             _ => {}
         }
         match self.selected_tab {
-            Tab::Headers => match key.code {
-                KeyCode::Up | KeyCode::Char('k') => self.previous_headers(),
-                KeyCode::Down | KeyCode::Char('j') => self.next_headers(state),
+            Tab::Headers => match command {
+                Some(Command::Up) => self.previous_headers(),
+                Some(Command::Down) => self.next_headers(state),
                 _ => {}
             },
-            Tab::Payload => match key.code {
-                KeyCode::Up | KeyCode::Char('k') => self.previous_payload(),
-                KeyCode::Down | KeyCode::Char('j') => self.next_payload(state),
+            Tab::Payload => match command {
+                Some(Command::Up) => self.previous_payload(),
+                Some(Command::Down) => self.next_payload(state),
                 _ => {}
             },
-            Tab::Response => match key.code {
-                KeyCode::Up | KeyCode::Char('k') => self.previous_response(),
-                KeyCode::Down | KeyCode::Char('j') => self.next_response(state),
+            Tab::Response => match command {
+                Some(Command::Up) => self.previous_response(),
+                Some(Command::Down) => self.next_response(state),
                 _ => {}
             },
             _ => {}
@@ -499,12 +505,36 @@ impl Component for NetworkRequestComponent {
         Ok(())
     }
 
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match (mouse.kind, self.selected_tab) {
+            (MouseEventKind::Down(MouseButton::Left), _) => {
+                self.action_tx
+                    .as_ref()
+                    .ok_or_else(|| eyre!("action_tx is None"))?
+                    .send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::NetworkRequest),
+                        }
+                        .into(),
+                    )?;
+            }
+            (MouseEventKind::ScrollUp, Tab::Headers) => self.previous_headers(),
+            (MouseEventKind::ScrollDown, Tab::Headers) => self.next_headers(state),
+            (MouseEventKind::ScrollUp, Tab::Payload) => self.previous_payload(),
+            (MouseEventKind::ScrollDown, Tab::Payload) => self.next_payload(state),
+            (MouseEventKind::ScrollUp, Tab::Response) => self.previous_response(),
+            (MouseEventKind::ScrollDown, Tab::Response) => self.next_response(state),
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let border_color =
             if state.focus == Focus::DevTools(DevTools::NetworkRequest) && state.popup.is_none() {
-                Color::Green
+                self.config.theme.focus
             } else {
-                Color::White
+                self.config.theme.border
             };
         let empty = Paragraph::new("No request selected")
             .style(Style::default().fg(border_color))
@@ -536,7 +566,13 @@ impl Component for NetworkRequestComponent {
         let full_request = session.full_requests.get(&network_request.id);
 
         let block = Block::default()
-            .title_bottom(Line::from(r#"Press "Tab" to select tabs"#).right_aligned())
+            .title_bottom(
+                Line::from(self.config.keybindings.hint(
+                    &KeyContext::focus_stack(&Focus::DevTools(DevTools::NetworkRequest)),
+                    &[(Command::NextTab, "select tabs")],
+                ))
+                .right_aligned(),
+            )
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(border_color))
             .borders(Borders::ALL);
@@ -551,7 +587,7 @@ impl Component for NetworkRequestComponent {
             .split(block.inner(area));
 
         let tabs = Tabs::new(vec!["Headers", "Payload", "Response", "Timing"])
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(self.config.theme.hint))
             .highlight_style(Style::default().fg(border_color).bold())
             .select(match self.selected_tab {
                 Tab::Headers => 0,
@@ -0,0 +1,89 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::Config;
+use crate::redux::state::{Notification, NotificationLevel, State};
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::Result;
+
+use super::Component;
+
+const NOTIFICATION_DURATION_MS: u128 = 5000;
+const NOTIFICATION_WIDTH: u16 = 50;
+
+/// Shows the latest notifications stacked in the bottom right corner of the screen. Each
+/// notification disappears after a few seconds.
+#[derive(Default)]
+pub struct NotificationsComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+}
+
+impl NotificationsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn visible_notifications(state: &State) -> Vec<&Notification> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        state
+            .notifications
+            .iter()
+            .filter(|n| now.saturating_sub(n.created_at) < NOTIFICATION_DURATION_MS)
+            .collect()
+    }
+}
+
+impl Component for NotificationsComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let width = NOTIFICATION_WIDTH.min(area.width);
+        let mut bottom = area.bottom();
+        for notification in Self::visible_notifications(state).into_iter().rev() {
+            let text_width = width.saturating_sub(4) as usize;
+            let lines = textwrap::wrap(&notification.message, text_width.max(1));
+            let height = lines.len() as u16 + 2;
+            if bottom < area.y + height {
+                break;
+            }
+            bottom -= height;
+            let notification_area = Rect::new(area.right() - width, bottom, width, height);
+
+            let color = match notification.level {
+                NotificationLevel::Info => self.config.theme.text,
+                NotificationLevel::Error => self.config.theme.error,
+            };
+            let block = Block::default()
+                .padding(Padding::horizontal(1))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(color));
+            let text = lines
+                .into_iter()
+                .map(|line| Line::raw(line.to_string()))
+                .collect::<Vec<_>>();
+            f.render_widget(Clear, notification_area);
+            f.render_widget(
+                Paragraph::new(text)
+                    .style(Style::default().fg(color))
+                    .block(block),
+                notification_area,
+            );
+        }
+    }
+}
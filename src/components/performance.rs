@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::{Command, Config};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
 use crate::redux::state::{DevTools, Focus, Home, State};
@@ -19,6 +20,7 @@ use super::Component;
 #[derive(Default)]
 pub struct PerformanceComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     state: ListState,
 }
 
@@ -60,15 +62,18 @@ impl Component for PerformanceComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
-        match key.code {
-            KeyCode::Right | KeyCode::Char('l')
-                if state.focus == Focus::DevTools(DevTools::Inspector) =>
-            {
+        match self.config.command(key, state) {
+            Some(Command::NextTab) if state.focus == Focus::DevTools(DevTools::Inspector) => {
                 *self.state.offset_mut() = 0;
             }
-            KeyCode::Left | KeyCode::Char('h')
-                if state.focus == Focus::DevTools(DevTools::Network) =>
+            Some(Command::PreviousTab)
+                if state.focus == Focus::DevTools(DevTools::RebuildStats) =>
             {
                 *self.state.offset_mut() = 0;
             }
@@ -78,9 +83,33 @@ impl Component for PerformanceComponent {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // The tab is collapsed until it is focused.
+                if state.focus != Focus::DevTools(DevTools::Performance) {
+                    *self.state.offset_mut() = 0;
+                }
+                self.action_tx
+                    .as_ref()
+                    .ok_or_else(|| eyre!("action_tx is None"))?
+                    .send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::Performance),
+                        }
+                        .into(),
+                    )?;
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
             _ => {}
         }
         Ok(())
@@ -89,9 +118,9 @@ impl Component for PerformanceComponent {
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let border_color =
             if state.focus == Focus::DevTools(DevTools::Performance) && state.popup.is_none() {
-                Color::Green
+                self.config.theme.focus
             } else {
-                Color::White
+                self.config.theme.border
             };
         let block = Block::default()
             .title("Performance")
@@ -130,7 +159,7 @@ impl Component for PerformanceComponent {
         let text = List::new(lines)
             .block(block)
             .highlight_style(if state.focus == Focus::DevTools(DevTools::Performance) {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(self.config.theme.highlight)
             } else {
                 Style::default()
             })
@@ -0,0 +1,259 @@
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::selector::device_or_emulators::{device_or_emulators_selector, DeviceOrEmulator};
+use crate::redux::state::{Focus, Home, PopUp, PortForward, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+#[derive(Default)]
+pub struct PortForwardsComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    selected: usize,
+    input: String,
+}
+
+impl PortForwardsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
+    /// Returns the id of the selected device. Emulators which are not running can't forward
+    /// ports, so they are ignored.
+    fn selected_device_id(state: &State) -> Option<String> {
+        let selected_id = state.selected_device_or_emulator_id.as_ref()?;
+        device_or_emulators_selector(state)
+            .into_iter()
+            .find_map(|device_or_emulator| match device_or_emulator {
+                DeviceOrEmulator::Device(device) if &device.id == selected_id => Some(device.id),
+                _ => None,
+            })
+    }
+
+    fn forwards<'a>(state: &'a State, device_id: &str) -> Vec<&'a PortForward> {
+        state
+            .port_forwards
+            .iter()
+            .filter(|forward| forward.device_id == device_id)
+            .collect()
+    }
+
+    /// Parses `<device port>` or `<device port>:<host port>`.
+    fn parse_input(input: &str) -> Option<(u32, Option<u32>)> {
+        match input.trim().split_once(':') {
+            Some((port, host_port)) => Some((port.parse().ok()?, Some(host_port.parse().ok()?))),
+            None => Some((input.trim().parse().ok()?, None)),
+        }
+    }
+
+    fn handle_input_key_events(&mut self, key: &KeyEvent, device_id: String) -> Result<()> {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+                self.input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Enter => {
+                let Some((port, host_port)) = Self::parse_input(&self.input) else {
+                    return Ok(());
+                };
+                self.input.clear();
+                self.send(Action::HideAddPortForwardPopup.into())?;
+                self.send(
+                    ThunkAction::ForwardPort {
+                        device_id,
+                        port,
+                        host_port,
+                    }
+                    .into(),
+                )?;
+            }
+            KeyCode::Esc => {
+                self.input.clear();
+                self.send(Action::HideAddPortForwardPopup.into())?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Component for PortForwardsComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        let Some(device_id) = Self::selected_device_id(state) else {
+            return Ok(());
+        };
+        if state.focus == Focus::Home(Home::Devices)
+            && state.popup.is_none()
+            && self.config.command(key, state) == Some(Command::Select)
+        {
+            self.selected = 0;
+            return self.send(Action::EnterPortForwards.into());
+        }
+        if state.focus != Focus::Home(Home::PortForwards) {
+            return Ok(());
+        }
+        if state.popup == Some(PopUp::AddPortForward) {
+            return self.handle_input_key_events(key, device_id);
+        }
+        if state.popup.is_some() {
+            return Ok(());
+        }
+
+        let forwards = Self::forwards(state, &device_id);
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.selected = self.selected.saturating_sub(1),
+            Some(Command::Down) => {
+                self.selected = (self.selected + 1).min(forwards.len().saturating_sub(1));
+            }
+            Some(Command::New) => {
+                self.input.clear();
+                self.send(Action::ShowAddPortForwardPopup.into())?;
+            }
+            Some(Command::Delete) => {
+                if let Some(forward) = forwards.get(self.selected) {
+                    let forward = (*forward).clone();
+                    self.send(ThunkAction::UnforwardPort { forward }.into())?;
+                    self.selected = self.selected.saturating_sub(1);
+                }
+            }
+            Some(Command::Back) => self.send(Action::ExitPortForwards.into())?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        let Some(device_id) = Self::selected_device_id(state) else {
+            return Ok(());
+        };
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if state.focus != Focus::Home(Home::PortForwards) =>
+            {
+                self.selected = 0;
+                self.send(Action::EnterPortForwards.into())?;
+            }
+            MouseEventKind::ScrollUp => self.selected = self.selected.saturating_sub(1),
+            MouseEventKind::ScrollDown => {
+                let count = Self::forwards(state, &device_id).len();
+                self.selected = (self.selected + 1).min(count.saturating_sub(1));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let focused = state.focus == Focus::Home(Home::PortForwards);
+        let border_color = if focused && state.popup.is_none() {
+            self.config.theme.focus
+        } else {
+            self.config.theme.border
+        };
+        let mut block = Block::default()
+            .title("Port Forwards")
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .borders(Borders::ALL);
+
+        let Some(device_id) = Self::selected_device_id(state) else {
+            let text = Paragraph::new("Launch the device to forward ports")
+                .style(Style::default().fg(self.config.theme.hint))
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        };
+
+        if state.popup == Some(PopUp::AddPortForward) {
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(
+                    "Device port[:host port]: ",
+                    Style::default().fg(self.config.theme.key),
+                ),
+                Span::raw(self.input.clone()),
+            ]));
+        } else if focused {
+            let hint = self.config.keybindings.hint(
+                &KeyContext::focus_stack(&state.focus),
+                &[(Command::New, "add"), (Command::Delete, "remove")],
+            );
+            block = block.title_bottom(Line::styled(
+                hint,
+                Style::default().fg(self.config.theme.hint),
+            ));
+        } else if state.focus == Focus::Home(Home::Devices) {
+            let hint = self.config.keybindings.hint(
+                &KeyContext::focus_stack(&state.focus),
+                &[(Command::Select, "manage port forwards")],
+            );
+            block = block.title_bottom(Line::styled(
+                hint,
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
+
+        let key_style = Style::default().fg(self.config.theme.key).bold();
+        let rows = Self::forwards(state, &device_id)
+            .into_iter()
+            .enumerate()
+            .map(|(index, forward)| {
+                let row = Row::new([
+                    Cell::from(format!("device:{}", forward.port)).style(key_style),
+                    Cell::from("→"),
+                    Cell::from(format!("localhost:{}", forward.host_port)),
+                ]);
+                if focused && index == self.selected {
+                    row.style(Style::default().bg(self.config.theme.highlight))
+                } else {
+                    row
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if rows.is_empty() {
+            let text = Paragraph::new("No port forwards")
+                .style(Style::default().fg(self.config.theme.hint))
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        let widths = [
+            Constraint::Length(16),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths).block(block);
+        f.render_widget(table, area);
+    }
+}
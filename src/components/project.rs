@@ -3,34 +3,53 @@ use std::io::BufReader;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
 
+use crate::config::Config;
+use crate::redux::action::Action;
 use crate::redux::state::{Focus, Home, State};
+use crate::redux::ActionOrThunk;
 use crate::tui::Frame;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use daemon::flutter::FlutterDaemon;
 
 use super::Component;
 
 #[derive(Default)]
 pub struct ProjectComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
     project_root: PathBuf,
     project_name: Option<String>,
     version: Option<String>,
+    config: Config,
 }
 
 impl ProjectComponent {
     pub fn new(project_root: PathBuf) -> Self {
         Self {
+            action_tx: None,
             project_root,
             project_name: None,
             version: None,
+            config: Config::default(),
         }
     }
 }
 
 impl Component for ProjectComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn init(&mut self, area: Rect) -> Result<()> {
         let file = File::open(self.project_root.join("pubspec.yaml"))?;
         let reader = BufReader::new(file);
@@ -42,11 +61,26 @@ impl Component for ProjectComponent {
         Ok(())
     }
 
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            self.action_tx
+                .as_ref()
+                .ok_or_else(|| eyre!("action_tx is None"))?
+                .send(
+                    Action::SetFocus {
+                        focus: Focus::Home(Home::Project),
+                    }
+                    .into(),
+                )?;
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let border_color = if state.focus == Focus::Home(Home::Project) && state.popup.is_none() {
-            Color::Green
+            self.config.theme.focus
         } else {
-            Color::White
+            self.config.theme.border
         };
 
         let block = Block::default()
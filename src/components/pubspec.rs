@@ -3,10 +3,11 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use color_eyre::owo_colors::OwoColorize;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 
+use crate::config::{Command, Config};
 use crate::redux::state::{Focus, Home, State};
 use crate::tui::Frame;
 use color_eyre::eyre::Result;
@@ -25,6 +26,7 @@ pub struct PubspecComponent {
     pub lines: Vec<Vec<(String, Style)>>,
     state: ListState,
     scroll_poition: usize,
+    config: Config,
 }
 
 impl PubspecComponent {
@@ -34,18 +36,28 @@ impl PubspecComponent {
             lines: vec![],
             scroll_poition: 0,
             state: ListState::default(),
+            config: Config::default(),
         }
     }
 }
 
 impl Component for PubspecComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn init(&mut self, area: Rect) -> Result<()> {
         let pubspec_content = fs::read_to_string(self.project_root.join("pubspec.yaml"))?;
         self.lines = vec![];
         let ps = SyntaxSet::load_defaults_newlines();
         let ts = ThemeSet::load_defaults();
         let syntax = ps.find_syntax_by_extension("yaml").unwrap();
-        let mut h = HighlightLines::new(syntax, &ts.themes["Solarized (dark)"]);
+        let theme = ts
+            .themes
+            .get(&self.config.theme.syntax)
+            .unwrap_or(&ts.themes["Solarized (dark)"]);
+        let mut h = HighlightLines::new(syntax, theme);
         for (line_num, line) in LinesWithEndings::from(&pubspec_content).enumerate() {
             let line_styles: Vec<(String, Style)> = h
                 .highlight_line(line, &ps)?
@@ -70,15 +82,15 @@ impl Component for PubspecComponent {
         if state.focus != Focus::Home(Home::Project) || state.popup.is_some() {
             return Ok(());
         }
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+        match self.config.command(key, state) {
+            Some(Command::Up) => {
                 self.scroll_poition = if self.scroll_poition > 0 {
                     self.scroll_poition - 1
                 } else {
                     0
                 };
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            Some(Command::Down) => {
                 self.scroll_poition = if self.scroll_poition < self.lines.len() - 1 {
                     self.scroll_poition + 1
                 } else {
@@ -90,6 +102,18 @@ impl Component for PubspecComponent {
         Ok(())
     }
 
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.scroll_poition = self.scroll_poition.saturating_sub(1),
+            MouseEventKind::ScrollDown => {
+                self.scroll_poition =
+                    (self.scroll_poition + 1).min(self.lines.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let lines = self.lines.iter().map(|items| {
             return Line::from(
@@ -110,10 +134,10 @@ impl Component for PubspecComponent {
             .padding(Padding::horizontal(1))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::White));
+            .border_style(Style::default().fg(self.config.theme.border));
         let text = List::new(lines)
             .block(block)
-            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_style(Style::default().bg(self.config.theme.highlight))
             .highlight_spacing(HighlightSpacing::Never);
 
         f.render_stateful_widget(text, area, &mut self.state);
@@ -0,0 +1,112 @@
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+
+use crate::config::Config;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::State;
+use crate::tui::Frame;
+use color_eyre::eyre::Result;
+
+use super::Component;
+
+#[derive(Default)]
+pub struct RebuildLocationComponent {
+    config: Config,
+}
+
+impl RebuildLocationComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn on_off(&self, enabled: bool) -> Span<'static> {
+        if enabled {
+            Span::styled("On", Style::default().fg(self.config.theme.success))
+        } else {
+            Span::styled("Off", Style::default().fg(self.config.theme.hint))
+        }
+    }
+}
+
+impl Component for RebuildLocationComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let block = Block::default()
+            .title("Rebuild Location")
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .borders(Borders::ALL);
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+
+        let key_style = Style::default().fg(self.config.theme.key).bold();
+        let mut rows = vec![
+            Row::new([
+                Cell::from("Track rebuilds").style(key_style),
+                Cell::from(self.on_off(session.track_rebuild_widgets_enabled)),
+            ]),
+            Row::new([
+                Cell::from("Track repaints").style(key_style),
+                Cell::from(self.on_off(session.track_repaint_widgets_enabled)),
+            ]),
+            Row::new([Cell::from(""), Cell::from("")]),
+        ];
+
+        if let Some(id) = session.selected_rebuild_location_id {
+            let location = session.rebuild_locations.get(&id);
+            let count = session.rebuild_counts.get(&id).cloned().unwrap_or_default();
+            let project_root = format!("file://{}/", state.project_root.display());
+            rows.extend([
+                Row::new([
+                    Cell::from("Widget").style(key_style),
+                    Cell::from(
+                        location
+                            .and_then(|location| location.name.clone())
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                ]),
+                Row::new([
+                    Cell::from("File").style(key_style),
+                    Cell::from(
+                        location
+                            .map(|location| {
+                                location
+                                    .file
+                                    .strip_prefix(&project_root)
+                                    .unwrap_or(&location.file)
+                                    .to_string()
+                            })
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                ]),
+                Row::new([
+                    Cell::from("Line").style(key_style),
+                    Cell::from(
+                        location
+                            .map(|location| format!("{}:{}", location.line, location.column))
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                ]),
+                Row::new([
+                    Cell::from("Last frame").style(key_style),
+                    Cell::from(count.last_frame.to_string()),
+                ]),
+                Row::new([
+                    Cell::from("Total").style(key_style),
+                    Cell::from(count.total.to_string()),
+                ]),
+            ]);
+        }
+
+        let widths = [Constraint::Length(16), Constraint::Fill(1)];
+        let table = Table::new(rows, widths).block(block);
+        f.render_widget(table, area);
+    }
+}
@@ -0,0 +1,224 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::action::Action;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::selector::current_session_rebuild_stats::current_session_rebuild_stats_selector;
+use crate::redux::state::{DevTools, Focus, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+use crate::tui::Frame;
+use color_eyre::eyre::{eyre, Result};
+
+use super::Component;
+
+#[derive(Default)]
+pub struct RebuildStatsComponent {
+    action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    state: TableState,
+}
+
+impl RebuildStatsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::NextRebuildLocation.into())?;
+        Ok(())
+    }
+
+    fn previous(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::PreviousRebuildLocation.into())?;
+        Ok(())
+    }
+
+    fn toggle_track_rebuild_widgets(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(ThunkAction::ToggleTrackRebuildWidgets.into())?;
+        Ok(())
+    }
+
+    fn toggle_track_repaint_widgets(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(ThunkAction::ToggleTrackRepaintWidgets.into())?;
+        Ok(())
+    }
+
+    fn clear(&self, state: &State) -> Result<()> {
+        let Some(session_id) = state.session_id.clone() else {
+            return Ok(());
+        };
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::ClearRebuildCounts { session_id }.into())?;
+        Ok(())
+    }
+}
+
+impl Component for RebuildStatsComponent {
+    fn register_action_handler(&mut self, tx: UnboundedSender<ActionOrThunk>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
+        match self.config.command(key, state) {
+            Some(Command::NextTab) if state.focus == Focus::DevTools(DevTools::Performance) => {
+                *self.state.offset_mut() = 0;
+            }
+            Some(Command::PreviousTab) if state.focus == Focus::DevTools(DevTools::Dumps) => {
+                *self.state.offset_mut() = 0;
+            }
+            _ => {}
+        }
+        if state.focus != Focus::DevTools(DevTools::RebuildStats) || state.popup.is_some() {
+            return Ok(());
+        }
+
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::TrackRebuilds) => self.toggle_track_rebuild_widgets()?,
+            Some(Command::TrackRepaints) => self.toggle_track_repaint_widgets()?,
+            Some(Command::Clear) => self.clear(state)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // The tab is collapsed until it is focused.
+                if state.focus != Focus::DevTools(DevTools::RebuildStats) {
+                    *self.state.offset_mut() = 0;
+                }
+                self.action_tx
+                    .as_ref()
+                    .ok_or_else(|| eyre!("action_tx is None"))?
+                    .send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::RebuildStats),
+                        }
+                        .into(),
+                    )?;
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let border_color =
+            if state.focus == Focus::DevTools(DevTools::RebuildStats) && state.popup.is_none() {
+                self.config.theme.focus
+            } else {
+                self.config.theme.border
+            };
+        let block = Block::default()
+            .title("Rebuild Stats")
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .borders(Borders::ALL);
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+
+        if !session.track_rebuild_widgets_enabled && session.rebuild_counts.is_empty() {
+            // Looked up in this pane, which binds the key, even while another pane is focused.
+            let hint = self.config.keybindings.hint(
+                &KeyContext::focus_stack(&Focus::DevTools(DevTools::RebuildStats)),
+                &[(Command::TrackRebuilds, "start tracking widget rebuilds")],
+            );
+            let text = Paragraph::new(hint)
+                .style(Style::default().fg(self.config.theme.hint))
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        let stats = current_session_rebuild_stats_selector(state);
+        let selected_index = stats
+            .iter()
+            .position(|(id, _)| Some(*id) == session.selected_rebuild_location_id);
+        self.state.select(selected_index);
+
+        let widths = [
+            Constraint::Fill(1),
+            Constraint::Length(5),
+            Constraint::Length(7),
+        ];
+        let rows = stats.iter().map(|(id, count)| {
+            let name = session
+                .rebuild_locations
+                .get(id)
+                .and_then(|location| location.name.clone())
+                .unwrap_or_else(|| format!("#{}", id));
+            Row::new(vec![
+                Cell::from(name),
+                Cell::from(Span::styled(
+                    format!("{: >5}", count.last_frame),
+                    Style::default().fg(if count.last_frame > 0 {
+                        self.config.theme.warning
+                    } else {
+                        self.config.theme.hint
+                    }),
+                )),
+                Cell::from(format!("{: >7}", count.total)),
+            ])
+        });
+
+        let mut scrollbar_state =
+            ScrollbarState::new(stats.len()).position(selected_index.unwrap_or(0));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Widget", " Last", "  Total"])
+                    .style(Style::default().fg(self.config.theme.key).bold()),
+            )
+            .block(block)
+            .highlight_style(if state.focus == Focus::DevTools(DevTools::RebuildStats) {
+                Style::default().bg(self.config.theme.highlight)
+            } else {
+                Style::default()
+            })
+            .highlight_spacing(HighlightSpacing::Never);
+
+        f.render_stateful_widget(table, area, &mut self.state);
+        f.render_stateful_widget(
+            scrollbar,
+            area.inner(&Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+}
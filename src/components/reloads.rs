@@ -0,0 +1,84 @@
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+
+use crate::config::Config;
+use crate::redux::selector::current_session::current_session_selector;
+use crate::redux::state::State;
+use crate::tui::Frame;
+use color_eyre::eyre::Result;
+
+use super::Component;
+
+/// Shows the latest hot reloads and hot restarts of the current session with their durations.
+#[derive(Default)]
+pub struct ReloadsComponent {
+    config: Config,
+}
+
+impl ReloadsComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Component for ReloadsComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let mut block = Block::default()
+            .title("Reloads")
+            .borders(Borders::ALL)
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded);
+
+        let Some(session) = current_session_selector(state) else {
+            f.render_widget(block, area);
+            return;
+        };
+
+        let reloads = session
+            .reloads
+            .iter()
+            .filter(|r| r.success && !r.full_restart)
+            .map(|r| r.duration)
+            .collect::<Vec<_>>();
+        if !reloads.is_empty() {
+            let average = reloads.iter().sum::<u128>() / reloads.len() as u128;
+            block = block.title_bottom(Line::styled(
+                format!("avg reload: {}ms", average),
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
+
+        let rows = session.reloads.iter().rev().map(|reload| {
+            let (mark, color) = if reload.success {
+                ("✓", self.config.theme.success)
+            } else {
+                ("✗", self.config.theme.error)
+            };
+            Row::new(vec![
+                Cell::from(mark).style(Style::default().fg(color)),
+                Cell::from(if reload.full_restart {
+                    "restart"
+                } else {
+                    "reload"
+                })
+                .style(Style::default().fg(self.config.theme.key)),
+                Cell::from(format!("{}ms", reload.duration)),
+                Cell::from(reload.message.clone()),
+            ])
+        });
+
+        let widths = [
+            Constraint::Length(1),
+            Constraint::Length(7),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths).block(block);
+        f.render_widget(table, area);
+    }
+}
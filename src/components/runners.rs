@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{prelude::*, widgets::*};
 use tokio::sync::{mpsc::UnboundedSender, Mutex};
 
+use crate::config::{Command, Config};
 use crate::{
     redux::{
         action::Action,
@@ -12,6 +13,7 @@ use crate::{
         ActionOrThunk,
     },
     tui::Frame,
+    utils::list_item_at,
 };
 use color_eyre::eyre::{self, eyre, Result};
 use daemon::{
@@ -24,6 +26,8 @@ use super::Component;
 
 pub struct RunnersComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    area: Rect,
 }
 
 impl Default for RunnersComponent {
@@ -34,7 +38,11 @@ impl Default for RunnersComponent {
 
 impl RunnersComponent {
     pub fn new() -> Self {
-        Self { action_tx: None }
+        Self {
+            action_tx: None,
+            config: Config::default(),
+            area: Rect::default(),
+        }
     }
 
     fn next(&self) -> Result<()> {
@@ -53,6 +61,22 @@ impl RunnersComponent {
         Ok(())
     }
 
+    fn send(&self, action: ActionOrThunk) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(action)?;
+        Ok(())
+    }
+
+    fn run_new_app(&self, state: &State) -> Result<()> {
+        if state.launch_configurations.is_empty() {
+            self.show_select_device_popup()
+        } else {
+            self.show_select_launch_configuration()
+        }
+    }
+
     fn show_select_device_popup(&self) -> Result<()> {
         self.action_tx
             .as_ref()
@@ -149,6 +173,25 @@ impl RunnersComponent {
         Ok(())
     }
 
+    fn toggle_hot_reload_on_save(&self, state: &State) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        let enabled = !state.hot_reload_on_save;
+        action_tx.send(Action::SetHotReloadOnSave { enabled }.into())?;
+        action_tx.send(ThunkAction::WatchFiles.into())?;
+        Ok(())
+    }
+
+    fn toggle_broadcast_reload(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::ToggleBroadcastReload.into())?;
+        Ok(())
+    }
+
     fn enter_devtools(&self) -> Result<()> {
         self.action_tx
             .as_ref()
@@ -164,51 +207,105 @@ impl Component for RunnersComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.focus != Focus::Home(Home::Runners) || state.popup.is_some() {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Char('r') => self.hot_reload()?,
-            KeyCode::Char('R') => self.hot_restart()?,
-            KeyCode::Char('i') => self.toggle_widget_inspector()?,
-            KeyCode::Char('p') => self.toggle_debug_paint()?,
-            KeyCode::Char('P') => self.toggle_show_performance_overlay()?,
-            KeyCode::Char('b') => self.toggle_debug_paint_baselines()?,
-            KeyCode::Char('I') => self.toggle_invert_overized_images()?,
-            KeyCode::Char('o') => self.toggle_repaint_rainbow()?,
-            KeyCode::Char('s') => self.toggle_slow_animations()?,
-            KeyCode::Char('d') => self.stop_app()?,
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
-            KeyCode::Enter => match state.session_id {
+        match self.config.command(key, state) {
+            Some(Command::HotReload) => self.hot_reload()?,
+            Some(Command::HotRestart) => self.hot_restart()?,
+            Some(Command::ToggleWidgetInspector) => self.toggle_widget_inspector()?,
+            Some(Command::ToggleDebugPaint) => self.toggle_debug_paint()?,
+            Some(Command::TogglePerformanceOverlay) => self.toggle_show_performance_overlay()?,
+            Some(Command::ToggleDebugPaintBaselines) => self.toggle_debug_paint_baselines()?,
+            Some(Command::ToggleInvertOversizedImages) => self.toggle_invert_overized_images()?,
+            Some(Command::ToggleRepaintRainbow) => self.toggle_repaint_rainbow()?,
+            Some(Command::ToggleSlowAnimations) => self.toggle_slow_animations()?,
+            Some(Command::StopApp) => self.stop_app()?,
+            Some(Command::ToggleBroadcastReload) => self.toggle_broadcast_reload()?,
+            Some(Command::ToggleHotReloadOnSave) => self.toggle_hot_reload_on_save(state)?,
+            // Opens the launch configurations even when there is none yet, to create one.
+            Some(Command::LaunchConfigurations) => self.show_select_launch_configuration()?,
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Select) => match state.session_id {
                 Some(_) => self.enter_devtools()?,
-                None => {
-                    if state.launch_configurations.is_empty() {
-                        self.show_select_device_popup()?
-                    } else {
-                        self.show_select_launch_configuration()?;
-                    };
-                }
+                None => self.run_new_app(state)?,
             },
             _ => {}
         }
         Ok(())
     }
 
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.send(
+                    Action::SetFocus {
+                        focus: Focus::Home(Home::Runners),
+                    }
+                    .into(),
+                )?;
+                // The last row is the button to run a new app.
+                let rows = vec![1; state.sessions.len() + 1];
+                match list_item_at(self.area, mouse.row, 0, rows) {
+                    Some(index) if index < state.sessions.len() => self.send(
+                        Action::SelectSession {
+                            session_id: state.sessions[index].id.clone(),
+                        }
+                        .into(),
+                    )?,
+                    Some(_) => self.run_new_app(state)?,
+                    None => {}
+                }
+            }
+            MouseEventKind::ScrollDown => self.next()?,
+            MouseEventKind::ScrollUp => self.previous()?,
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.area = area;
         let border_color = if state.focus == Focus::Home(Home::Runners) && state.popup.is_none() {
-            Color::Green
+            self.config.theme.focus
         } else {
-            Color::White
+            self.config.theme.border
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title("Apps")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(border_color));
+        let modes = [
+            (state.broadcast_reload, "broadcast reload"),
+            (state.hot_reload_on_save, "reload on save"),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, mode)| *mode)
+        .collect::<Vec<_>>();
+        if !modes.is_empty() {
+            block = block.title_bottom(Line::styled(
+                modes.join(", "),
+                Style::default().fg(self.config.theme.warning),
+            ));
+        }
+
+        let mut group_ids: Vec<&String> = vec![];
+        for group_id in state.sessions.iter().filter_map(|s| s.group_id.as_ref()) {
+            if !group_ids.contains(&group_id) {
+                group_ids.push(group_id);
+            }
+        }
 
         let mut items = state
             .sessions
@@ -218,22 +315,31 @@ impl Component for RunnersComponent {
                     .devices
                     .iter()
                     .find(|d| d.id == session.device_id.clone().unwrap_or("".to_string()));
-                let device_name = device.map(|d| d.name.clone()).unwrap_or("".to_string());
+                let device_name = device
+                    .map(|d| d.name.clone())
+                    .or(session.vm_service_uri.clone())
+                    .unwrap_or("".to_string());
                 let status_color = if session.stopped {
-                    Color::Red
+                    self.config.theme.error
                 } else if session.hot_reloading {
-                    Color::Yellow
+                    self.config.theme.warning
                 } else if session.hot_restarting {
-                    Color::LightMagenta
+                    self.config.theme.accent
                 } else if !session.started {
-                    Color::DarkGray
+                    self.config.theme.hint
                 } else {
-                    Color::White
+                    self.config.theme.text
                 };
+                let group = session
+                    .group_id
+                    .as_ref()
+                    .and_then(|group_id| group_ids.iter().position(|id| *id == group_id))
+                    .map(|index| format!(" #{}", index + 1))
+                    .unwrap_or_default();
                 let name = if session.stopped {
-                    format!(" {} [STOPPED] ", device_name)
+                    format!(" {}{} [STOPPED] ", device_name, group)
                 } else {
-                    format!(" {} ", device_name)
+                    format!(" {}{} ", device_name, group)
                 };
                 let item = ListItem::new(name).style(Style::default().fg(status_color));
                 if state.focus == Focus::Home(Home::Runners)
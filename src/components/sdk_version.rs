@@ -7,6 +7,7 @@ use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::TuiAction;
+use crate::config::Config;
 use crate::redux::action::Action;
 use crate::redux::selector::selected_device::selected_device_selector;
 use crate::redux::state::{Home, PopUp, State};
@@ -19,7 +20,9 @@ use daemon::flutter::FlutterDaemon;
 use super::Component;
 
 #[derive(Default)]
-pub struct SdkVersionComponent {}
+pub struct SdkVersionComponent {
+    config: Config,
+}
 
 impl SdkVersionComponent {
     pub fn new() -> Self {
@@ -28,6 +31,11 @@ impl SdkVersionComponent {
 }
 
 impl Component for SdkVersionComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
         let block = Block::default()
             .title("SDK Version")
@@ -44,39 +52,40 @@ impl Component for SdkVersionComponent {
 
         let rows = vec![
             Row::new(vec![
-                Cell::from("Flutter Version").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Flutter Version").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.flutter_version.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Framework Version").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Framework Version").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.framework_version.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Channel").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Channel").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.channel.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Repository URL").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Repository URL").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.repository_url.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Framework Revision").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Framework Revision").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.framework_revision.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Framework Commit Date").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Framework Commit Date")
+                    .style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.framework_commit_date.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Engine Revision").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Engine Revision").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.engine_revision.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Dart SDK Version").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Dart SDK Version").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.dart_sdk_version.clone()),
             ]),
             Row::new(vec![
-                Cell::from("Flutter Root").style(Style::default().fg(Color::Yellow)),
+                Cell::from("Flutter Root").style(Style::default().fg(self.config.theme.key)),
                 Cell::from(version.flutter_root.clone()),
             ]),
         ];
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::TuiAction;
+use crate::config::{Command, Config, KeyContext};
 use crate::redux::action::Action;
 use crate::redux::selector::availale_devices::available_devices_selector;
 use crate::redux::selector::selected_device::selected_device_selector;
@@ -23,6 +24,7 @@ use super::Component;
 pub struct SelectDevicePopupComponent {
     use_fvm: bool,
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
 }
 
 impl SelectDevicePopupComponent {
@@ -56,12 +58,21 @@ impl SelectDevicePopupComponent {
             .send(
                 ThunkAction::RunNewApp {
                     use_fvm: self.use_fvm,
+                    target: None,
                 }
                 .into(),
             )?;
         Ok(())
     }
 
+    fn toggle_device(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(Action::ToggleDeviceForRunning.into())?;
+        Ok(())
+    }
+
     fn hide_popup(&self) -> Result<()> {
         self.action_tx
             .as_ref()
@@ -77,19 +88,25 @@ impl Component for SelectDevicePopupComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.popup != Some(PopUp::SelectDevice) {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
-            KeyCode::Enter => {
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Toggle) => self.toggle_device()?,
+            Some(Command::Select) => {
                 self.hide_popup()?;
                 self.run_new_app()?;
             }
-            KeyCode::Esc => self.hide_popup()?,
+            Some(Command::Back) => self.hide_popup()?,
 
             _ => {}
         }
@@ -105,9 +122,16 @@ impl Component for SelectDevicePopupComponent {
 
         let devices = available_devices_selector(state);
 
+        let checked_device_ids = &state.select_device_popup.checked_device_ids;
         let items = devices
             .map(|device| {
-                let item = ListItem::new(format!(" {} ", device.name.clone()));
+                let item = if checked_device_ids.is_empty() {
+                    ListItem::new(format!(" {} ", device.name.clone()))
+                } else if checked_device_ids.contains(&device.id) {
+                    ListItem::new(format!(" [x] {} ", device.name.clone()))
+                } else {
+                    ListItem::new(format!(" [ ] {} ", device.name.clone()))
+                };
                 if let Some(selected_device_id) = &state.select_device_popup.selected_device_id {
                     if selected_device_id == &device.id {
                         return item
@@ -123,7 +147,14 @@ impl Component for SelectDevicePopupComponent {
             .title("Which device do you want to use?")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Green));
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title_bottom(Line::styled(
+                self.config.keybindings.hint(
+                    &KeyContext::stack(state),
+                    &[(Command::Toggle, "select multiple devices")],
+                ),
+                Style::default().fg(self.config.theme.hint),
+            ));
 
         let list = List::new(items).block(block);
 
@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::TuiAction;
+use crate::config::{Command, Config, KeyContext};
 use crate::redux::action::Action;
 use crate::redux::selector::selected_device::selected_device_selector;
-use crate::redux::state::{Home, PopUp, State};
+use crate::redux::state::{Home, LaunchConfigurationSource, PopUp, State};
 use crate::redux::thunk::ThunkAction;
 use crate::redux::ActionOrThunk;
 use crate::tui::Frame;
@@ -21,6 +22,9 @@ use super::Component;
 #[derive(Default)]
 pub struct SelectLaunchConfigurationPopupComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
+    /// Index of the configuration to delete when delete is pressed again.
+    pending_delete: Option<usize>,
 }
 
 impl SelectLaunchConfigurationPopupComponent {
@@ -52,6 +56,22 @@ impl SelectLaunchConfigurationPopupComponent {
         Ok(())
     }
 
+    fn edit(&self, index: Option<usize>, duplicate: bool) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(ThunkAction::EditLaunchConfiguration { index, duplicate }.into())?;
+        Ok(())
+    }
+
+    fn delete(&self, index: usize) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(ThunkAction::DeleteLaunchConfiguration { index }.into())?;
+        Ok(())
+    }
+
     fn hide_popup(&self) -> Result<()> {
         self.action_tx
             .as_ref()
@@ -67,19 +87,39 @@ impl Component for SelectLaunchConfigurationPopupComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.popup != Some(PopUp::SelectLaunchConfiguration) {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => self.previous()?,
-            KeyCode::Down | KeyCode::Char('j') => self.next()?,
-            KeyCode::Enter => {
+        let selected_index = state.select_launch_configuration_poopup.selected_index;
+        // Any other key cancels the deletion.
+        let pending_delete = self.pending_delete.take();
+        match self.config.command(key, state) {
+            Some(Command::Up) => self.previous()?,
+            Some(Command::Down) => self.next()?,
+            Some(Command::Select) => {
                 self.hide_popup()?;
                 self.show_select_device_popup()?;
             }
-            KeyCode::Esc => self.hide_popup()?,
+            Some(Command::New) => self.edit(None, false)?,
+            Some(Command::Edit) if selected_index.is_some() => self.edit(selected_index, false)?,
+            Some(Command::Duplicate) if selected_index.is_some() => {
+                self.edit(selected_index, true)?
+            }
+            Some(Command::Delete) if selected_index.is_some() => {
+                if pending_delete == selected_index {
+                    self.delete(pending_delete.unwrap_or_default())?;
+                } else {
+                    self.pending_delete = selected_index;
+                }
+            }
+            Some(Command::Back) => self.hide_popup()?,
             _ => {}
         }
         Ok(())
@@ -97,7 +137,15 @@ impl Component for SelectLaunchConfigurationPopupComponent {
             .iter()
             .enumerate()
             .map(|(index, config)| {
-                let item = ListItem::new(format!(" {} ", config.name.clone()));
+                let source = match config.source {
+                    LaunchConfigurationSource::Flx => "flx.toml",
+                    LaunchConfigurationSource::VSCode => "launch.json",
+                    LaunchConfigurationSource::Discovered => "flavor",
+                };
+                let item = ListItem::new(Line::from(vec![
+                    Span::raw(format!(" {} ", config.name)),
+                    Span::styled(source, Style::default().fg(self.config.theme.hint)),
+                ]));
                 if state.select_launch_configuration_poopup.selected_index == Some(index) {
                     item.add_modifier(Modifier::REVERSED)
                         .add_modifier(Modifier::BOLD)
@@ -107,11 +155,43 @@ impl Component for SelectLaunchConfigurationPopupComponent {
             })
             .collect::<Vec<_>>();
 
+        let pending_delete = self.pending_delete.and_then(|index| {
+            state
+                .launch_configurations
+                .get(index)
+                .filter(|_| state.select_launch_configuration_poopup.selected_index == Some(index))
+        });
+        let contexts = KeyContext::stack(state);
+        let hint = match pending_delete {
+            Some(configuration) => Line::styled(
+                format!(
+                    "Delete {}? {}, any other key: cancel",
+                    configuration.name,
+                    self.config
+                        .keybindings
+                        .hint(&contexts, &[(Command::Delete, "delete")])
+                ),
+                Style::default().fg(self.config.theme.error),
+            ),
+            None => Line::styled(
+                self.config.keybindings.hint(
+                    &contexts,
+                    &[
+                        (Command::New, "new"),
+                        (Command::Edit, "edit"),
+                        (Command::Duplicate, "duplicate"),
+                        (Command::Delete, "delete"),
+                    ],
+                ),
+                Style::default().fg(self.config.theme.hint),
+            ),
+        };
         let block = Block::default()
             .title("Select launch configuration")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Green));
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title_bottom(hint);
 
         let list = List::new(items).block(block);
 
@@ -1,7 +1,8 @@
 use std::{collections::HashMap, time::Duration};
 
+use crate::config::{Command, Config};
 use color_eyre::eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 use ratatui::{prelude::*, widgets::*};
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::UnboundedSender;
@@ -19,6 +20,7 @@ use crate::{
 #[derive(Default)]
 pub struct SelectTabControllerComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
 }
 
 impl SelectTabControllerComponent {
@@ -73,23 +75,30 @@ impl Component for SelectTabControllerComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
         if state.popup.is_some() {
             return Ok(());
         }
         match state.focus {
-            Focus::Home(_) => match key.code {
-                KeyCode::Left | KeyCode::Char('h') => self.previous_home_tab()?,
-                KeyCode::Right | KeyCode::Char('l') => self.next_home_tab()?,
+            Focus::Home(_) => match self.config.command(key, state) {
+                Some(Command::PreviousTab) => self.previous_home_tab()?,
+                Some(Command::NextTab) => self.next_home_tab()?,
                 _ => {}
             },
-            Focus::DevTools(_) => match key.code {
-                KeyCode::Left | KeyCode::Char('h') => self.previous_devtools_tab()?,
-                KeyCode::Right | KeyCode::Char('l') => self.next_devtools_tab()?,
-                KeyCode::Esc => match state.focus {
+            Focus::DevTools(_) => match self.config.command(key, state) {
+                Some(Command::PreviousTab) => self.previous_devtools_tab()?,
+                Some(Command::NextTab) => self.next_devtools_tab()?,
+                Some(Command::Back) => match state.focus {
                     Focus::DevTools(state::DevTools::App)
                     | Focus::DevTools(state::DevTools::Inspector)
                     | Focus::DevTools(state::DevTools::Performance)
+                    | Focus::DevTools(state::DevTools::RebuildStats)
+                    | Focus::DevTools(state::DevTools::Dumps)
                     | Focus::DevTools(state::DevTools::Network) => {
                         self.exit_devtools()?;
                     }
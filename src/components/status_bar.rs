@@ -0,0 +1,64 @@
+use ratatui::prelude::Rect;
+use ratatui::{prelude::*, widgets::*};
+
+use crate::config::{Command, Config, KeyContext};
+use crate::redux::state::State;
+use crate::tui::Frame;
+use color_eyre::eyre::Result;
+
+use super::Component;
+
+/// Shows the keys of the focused pane or popup in a line at the bottom of the screen.
+#[derive(Default)]
+pub struct StatusBarComponent {
+    config: Config,
+}
+
+impl StatusBarComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Component for StatusBarComponent {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let mut bindings = self
+            .config
+            .keybindings
+            .bindings(&KeyContext::stack(state))
+            .into_iter()
+            .filter(|(command, _)| !command.is_navigation())
+            .collect::<Vec<_>>();
+        // Help is always shown first, so that the rest of the keys can be found.
+        if let Some(index) = bindings.iter().position(|(c, _)| *c == Command::Help) {
+            let help = bindings.remove(index);
+            bindings.insert(0, help);
+        }
+
+        let mut spans = vec![Span::raw(" ")];
+        let mut width = 1;
+        for (command, keys) in bindings {
+            let key = keys[0].to_string();
+            let description = command.description();
+            width += key.len() + description.len() + 3;
+            if width > area.width as usize {
+                break;
+            }
+            spans.push(Span::styled(
+                key,
+                Style::default().fg(self.config.theme.key).bold(),
+            ));
+            spans.push(Span::styled(
+                format!(" {}  ", description),
+                Style::default().fg(self.config.theme.hint),
+            ));
+        }
+
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
@@ -1,42 +1,63 @@
 use std::collections::HashSet;
+use std::io::Write;
 use std::sync::Arc;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::cursor::MoveTo;
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::queue;
 use devtools::protocols::flutter_extension::DiagnosticNode;
+use image::{ImageFormat, RgbaImage};
 use ratatui::prelude::Rect;
 use ratatui::{prelude::*, widgets::*};
 use redux_rs::Selector;
 use tokio::sync::mpsc::UnboundedSender;
 
+use crate::action::TuiAction;
+use crate::config::{Command, Config, KeyContext};
 use crate::redux::action::Action;
 use crate::redux::selector::current_session::current_session_selector;
-use crate::redux::state::{DevTools, Focus, Home, SessionState, State};
+use crate::redux::state::{DevTools, Focus, Home, SessionState, State, WidgetScreenshot};
 use crate::redux::thunk::ThunkAction;
 use crate::redux::ActionOrThunk;
-use crate::tui::Frame;
+use crate::theme::Theme;
+use crate::tui::{Frame, Tui};
+use crate::widgets::graphics::{
+    cell_size, fit_size, kitty_delete_sequence, kitty_sequence, sixel_sequence, GraphicsProtocol,
+    HalfBlockImage,
+};
 use crate::widgets::tree::{Node, Tree, TreeState};
 use color_eyre::eyre::{eyre, Result};
 use daemon::flutter::FlutterDaemon;
 
 use super::Component;
 
+const KITTY_IMAGE_ID: u32 = 1;
+
 #[derive(Default)]
 pub struct WidgetDetailsComponent {
     action_tx: Option<UnboundedSender<ActionOrThunk>>,
+    config: Config,
     state: TreeState,
+    protocol: GraphicsProtocol,
+    preview: Option<(Arc<Vec<u8>>, RgbaImage)>,
+    preview_area: Option<Rect>,
+    placement: Option<(Arc<Vec<u8>>, Rect)>,
 }
 
 impl WidgetDetailsComponent {
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            protocol: GraphicsProtocol::detect(),
+            ..Self::default()
+        }
     }
 
-    fn item_builder(item: &DiagnosticNode) -> Node {
+    fn item_builder<'a>(theme: &Theme, item: &'a DiagnosticNode) -> Node<'a> {
         let value_id = item.value_id.clone().unwrap_or_default();
 
         let spans = if let Some(name) = item.name.clone() {
             vec![
-                Span::styled(format!("{}: ", name), Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}: ", name), Style::default().fg(theme.key)),
                 Span::raw(item.description.clone().unwrap_or_default()),
             ]
         } else {
@@ -49,11 +70,15 @@ impl WidgetDetailsComponent {
         let mut child_nodes: Vec<Node> = vec![];
 
         if let Some(properties) = item.properties.as_ref() {
-            child_nodes.extend(properties.iter().map(Self::item_builder));
+            child_nodes.extend(
+                properties
+                    .iter()
+                    .map(|item| Self::item_builder(theme, item)),
+            );
         }
 
         if let Some(children) = item.children.as_ref() {
-            child_nodes.extend(children.iter().map(Self::item_builder));
+            child_nodes.extend(children.iter().map(|item| Self::item_builder(theme, item)));
         }
 
         Node::new(&value_id, spans, child_nodes)
@@ -67,7 +92,7 @@ impl WidgetDetailsComponent {
         let Some(ref details_tree) = session.selected_widget_details_tree else {
             return;
         };
-        let root = Self::item_builder(details_tree);
+        let root = Self::item_builder(&self.config.theme, details_tree);
         let state = TreeState::new()
             .with_opened(session.opened_widget_details_value_ids.clone())
             .with_selected(self.state.selected());
@@ -104,7 +129,7 @@ impl WidgetDetailsComponent {
         let Some(ref details_tree) = session.selected_widget_details_tree else {
             return;
         };
-        let root = Self::item_builder(details_tree);
+        let root = Self::item_builder(&self.config.theme, details_tree);
         let state = TreeState::new()
             .with_opened(session.opened_widget_details_value_ids.clone())
             .with_selected(self.state.selected());
@@ -153,6 +178,99 @@ impl WidgetDetailsComponent {
             .unwrap();
     }
 
+    fn toggle_preview(&self, state: &State) -> Result<()> {
+        let action_tx = self
+            .action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?;
+        action_tx.send(Action::ToggleWidgetPreview.into())?;
+        if state.widget_preview_enabled {
+            return Ok(());
+        }
+        if let Some(value_id) =
+            current_session_selector(state).and_then(|s| s.selected_widget_value_id.clone())
+        {
+            action_tx.send(ThunkAction::LoadWidgetScreenshot { value_id }.into())?;
+        }
+        Ok(())
+    }
+
+    fn save_screenshot(&self) -> Result<()> {
+        self.action_tx
+            .as_ref()
+            .ok_or_else(|| eyre!("action_tx is None"))?
+            .send(ThunkAction::SaveWidgetScreenshot.into())?;
+        Ok(())
+    }
+
+    fn decode_screenshot(&mut self, screenshot: &WidgetScreenshot) -> Option<&RgbaImage> {
+        let cached = matches!(&self.preview, Some((png, _)) if Arc::ptr_eq(png, &screenshot.png));
+        if !cached {
+            self.preview = None;
+            match image::load_from_memory_with_format(&screenshot.png, ImageFormat::Png) {
+                Ok(image) => self.preview = Some((screenshot.png.clone(), image.to_rgba8())),
+                Err(err) => log::error!("Failed to decode widget screenshot: {:?}", err),
+            }
+        }
+        self.preview.as_ref().map(|(_, image)| image)
+    }
+
+    fn draw_preview(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        let session = current_session_selector(state);
+        let hint = if let Some(path) = session.and_then(|s| s.saved_screenshot_path.as_ref()) {
+            format!("Saved to {}", path.display())
+        } else {
+            self.config.keybindings.hint(
+                &KeyContext::focus_stack(&Focus::DevTools(DevTools::WidgetDetails)),
+                &[
+                    (Command::TogglePreview, "hide preview"),
+                    (Command::Save, "save png"),
+                ],
+            )
+        };
+        let block = Block::default()
+            .title("Preview")
+            .title_bottom(Line::from(hint).style(Style::default().fg(self.config.theme.hint)))
+            .padding(Padding::horizontal(1))
+            .border_style(Style::default().fg(self.config.theme.border))
+            .border_type(BorderType::Rounded)
+            .borders(Borders::ALL);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let Some(session) = session else {
+            return;
+        };
+        let Some(ref selected_widget_value_id) = session.selected_widget_value_id else {
+            return;
+        };
+        let screenshot = session
+            .widget_screenshot
+            .as_ref()
+            .filter(|screenshot| &screenshot.value_id == selected_widget_value_id);
+        let Some(screenshot) = screenshot else {
+            f.render_widget(
+                Paragraph::new("Loading...").fg(self.config.theme.hint),
+                inner,
+            );
+            return;
+        };
+
+        let protocol = self.protocol;
+        let Some(image) = self.decode_screenshot(screenshot) else {
+            f.render_widget(
+                Paragraph::new("Failed to decode screenshot").fg(self.config.theme.hint),
+                inner,
+            );
+            return;
+        };
+        if protocol == GraphicsProtocol::HalfBlocks {
+            f.render_widget(HalfBlockImage::new(image), inner);
+        } else {
+            self.preview_area = Some(inner);
+        }
+    }
+
     fn exit_widget_details(&mut self) -> Result<()> {
         self.state.selected.clone_from(&None);
         self.action_tx
@@ -169,27 +287,93 @@ impl Component for WidgetDetailsComponent {
         Ok(())
     }
 
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
     fn handle_key_events(&mut self, key: &KeyEvent, state: &State) -> Result<()> {
-        if state.focus != Focus::DevTools(DevTools::WidgetDetails) || state.popup.is_some() {
+        if !matches!(
+            state.focus,
+            Focus::DevTools(DevTools::Inspector) | Focus::DevTools(DevTools::WidgetDetails)
+        ) || state.popup.is_some()
+        {
             return Ok(());
         }
 
-        match key.code {
-            KeyCode::Esc => self.exit_widget_details()?,
-            KeyCode::Char('j') | KeyCode::Down => self.next(state),
-            KeyCode::Char('k') | KeyCode::Up => self.previous(state),
-            KeyCode::Tab => self.toggle(state),
+        let command = self.config.command(key, state);
+        match command {
+            Some(Command::TogglePreview) => self.toggle_preview(state)?,
+            Some(Command::Save) => self.save_screenshot()?,
+            _ => {}
+        }
+
+        if state.focus != Focus::DevTools(DevTools::WidgetDetails) {
+            return Ok(());
+        }
+
+        match command {
+            Some(Command::Back) => self.exit_widget_details()?,
+            Some(Command::Down) => self.next(state),
+            Some(Command::Up) => self.previous(state),
+            Some(Command::Toggle) => self.toggle(state),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_mouse_events(&mut self, mouse: &MouseEvent, state: &State) -> Result<()> {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.action_tx
+                    .as_ref()
+                    .ok_or_else(|| eyre!("action_tx is None"))?
+                    .send(
+                        Action::SetFocus {
+                            focus: Focus::DevTools(DevTools::WidgetDetails),
+                        }
+                        .into(),
+                    )?;
+                if let Some((id, on_marker)) = self.state.node_at(mouse.column, mouse.row) {
+                    self.state.selected = Some(id);
+                    if on_marker {
+                        self.toggle(state);
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown => self.next(state),
+            MouseEventKind::ScrollUp => self.previous(state),
             _ => {}
         }
         Ok(())
     }
 
+    fn update(&mut self, action: TuiAction) -> Result<Option<TuiAction>> {
+        if let TuiAction::Resize(_, _) | TuiAction::Resume = action {
+            // The screen has been cleared, so the image has to be drawn again.
+            self.placement = None;
+        }
+        Ok(None)
+    }
+
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect, state: &State) {
+        self.preview_area = None;
+        let area = if state.widget_preview_enabled {
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            self.draw_preview(f, layout[1], state);
+            layout[0]
+        } else {
+            area
+        };
+
         let border_color =
             if state.focus == Focus::DevTools(DevTools::WidgetDetails) && state.popup.is_none() {
-                Color::Green
+                self.config.theme.focus
             } else {
-                Color::White
+                self.config.theme.border
             };
         let block = Block::default()
             .title("Widget Details Tree")
@@ -208,10 +392,10 @@ impl Component for WidgetDetailsComponent {
             return;
         };
 
-        let root = Self::item_builder(details_tree);
+        let root = Self::item_builder(&self.config.theme, details_tree);
         let tree = Tree::new(root).block(block).highlight_style(
             if state.focus == Focus::DevTools(DevTools::WidgetDetails) {
-                Style::default().bg(Color::DarkGray)
+                Style::default().bg(self.config.theme.highlight)
             } else {
                 Style::default()
             },
@@ -223,4 +407,83 @@ impl Component for WidgetDetailsComponent {
 
         f.render_stateful_widget(tree, area, &mut self.state);
     }
+
+    fn draw_graphics(&mut self, tui: &mut Tui, state: &State) -> Result<()> {
+        if self.protocol == GraphicsProtocol::HalfBlocks {
+            return Ok(());
+        }
+        let visible = matches!(
+            state.focus,
+            Focus::DevTools(DevTools::Inspector) | Focus::DevTools(DevTools::WidgetDetails)
+        ) && state.popup.is_none();
+        let target = match (visible, self.preview_area, self.preview.as_ref()) {
+            (true, Some(area), Some((png, _))) => Some((png.clone(), area)),
+            _ => None,
+        };
+        let unchanged = match (&target, &self.placement) {
+            (Some((png, area)), Some((placed_png, placed_area))) => {
+                Arc::ptr_eq(png, placed_png) && area == placed_area
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return Ok(());
+        }
+
+        if self.placement.take().is_some() {
+            match self.protocol {
+                GraphicsProtocol::Kitty => {
+                    let backend = tui.backend_mut();
+                    backend.write_all(kitty_delete_sequence(KITTY_IMAGE_ID).as_bytes())?;
+                    Write::flush(backend)?;
+                }
+                GraphicsProtocol::Sixel => {
+                    // Sixel images are only removed by redrawing the cells below them, so the
+                    // new image is drawn on the next frame after the whole screen is redrawn.
+                    tui.clear()?;
+                    return Ok(());
+                }
+                GraphicsProtocol::HalfBlocks => {}
+            }
+        }
+
+        let (Some((png, area)), Some((_, image))) = (target, self.preview.as_ref()) else {
+            return Ok(());
+        };
+        let (cell_width, cell_height) = cell_size();
+        let (width, height) = fit_size(
+            image.dimensions(),
+            (
+                area.width as u32 * cell_width as u32,
+                area.height as u32 * cell_height as u32,
+            ),
+        );
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let sequence = match self.protocol {
+            GraphicsProtocol::Kitty => kitty_sequence(
+                &png,
+                KITTY_IMAGE_ID,
+                width.div_ceil(cell_width as u32) as u16,
+                height.div_ceil(cell_height as u32) as u16,
+            ),
+            GraphicsProtocol::Sixel => match sixel_sequence(image, width, height) {
+                Ok(sequence) => sequence,
+                Err(err) => {
+                    log::error!("{:?}", err);
+                    return Ok(());
+                }
+            },
+            GraphicsProtocol::HalfBlocks => return Ok(()),
+        };
+
+        let backend = tui.backend_mut();
+        queue!(backend, MoveTo(area.x, area.y))?;
+        backend.write_all(sequence.as_bytes())?;
+        Write::flush(backend)?;
+        self.placement = Some((png, area));
+        Ok(())
+    }
 }
@@ -0,0 +1,632 @@
+use std::{collections::HashMap, fmt};
+
+use color_eyre::eyre::{eyre, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{de::Deserializer, Deserialize};
+
+use crate::{
+    redux::state::{DevTools, Focus, Home, PopUp, State},
+    theme::Theme,
+    utils::get_config_dir,
+};
+
+const DEFAULT_CONFIG: &str = include_str!("../.config/config.toml");
+
+/// A named command which keys can be bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Command {
+    /// Removes the binding of the key, without falling back to the outer contexts.
+    None,
+    Quit,
+    Suspend,
+    CommandPalette,
+    Help,
+    Zoom,
+    ShrinkSidebar,
+    GrowSidebar,
+    Up,
+    Down,
+    Top,
+    Bottom,
+    PreviousTab,
+    NextTab,
+    Select,
+    Back,
+    Toggle,
+    New,
+    Edit,
+    Duplicate,
+    Delete,
+    Save,
+    Reset,
+    Clear,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    HotReload,
+    HotRestart,
+    StopApp,
+    ToggleWidgetInspector,
+    ToggleDebugPaint,
+    TogglePerformanceOverlay,
+    ToggleDebugPaintBaselines,
+    ToggleInvertOversizedImages,
+    ToggleRepaintRainbow,
+    ToggleSlowAnimations,
+    ToggleBroadcastReload,
+    ToggleHotReloadOnSave,
+    LaunchConfigurations,
+    ColdBoot,
+    NewEmulator,
+    TogglePreview,
+    TrackRebuilds,
+    TrackRepaints,
+}
+
+impl Command {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Command::None => "",
+            Command::Quit => "quit",
+            Command::Suspend => "suspend",
+            Command::CommandPalette => "commands",
+            Command::Help => "help",
+            Command::Zoom => "zoom",
+            Command::ShrinkSidebar => "narrower sidebar",
+            Command::GrowSidebar => "wider sidebar",
+            Command::Up => "up",
+            Command::Down => "down",
+            Command::Top => "top",
+            Command::Bottom => "bottom",
+            Command::PreviousTab => "previous tab",
+            Command::NextTab => "next tab",
+            Command::Select => "select",
+            Command::Back => "back",
+            Command::Toggle => "toggle",
+            Command::New => "new",
+            Command::Edit => "edit",
+            Command::Duplicate => "duplicate",
+            Command::Delete => "delete",
+            Command::Save => "save",
+            Command::Reset => "reset",
+            Command::Clear => "clear",
+            Command::Search => "search",
+            Command::NextMatch => "next match",
+            Command::PreviousMatch => "previous match",
+            Command::HotReload => "hot reload",
+            Command::HotRestart => "hot restart",
+            Command::StopApp => "stop app",
+            Command::ToggleWidgetInspector => "widget inspector",
+            Command::ToggleDebugPaint => "debug paint",
+            Command::TogglePerformanceOverlay => "performance overlay",
+            Command::ToggleDebugPaintBaselines => "paint baselines",
+            Command::ToggleInvertOversizedImages => "invert oversized images",
+            Command::ToggleRepaintRainbow => "repaint rainbow",
+            Command::ToggleSlowAnimations => "slow animations",
+            Command::ToggleBroadcastReload => "broadcast reload",
+            Command::ToggleHotReloadOnSave => "reload on save",
+            Command::LaunchConfigurations => "launch configurations",
+            Command::ColdBoot => "cold boot",
+            Command::NewEmulator => "new emulator",
+            Command::TogglePreview => "preview",
+            Command::TrackRebuilds => "track rebuilds",
+            Command::TrackRepaints => "track repaints",
+        }
+    }
+
+    /// Commands for moving around, which are too common to be worth a hint.
+    pub fn is_navigation(&self) -> bool {
+        matches!(
+            self,
+            Command::Up
+                | Command::Down
+                | Command::Top
+                | Command::Bottom
+                | Command::PreviousTab
+                | Command::NextTab
+        )
+    }
+}
+
+/// Where keys are looked up. Each pane has its own context, which falls back to the context of
+/// the screen it is in and then to `Global`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyContext {
+    Global,
+    Home,
+    Runners,
+    Devices,
+    PortForwards,
+    #[serde(rename = "devtools")]
+    DevTools,
+    AppSettings,
+    Inspector,
+    WidgetDetails,
+    RebuildStats,
+    Dump,
+    NetworkRequest,
+    SelectDevice,
+    LaunchConfigurations,
+    Popup,
+}
+
+impl KeyContext {
+    /// The contexts to look keys up in for the current state, in order. Empty while typing in a
+    /// text input so that every key is typed.
+    pub fn stack(state: &State) -> Vec<KeyContext> {
+        Self::stack_for(&state.focus, state.popup.as_ref())
+    }
+
+    pub fn stack_for(focus: &Focus, popup: Option<&PopUp>) -> Vec<KeyContext> {
+        match popup {
+            Some(popup) if popup.has_text_input() => vec![],
+            Some(PopUp::SelectDevice) => vec![
+                KeyContext::SelectDevice,
+                KeyContext::Popup,
+                KeyContext::Global,
+            ],
+            Some(PopUp::SelectLaunchConfiguration) => vec![
+                KeyContext::LaunchConfigurations,
+                KeyContext::Popup,
+                KeyContext::Global,
+            ],
+            Some(_) => vec![KeyContext::Popup, KeyContext::Global],
+            None => Self::focus_stack(focus),
+        }
+    }
+
+    /// The contexts to look keys up in when no popup is open.
+    pub fn focus_stack(focus: &Focus) -> Vec<KeyContext> {
+        let pane = match focus {
+            Focus::Home(Home::Runners) => Some(KeyContext::Runners),
+            Focus::Home(Home::Devices) => Some(KeyContext::Devices),
+            Focus::Home(Home::PortForwards) => Some(KeyContext::PortForwards),
+            Focus::DevTools(DevTools::AppSettings) => Some(KeyContext::AppSettings),
+            Focus::DevTools(DevTools::Inspector) => Some(KeyContext::Inspector),
+            Focus::DevTools(DevTools::WidgetDetails) => Some(KeyContext::WidgetDetails),
+            Focus::DevTools(DevTools::RebuildStats) => Some(KeyContext::RebuildStats),
+            Focus::DevTools(DevTools::Dumps) | Focus::DevTools(DevTools::DumpDetails) => {
+                Some(KeyContext::Dump)
+            }
+            Focus::DevTools(DevTools::NetworkRequest) => Some(KeyContext::NetworkRequest),
+            _ => None,
+        };
+        let screen = match focus {
+            Focus::Home(_) => KeyContext::Home,
+            Focus::DevTools(_) => KeyContext::DevTools,
+        };
+        pane.into_iter()
+            .chain([screen, KeyContext::Global])
+            .collect()
+    }
+}
+
+/// A key with its modifiers, e.g. `ctrl-c`, `R` or `enter`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Shift is already part of upper case characters and `BackTab`.
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s;
+        loop {
+            let lower = rest.to_lowercase();
+            if lower.starts_with("ctrl-") {
+                modifiers |= KeyModifiers::CONTROL;
+            } else if lower.starts_with("alt-") {
+                modifiers |= KeyModifiers::ALT;
+            } else if lower.starts_with("shift-") {
+                modifiers |= KeyModifiers::SHIFT;
+            } else {
+                break;
+            }
+            rest = &rest[rest.find('-').unwrap_or(0) + 1..];
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            f if f.len() > 1 && f.starts_with('f') => {
+                KeyCode::F(f[1..].parse().map_err(|_| eyre!("Unknown key: {}", s))?)
+            }
+            _ => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                        KeyCode::Char(c.to_ascii_uppercase())
+                    }
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(eyre!("Unknown key: {}", s)),
+                }
+            }
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl From<&KeyEvent> for KeyChord {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            KeyCode::BackTab => write!(f, "shift-tab"),
+            code => write!(f, "{}", format!("{:?}", code).to_lowercase()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyBindings(pub HashMap<KeyContext, HashMap<KeyChord, Command>>);
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parsed = HashMap::<KeyContext, HashMap<String, Command>>::deserialize(deserializer)?;
+        let mut bindings = HashMap::new();
+        for (context, keys) in parsed {
+            let mut chords = HashMap::new();
+            for (key, command) in keys {
+                let chord = KeyChord::parse(&key).map_err(serde::de::Error::custom)?;
+                chords.insert(chord, command);
+            }
+            bindings.insert(context, chords);
+        }
+        Ok(Self(bindings))
+    }
+}
+
+impl KeyBindings {
+    pub fn command(&self, contexts: &[KeyContext], key: &KeyEvent) -> Option<Command> {
+        let chord = KeyChord::from(key);
+        contexts
+            .iter()
+            .filter_map(|context| self.0.get(context))
+            .find_map(|chords| chords.get(&chord).copied())
+            .filter(|command| *command != Command::None)
+    }
+
+    /// Returns the commands which can be run in the contexts with their keys, innermost context
+    /// first.
+    pub fn bindings(&self, contexts: &[KeyContext]) -> Vec<(Command, Vec<KeyChord>)> {
+        let mut bindings: Vec<(Command, Vec<KeyChord>)> = vec![];
+        for chords in contexts.iter().filter_map(|context| self.0.get(context)) {
+            let mut commands = chords
+                .values()
+                .copied()
+                .filter(|command| !bindings.iter().any(|(bound, _)| bound == command))
+                .collect::<Vec<_>>();
+            commands.sort();
+            commands.dedup();
+            for command in commands {
+                let keys = self.keys_for(contexts, command);
+                if command != Command::None && !keys.is_empty() {
+                    bindings.push((command, keys));
+                }
+            }
+        }
+        bindings
+    }
+
+    /// Returns the keys which run the command in the contexts, shortest first.
+    pub fn keys_for(&self, contexts: &[KeyContext], command: Command) -> Vec<KeyChord> {
+        let mut keys = contexts
+            .iter()
+            .filter_map(|context| self.0.get(context))
+            .flat_map(|chords| chords.iter())
+            .filter(|(_, bound)| **bound == command)
+            .map(|(chord, _)| *chord)
+            // The key may be bound to another command in an inner context.
+            .filter(|chord| {
+                let key = KeyEvent::new(chord.code, chord.modifiers);
+                self.command(contexts, &key) == Some(command)
+            })
+            .collect::<Vec<_>>();
+        keys.sort_by_key(|chord| {
+            let key = chord.to_string();
+            (key.len(), key)
+        });
+        keys.dedup();
+        keys
+    }
+
+    /// Returns the key which runs the command in the contexts, preferring the shortest one.
+    pub fn key_for(&self, contexts: &[KeyContext], command: Command) -> Option<KeyChord> {
+        self.keys_for(contexts, command).into_iter().next()
+    }
+
+    /// A hint like `a: add, d: remove` for the bottom of a pane. Unbound commands are left out.
+    pub fn hint(&self, contexts: &[KeyContext], commands: &[(Command, &str)]) -> String {
+        commands
+            .iter()
+            .filter_map(|(command, description)| {
+                self.key_for(contexts, *command)
+                    .map(|key| format!("{}: {}", key, description))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+    #[serde(default)]
+    pub theme: Theme,
+}
+
+impl Config {
+    /// Loads the default config, overridden by `config.toml` in the config directory.
+    pub fn new() -> Result<Self> {
+        let path = get_config_dir().join("config.toml");
+        let config = config::Config::builder()
+            .add_source(config::File::from_str(
+                DEFAULT_CONFIG,
+                config::FileFormat::Toml,
+            ))
+            .add_source(
+                config::File::from(path)
+                    .format(config::FileFormat::Toml)
+                    .required(false),
+            )
+            .build()?;
+        Ok(config.try_deserialize()?)
+    }
+
+    /// Returns the command the key is bound to in the current state.
+    pub fn command(&self, key: &KeyEvent, state: &State) -> Option<Command> {
+        self.keybindings.command(&KeyContext::stack(state), key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::redux::state::PopUp;
+    use ratatui::style::Color;
+
+    fn load(user: &str) -> Config {
+        config::Config::builder()
+            .add_source(config::File::from_str(
+                DEFAULT_CONFIG,
+                config::FileFormat::Toml,
+            ))
+            .add_source(config::File::from_str(user, config::FileFormat::Toml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn parse_key_chords() {
+        assert_eq!(
+            KeyChord::parse("ctrl-c").unwrap(),
+            KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyChord::parse("shift-r").unwrap(),
+            KeyChord::new(KeyCode::Char('R'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::parse("shift-tab").unwrap(),
+            KeyChord::new(KeyCode::BackTab, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::parse("f5").unwrap(),
+            KeyChord::new(KeyCode::F(5), KeyModifiers::NONE)
+        );
+        assert_eq!(KeyChord::parse("-").unwrap().code, KeyCode::Char('-'));
+        assert!(KeyChord::parse("foo").is_err());
+        assert_eq!(KeyChord::parse("ctrl-c").unwrap().to_string(), "ctrl-c");
+    }
+
+    #[test]
+    fn resolve_shifted_symbols() {
+        let config = load("");
+        let state = State {
+            focus: Focus::DevTools(DevTools::Inspector),
+            ..State::default()
+        };
+        assert_eq!(
+            config.command(
+                &KeyEvent::new(KeyCode::Char('<'), KeyModifiers::SHIFT),
+                &state
+            ),
+            Some(Command::ShrinkSidebar)
+        );
+        assert_eq!(
+            config.command(&key(KeyCode::Char('>')), &state),
+            Some(Command::GrowSidebar)
+        );
+    }
+
+    #[test]
+    fn resolve_commands_by_context() {
+        let config = load("");
+        let mut state = State::default();
+        assert_eq!(
+            config.command(&key(KeyCode::Char('d')), &state),
+            Some(Command::StopApp)
+        );
+        assert_eq!(
+            config.command(&key(KeyCode::Char('q')), &state),
+            Some(Command::Quit)
+        );
+
+        state.focus = Focus::Home(Home::PortForwards);
+        assert_eq!(
+            config.command(&key(KeyCode::Char('d')), &state),
+            Some(Command::Delete)
+        );
+        assert_eq!(
+            config.command(&key(KeyCode::Char('k')), &state),
+            Some(Command::Up)
+        );
+
+        let contexts = KeyContext::focus_stack(&Focus::Home(Home::Runners));
+        assert_eq!(
+            config
+                .keybindings
+                .key_for(&contexts, Command::HotReload)
+                .map(|key| key.to_string()),
+            Some("r".to_string())
+        );
+        assert_eq!(
+            config
+                .keybindings
+                .key_for(&contexts, Command::Up)
+                .map(|key| key.to_string()),
+            Some("k".to_string())
+        );
+
+        state.popup = Some(PopUp::AddPortForward);
+        assert_eq!(config.command(&key(KeyCode::Char('q')), &state), None);
+    }
+
+    #[test]
+    fn override_default_keybindings() {
+        let config = load(
+            r#"
+            [keybindings.runners]
+            d = "none"
+            x = "stop_app"
+
+            [keybindings.global]
+            ctrl-q = "quit"
+            "#,
+        );
+        let state = State::default();
+        assert_eq!(config.command(&key(KeyCode::Char('d')), &state), None);
+        assert_eq!(
+            config.command(&key(KeyCode::Char('x')), &state),
+            Some(Command::StopApp)
+        );
+        // Bindings which are not overridden are kept.
+        assert_eq!(
+            config.command(&key(KeyCode::Char('r')), &state),
+            Some(Command::HotReload)
+        );
+        assert_eq!(
+            config.command(
+                &KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
+                &state
+            ),
+            Some(Command::Quit)
+        );
+    }
+
+    #[test]
+    fn override_theme_colors() {
+        assert_eq!(load("").theme, Theme::dark());
+
+        let config = load(
+            r##"
+            [theme]
+            name = "light"
+            focus = "blue"
+            highlight = "#303030"
+            "##,
+        );
+        assert_eq!(config.theme.focus, Color::Blue);
+        assert_eq!(config.theme.highlight, Color::Rgb(0x30, 0x30, 0x30));
+        // Colours which are not overridden come from the named theme.
+        assert_eq!(config.theme.text, Theme::light().text);
+        assert_eq!(config.theme.syntax, Theme::light().syntax);
+    }
+
+    #[test]
+    fn list_bindings_for_popups() {
+        let config = load("");
+        let contexts =
+            KeyContext::stack_for(&Focus::Home(Home::Runners), Some(&PopUp::SelectDevice));
+        let bindings = config.keybindings.bindings(&contexts);
+        assert_eq!(bindings[0].0, Command::Toggle);
+        assert!(bindings
+            .iter()
+            .any(|(command, _)| *command == Command::Help));
+        // Keys of the panes under the popup are not listed.
+        assert!(!bindings
+            .iter()
+            .any(|(command, _)| *command == Command::HotReload));
+
+        let (_, keys) = bindings
+            .iter()
+            .find(|(command, _)| *command == Command::Down)
+            .unwrap();
+        assert_eq!(
+            keys.iter().map(|key| key.to_string()).collect::<Vec<_>>(),
+            vec!["j", "down"]
+        );
+    }
+
+    #[test]
+    fn hint_with_bound_keys() {
+        let config = load(
+            r#"
+            [keybindings.port_forwards]
+            a = "none"
+            "+" = "new"
+            d = "none"
+            "#,
+        );
+        let contexts = KeyContext::focus_stack(&Focus::Home(Home::PortForwards));
+        assert_eq!(
+            config.keybindings.hint(
+                &contexts,
+                &[(Command::New, "add"), (Command::Delete, "remove")]
+            ),
+            "+: add"
+        );
+    }
+}
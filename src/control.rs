@@ -0,0 +1,642 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::unix::OwnedWriteHalf;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::watch;
+
+use crate::headless::Cursor;
+use crate::redux::action::Action;
+use crate::redux::state::{SessionLog, SessionState, State};
+use crate::redux::thunk::ThunkAction;
+use crate::redux::ActionOrThunk;
+
+pub const CONTROL_SOCKET_PATH: &str = ".dart_tool/flx/control.sock";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const APP_ERROR: i64 = -32000;
+
+const DEBUG_FLAGS: [&str; 9] = [
+    "debug_paint",
+    "debug_paint_baselines",
+    "invert_oversized_images",
+    "repaint_rainbow",
+    "show_performance_overlay",
+    "show_widget_inspector",
+    "slow_animations",
+    "track_rebuild_widgets",
+    "track_repaint_widgets",
+];
+
+#[derive(Deserialize, Debug)]
+struct Request {
+    /// Notifications from the client have no id and get no response.
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct SessionParams {
+    session_id: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct ToggleParams {
+    session_id: Option<String>,
+    flag: Option<String>,
+    /// Toggled only when it differs from the current value. Flipped when omitted.
+    enabled: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+struct LogsParams {
+    session_id: Option<String>,
+    #[serde(default)]
+    offset: usize,
+    limit: Option<usize>,
+}
+
+#[derive(Deserialize, Default)]
+struct ExportParams {
+    session_id: Option<String>,
+    /// Relative to the project root.
+    path: Option<PathBuf>,
+}
+
+fn parse<T: DeserializeOwned + Default>(params: &Value) -> Result<T, RpcError> {
+    if params.is_null() {
+        return Ok(T::default());
+    }
+    serde_json::from_value(params.clone()).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+/// The given session, or the selected one.
+fn find_session<'a>(
+    state: &'a State,
+    session_id: Option<&String>,
+) -> Result<&'a SessionState, RpcError> {
+    let Some(session_id) = session_id.or(state.session_id.as_ref()) else {
+        return Err(RpcError::new(APP_ERROR, "No app is running"));
+    };
+    state
+        .sessions
+        .iter()
+        .find(|s| &s.id == session_id)
+        .ok_or_else(|| {
+            RpcError::new(
+                INVALID_PARAMS,
+                format!("Session {} is not found", session_id),
+            )
+        })
+}
+
+/// Thunks act on the selected session, so select the session first when it is another one.
+fn select(state: &State, session: &SessionState, thunk: ThunkAction) -> Vec<ActionOrThunk> {
+    let mut actions = vec![];
+    if state.session_id.as_ref() != Some(&session.id) {
+        actions.push(
+            Action::SelectSession {
+                session_id: session.id.clone(),
+            }
+            .into(),
+        );
+    }
+    actions.push(thunk.into());
+    actions
+}
+
+fn debug_flag(session: &SessionState, flag: &str) -> Option<(bool, ThunkAction)> {
+    match flag {
+        "debug_paint" => Some((session.debug_paint_enabled, ThunkAction::ToggleDebugPaint)),
+        "debug_paint_baselines" => Some((
+            session.debug_paint_baselines_enabled,
+            ThunkAction::ToggleDebugPaintBaselines,
+        )),
+        "invert_oversized_images" => Some((
+            session.invert_oversized_images_enabled,
+            ThunkAction::ToggleInvertOversizedImages,
+        )),
+        "repaint_rainbow" => Some((
+            session.repaint_rainbow_enabled,
+            ThunkAction::ToggleRepaintRainbow,
+        )),
+        "show_performance_overlay" => Some((
+            session.show_performance_overlay_enabled,
+            ThunkAction::ToggleShowPerformanceOverlay,
+        )),
+        "show_widget_inspector" => Some((
+            session.show_widget_inspector_enabled,
+            ThunkAction::ToggleWidgetInspector,
+        )),
+        "slow_animations" => Some((
+            session.slow_animations_enabled,
+            ThunkAction::ToggleSlowAnimations,
+        )),
+        "track_rebuild_widgets" => Some((
+            session.track_rebuild_widgets_enabled,
+            ThunkAction::ToggleTrackRebuildWidgets,
+        )),
+        "track_repaint_widgets" => Some((
+            session.track_repaint_widgets_enabled,
+            ThunkAction::ToggleTrackRepaintWidgets,
+        )),
+        _ => None,
+    }
+}
+
+fn session_info(state: &State, session: &SessionState) -> Value {
+    let debug_flags = DEBUG_FLAGS
+        .iter()
+        .filter_map(|flag| {
+            debug_flag(session, flag).map(|(enabled, _)| (flag.to_string(), json!(enabled)))
+        })
+        .collect::<serde_json::Map<_, _>>();
+    json!({
+        "id": session.id,
+        "app_id": session.app_id,
+        "device_id": session.device_id,
+        "configuration": session.configuration.as_ref().map(|c| &c.name),
+        "mode": session.mode,
+        "vm_service_uri": session.vm_service_uri,
+        "started": session.started,
+        "stopped": session.stopped,
+        "hot_reloading": session.hot_reloading,
+        "hot_restarting": session.hot_restarting,
+        "selected": state.session_id.as_ref() == Some(&session.id),
+        "debug_flags": debug_flags,
+    })
+}
+
+fn sessions_info(state: &State) -> Value {
+    json!({
+        "sessions": state
+            .sessions
+            .iter()
+            .map(|session| session_info(state, session))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Returns the result of a request and the actions to send to the store for it.
+fn handle(
+    method: &str,
+    params: &Value,
+    state: &State,
+) -> Result<(Value, Vec<ActionOrThunk>), RpcError> {
+    match method {
+        "session.list" => Ok((sessions_info(state), vec![])),
+        "session.select" => {
+            let params = parse::<SessionParams>(params)?;
+            let Some(session_id) = params.session_id else {
+                return Err(RpcError::new(INVALID_PARAMS, "session_id is required"));
+            };
+            let session = find_session(state, Some(&session_id))?;
+            Ok((
+                Value::Null,
+                vec![Action::SelectSession {
+                    session_id: session.id.clone(),
+                }
+                .into()],
+            ))
+        }
+        "session.hotReload" | "session.hotRestart" | "session.stop" => {
+            let params = parse::<SessionParams>(params)?;
+            let session = find_session(state, params.session_id.as_ref())?;
+            let thunk = match method {
+                "session.hotReload" => ThunkAction::HotReload,
+                "session.hotRestart" => ThunkAction::HotRestart,
+                _ => ThunkAction::StopApp,
+            };
+            Ok((Value::Null, select(state, session, thunk)))
+        }
+        "debug.toggle" => {
+            let params = parse::<ToggleParams>(params)?;
+            let session = find_session(state, params.session_id.as_ref())?;
+            let Some((enabled, thunk)) = params
+                .flag
+                .as_deref()
+                .and_then(|flag| debug_flag(session, flag))
+            else {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    format!("flag must be one of {}", DEBUG_FLAGS.join(", ")),
+                ));
+            };
+            if params.enabled == Some(enabled) {
+                return Ok((json!({ "enabled": enabled }), vec![]));
+            }
+            Ok((
+                json!({ "enabled": !enabled }),
+                select(state, session, thunk),
+            ))
+        }
+        "logs.get" => {
+            let params = parse::<LogsParams>(params)?;
+            let session = find_session(state, params.session_id.as_ref())?;
+            let logs = session
+                .logs
+                .iter()
+                .skip(params.offset)
+                .take(params.limit.unwrap_or(usize::MAX))
+                .map(|log| match log {
+                    SessionLog::Stdout(line) => json!({ "stream": "stdout", "message": line }),
+                    SessionLog::Stderr(line) => json!({ "stream": "stderr", "message": line }),
+                    SessionLog::Progress {
+                        message, end_at, ..
+                    } => json!({
+                        "stream": "progress",
+                        "message": message,
+                        "finished": end_at.is_some(),
+                    }),
+                })
+                .collect::<Vec<_>>();
+            let next_offset = params.offset.min(session.logs.len()) + logs.len();
+            Ok((json!({ "logs": logs, "next_offset": next_offset }), vec![]))
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method {} is not found", method),
+        )),
+    }
+}
+
+/// The result of `network.export`, or the file to write for it.
+enum Export {
+    Requests(Value),
+    File {
+        path: PathBuf,
+        contents: String,
+        count: usize,
+    },
+}
+
+/// Handled apart from the other methods because the file is written asynchronously.
+fn export_requests(params: &Value, state: &State) -> Result<Export, RpcError> {
+    let params = parse::<ExportParams>(params)?;
+    let session = find_session(state, params.session_id.as_ref())?;
+    let requests = json!({ "requests": session.requests });
+    let Some(path) = params.path else {
+        return Ok(Export::Requests(requests));
+    };
+    let contents = serde_json::to_string_pretty(&requests)
+        .map_err(|e| RpcError::new(APP_ERROR, e.to_string()))?;
+    Ok(Export::File {
+        path: export_path(&state.project_root, &path)?,
+        contents,
+        count: session.requests.len(),
+    })
+}
+
+/// Files are only written inside the project, so that a client can't overwrite any file of the
+/// user.
+fn export_path(project_root: &Path, path: &Path) -> Result<PathBuf, RpcError> {
+    let invalid = || {
+        RpcError::new(
+            INVALID_PARAMS,
+            format!("path must be a file in the project: {}", path.display()),
+        )
+    };
+    let mut relative = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => relative.push(name),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !relative.pop() {
+                    return Err(invalid());
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return Err(invalid()),
+        }
+    }
+    if relative.as_os_str().is_empty() {
+        return Err(invalid());
+    }
+    Ok(project_root.join(relative))
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    }
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// What a subscribed client has been told about.
+struct Subscription {
+    sessions: Value,
+    cursors: HashMap<String, Cursor>,
+}
+
+impl Subscription {
+    /// Starts from the current state, so that only changes are sent.
+    fn new(state: &State) -> Self {
+        let mut subscription = Self {
+            sessions: Value::Null,
+            cursors: HashMap::new(),
+        };
+        subscription.notifications(state);
+        subscription
+    }
+
+    fn notifications(&mut self, state: &State) -> Vec<Value> {
+        let mut notifications = vec![];
+
+        let sessions = sessions_info(state);
+        if sessions != self.sessions {
+            notifications.push(notification("sessions.changed", sessions.clone()));
+            self.sessions = sessions;
+        }
+
+        self.cursors
+            .retain(|id, _| state.sessions.iter().any(|s| &s.id == id));
+        for session in &state.sessions {
+            let cursor = self.cursors.entry(session.id.clone()).or_default();
+            for event in cursor.session_events(session) {
+                let mut params = serde_json::to_value(event).unwrap_or_default();
+                if let Value::Object(params) = &mut params {
+                    params.insert("session_id".to_string(), json!(session.id));
+                }
+                notifications.push(notification("event", params));
+            }
+        }
+        notifications
+    }
+}
+
+async fn write_line(writer: &mut OwnedWriteHalf, message: &Value) -> Result<()> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes()).await?;
+    Ok(())
+}
+
+/// JSON-RPC 2.0 over a Unix domain socket, one message per line, so that editors and scripts can
+/// drive a running flx.
+pub struct ControlSocket {
+    listener: UnixListener,
+    action_tx: UnboundedSender<ActionOrThunk>,
+    state_rx: watch::Receiver<State>,
+}
+
+impl ControlSocket {
+    /// Fails when another flx is already listening on the path.
+    pub fn bind(
+        path: &Path,
+        action_tx: UnboundedSender<ActionOrThunk>,
+        state_rx: watch::Receiver<State>,
+    ) -> Result<Self> {
+        if path.exists() {
+            if std::os::unix::net::UnixStream::connect(path).is_ok() {
+                return Err(eyre!(
+                    "Another flx is already listening on {}",
+                    path.display()
+                ));
+            }
+            // Left behind by a flx which didn't exit cleanly.
+            std::fs::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            listener: UnixListener::bind(path)?,
+            action_tx,
+            state_rx,
+        })
+    }
+
+    pub async fn serve(self) {
+        while let Ok((stream, _)) = self.listener.accept().await {
+            let action_tx = self.action_tx.clone();
+            let state_rx = self.state_rx.clone();
+            tokio::spawn(async move {
+                if let Err(e) = Self::handle_connection(stream, action_tx, state_rx).await {
+                    log::error!("Control socket connection failed: {:?}", e);
+                }
+            });
+        }
+    }
+
+    async fn handle_connection(
+        stream: UnixStream,
+        action_tx: UnboundedSender<ActionOrThunk>,
+        mut state_rx: watch::Receiver<State>,
+    ) -> Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut subscription: Option<Subscription> = None;
+
+        loop {
+            tokio::select! {
+                line = lines.next_line() => {
+                    let Some(line) = line? else {
+                        return Ok(());
+                    };
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let request = match serde_json::from_str::<Request>(&line) {
+                        Ok(request) => request,
+                        Err(e) => {
+                            let error = RpcError::new(PARSE_ERROR, e.to_string());
+                            write_line(&mut writer, &response(Value::Null, Err(error))).await?;
+                            continue;
+                        }
+                    };
+                    let result = match request.method.as_str() {
+                        "subscribe" => {
+                            subscription = Some(Subscription::new(&state_rx.borrow_and_update()));
+                            Ok(Value::Null)
+                        }
+                        "unsubscribe" => {
+                            subscription = None;
+                            Ok(Value::Null)
+                        }
+                        "network.export" => {
+                            let export = export_requests(&request.params, &state_rx.borrow());
+                            match export {
+                                Ok(Export::Requests(requests)) => Ok(requests),
+                                Ok(Export::File { path, contents, count }) => {
+                                    match tokio::fs::write(&path, contents).await {
+                                        Ok(()) => Ok(json!({ "path": path, "count": count })),
+                                        Err(e) => Err(RpcError::new(APP_ERROR, e.to_string())),
+                                    }
+                                }
+                                Err(error) => Err(error),
+                            }
+                        }
+                        method => match handle(method, &request.params, &state_rx.borrow()) {
+                            Ok((result, actions)) => {
+                                for action in actions {
+                                    action_tx.send(action)?;
+                                }
+                                Ok(result)
+                            }
+                            Err(error) => Err(error),
+                        },
+                    };
+                    if let Some(id) = request.id {
+                        write_line(&mut writer, &response(id, result)).await?;
+                    }
+                }
+                changed = state_rx.changed(), if subscription.is_some() => {
+                    // The app has exited.
+                    if changed.is_err() {
+                        return Ok(());
+                    }
+                    let notifications = subscription
+                        .as_mut()
+                        .map(|s| s.notifications(&state_rx.borrow_and_update()))
+                        .unwrap_or_default();
+                    for notification in notifications {
+                        write_line(&mut writer, &notification).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> State {
+        State {
+            session_id: Some("0".to_string()),
+            sessions: vec![
+                SessionState {
+                    id: "0".to_string(),
+                    logs: vec![
+                        SessionLog::Stdout("a".to_string()),
+                        SessionLog::Stderr("b".to_string()),
+                        SessionLog::Stdout("c".to_string()),
+                    ],
+                    ..SessionState::default()
+                },
+                SessionState {
+                    id: "1".to_string(),
+                    debug_paint_enabled: true,
+                    ..SessionState::default()
+                },
+            ],
+            ..State::new(PathBuf::from("/tmp"))
+        }
+    }
+
+    #[test]
+    fn get_logs_from_offset() {
+        let (result, actions) =
+            handle("logs.get", &json!({ "offset": 1, "limit": 1 }), &state()).unwrap();
+        assert_eq!(
+            result,
+            json!({ "logs": [{ "stream": "stderr", "message": "b" }], "next_offset": 2 })
+        );
+        assert!(actions.is_empty());
+
+        let (result, _) = handle("logs.get", &json!({ "offset": 5 }), &state()).unwrap();
+        assert_eq!(result, json!({ "logs": [], "next_offset": 3 }));
+    }
+
+    #[test]
+    fn select_session_before_running_thunks() {
+        let (_, actions) = handle("session.hotReload", &Value::Null, &state()).unwrap();
+        assert!(matches!(
+            actions.as_slice(),
+            [ActionOrThunk::Thunk(ThunkAction::HotReload)]
+        ));
+
+        let (result, actions) = handle(
+            "debug.toggle",
+            &json!({ "session_id": "1", "flag": "debug_paint" }),
+            &state(),
+        )
+        .unwrap();
+        assert_eq!(result, json!({ "enabled": false }));
+        assert!(matches!(
+            actions.as_slice(),
+            [
+                ActionOrThunk::Action(Action::SelectSession { .. }),
+                ActionOrThunk::Thunk(ThunkAction::ToggleDebugPaint)
+            ]
+        ));
+
+        let (_, actions) = handle(
+            "debug.toggle",
+            &json!({ "session_id": "1", "flag": "debug_paint", "enabled": true }),
+            &state(),
+        )
+        .unwrap();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn export_only_inside_project() {
+        let project_root = Path::new("/work/app");
+        let path = |path: &str| export_path(project_root, Path::new(path));
+        assert_eq!(
+            path("./build/../requests.json").unwrap(),
+            PathBuf::from("/work/app/requests.json")
+        );
+        assert_eq!(path("/etc/passwd").err().unwrap().code, INVALID_PARAMS);
+        assert_eq!(path("../requests.json").err().unwrap().code, INVALID_PARAMS);
+        assert_eq!(path("a/../../b").err().unwrap().code, INVALID_PARAMS);
+        assert_eq!(path("a/..").err().unwrap().code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn reject_invalid_requests() {
+        let error = |method, params| handle(method, &params, &state()).err().unwrap().code;
+        assert_eq!(error("session.launch", Value::Null), METHOD_NOT_FOUND);
+        assert_eq!(error("session.select", json!({})), INVALID_PARAMS);
+        assert_eq!(
+            error("session.stop", json!({ "session_id": "2" })),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            error("debug.toggle", json!({ "flag": "paint" })),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            handle(
+                "session.stop",
+                &Value::Null,
+                &State::new(PathBuf::from("/tmp"))
+            )
+            .err()
+            .unwrap()
+            .code,
+            APP_ERROR
+        );
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use color_eyre::eyre::{eyre, Result};
+use daemon::flutter::FlutterDaemon;
+use daemon::io::event::AppMode;
+use redux_rs::middlewares::thunk::{self, ThunkMiddleware};
+use redux_rs::Store;
+use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc;
+
+use crate::redux::reducer::reducer;
+use crate::redux::state::{NotificationLevel, SessionLog, SessionState, State};
+use crate::redux::thunk::context::Context;
+use crate::redux::thunk::run_new_app::RunTarget;
+use crate::redux::thunk::{thunk_impl, ThunkAction};
+use crate::redux::ActionOrThunk;
+use crate::session::session_manager::SessionManager;
+use crate::utils::resolve_project_root;
+
+pub mod devices;
+pub mod emulators;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Something which happened to the app. Printed as a line of JSON, or as text for people.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub(crate) enum Event {
+    AppStart {
+        app_id: Option<String>,
+        device_id: Option<String>,
+        mode: Option<AppMode>,
+    },
+    VmService {
+        uri: String,
+    },
+    AppStarted,
+    Log {
+        stream: &'static str,
+        message: String,
+    },
+    Progress {
+        message: String,
+        finished: bool,
+    },
+    Frame {
+        number: u64,
+        build_ms: f64,
+        raster_ms: f64,
+        elapsed_ms: f64,
+    },
+    /// Sent when the request is made and again when its response arrives.
+    Request {
+        id: String,
+        method: String,
+        uri: String,
+        status_code: Option<i64>,
+        duration_ms: Option<i64>,
+    },
+    Reload {
+        full_restart: bool,
+        success: bool,
+        message: String,
+        duration_ms: u128,
+    },
+    Notification {
+        level: &'static str,
+        message: String,
+    },
+    AppStop {
+        error: Option<String>,
+    },
+}
+
+impl Event {
+    /// Frames and pending requests are too noisy to be read, so they are only printed as JSON.
+    fn text(&self) -> Option<String> {
+        match self {
+            Event::AppStart { device_id, .. } => Some(format!(
+                "Launching on {}",
+                device_id.as_deref().unwrap_or("the default device")
+            )),
+            Event::VmService { uri } => Some(format!("VM service is available at {}", uri)),
+            Event::AppStarted => Some("App started".to_string()),
+            Event::Log { message, .. } => Some(message.clone()),
+            Event::Progress { message, finished } => Some(if *finished {
+                format!("{} done", message)
+            } else {
+                format!("{}...", message)
+            }),
+            Event::Frame { .. } => None,
+            Event::Request {
+                method,
+                uri,
+                status_code,
+                duration_ms: Some(duration),
+                ..
+            } => Some(format!(
+                "{} {} {} ({} ms)",
+                method,
+                uri,
+                status_code.map(|c| c.to_string()).unwrap_or_default(),
+                duration
+            )),
+            Event::Request { .. } => None,
+            Event::Reload {
+                full_restart,
+                success,
+                message,
+                duration_ms,
+            } => Some(match (full_restart, success) {
+                (true, true) => format!("Restarted in {} ms", duration_ms),
+                (false, true) => format!("Reloaded in {} ms", duration_ms),
+                (true, false) => format!("Hot restart failed: {}", message),
+                (false, false) => format!("Hot reload failed: {}", message),
+            }),
+            Event::Notification { level, message } => Some(format!("{}: {}", level, message)),
+            Event::AppStop { error: None } => Some("App stopped".to_string()),
+            Event::AppStop { error: Some(error) } => Some(format!("App stopped: {}", error)),
+        }
+    }
+}
+
+/// How much of the state has been printed already.
+#[derive(Default)]
+pub(crate) struct Cursor {
+    app_start: bool,
+    vm_service: bool,
+    started: bool,
+    stopped: bool,
+    logs: usize,
+    finished_progress: HashSet<String>,
+    frame_number: Option<u64>,
+    /// Whether the response of each request has been printed.
+    requests: HashMap<String, bool>,
+    reload_finished_at: u128,
+    notifications: HashSet<(u128, String)>,
+}
+
+impl Cursor {
+    pub(crate) fn session_events(&mut self, session: &SessionState) -> Vec<Event> {
+        let mut events = vec![];
+
+        if !self.app_start && session.app_id.is_some() {
+            self.app_start = true;
+            events.push(Event::AppStart {
+                app_id: session.app_id.clone(),
+                device_id: session.device_id.clone(),
+                mode: session.mode.clone(),
+            });
+        }
+        if let Some(uri) = session.vm_service_uri.as_ref().filter(|_| !self.vm_service) {
+            self.vm_service = true;
+            events.push(Event::VmService { uri: uri.clone() });
+        }
+        if !self.started && session.started {
+            self.started = true;
+            events.push(Event::AppStarted);
+        }
+
+        for (index, log) in session.logs.iter().enumerate() {
+            let is_new = index >= self.logs;
+            match log {
+                SessionLog::Stdout(line) if is_new => events.push(Event::Log {
+                    stream: "stdout",
+                    message: line.clone(),
+                }),
+                SessionLog::Stderr(line) if is_new => events.push(Event::Log {
+                    stream: "stderr",
+                    message: line.clone(),
+                }),
+                SessionLog::Progress {
+                    id,
+                    message: Some(message),
+                    end_at,
+                    ..
+                } => {
+                    let finished = end_at.is_some() && self.finished_progress.insert(id.clone());
+                    if is_new || finished {
+                        events.push(Event::Progress {
+                            message: message.clone(),
+                            finished: end_at.is_some(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.logs = session.logs.len();
+
+        for frame in session
+            .frames
+            .iter()
+            .filter(|frame| self.frame_number.is_none_or(|number| frame.number > number))
+        {
+            events.push(Event::Frame {
+                number: frame.number,
+                build_ms: frame.build.as_secs_f64() * 1000.0,
+                raster_ms: frame.raster.as_secs_f64() * 1000.0,
+                elapsed_ms: frame.elapsed.as_secs_f64() * 1000.0,
+            });
+        }
+        if let Some(frame) = session.frames.last() {
+            self.frame_number = Some(frame.number);
+        }
+
+        for request in &session.requests {
+            let completed = request.end_time.is_some();
+            if self.requests.insert(request.id.clone(), completed) == Some(completed) {
+                continue;
+            }
+            events.push(Event::Request {
+                id: request.id.clone(),
+                method: request.method.clone(),
+                uri: request.uri.clone(),
+                status_code: request.response.as_ref().and_then(|r| r.status_code),
+                // The profile is timed in microseconds.
+                duration_ms: request
+                    .end_time
+                    .map(|end_time| (end_time - request.start_time) / 1000),
+            });
+        }
+
+        let reload_finished_at = self.reload_finished_at;
+        for reload in session
+            .reloads
+            .iter()
+            .filter(|reload| reload.finished_at > reload_finished_at)
+        {
+            self.reload_finished_at = self.reload_finished_at.max(reload.finished_at);
+            events.push(Event::Reload {
+                full_restart: reload.full_restart,
+                success: reload.success,
+                message: reload.message.clone(),
+                duration_ms: reload.duration,
+            });
+        }
+
+        if !self.stopped && session.stopped {
+            self.stopped = true;
+            events.push(Event::AppStop {
+                error: session.stop_error.clone(),
+            });
+        }
+        events
+    }
+
+    fn notification_events(&mut self, state: &State) -> Vec<Event> {
+        state
+            .notifications
+            .iter()
+            .filter(|n| self.notifications.insert((n.created_at, n.message.clone())))
+            .map(|n| Event::Notification {
+                level: match n.level {
+                    NotificationLevel::Info => "info",
+                    NotificationLevel::Error => "error",
+                },
+                message: n.message.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Writes to stdout without panicking when it is closed, e.g. when piped to `head`.
+fn print_line(line: &str) -> Result<()> {
+    writeln!(std::io::stdout().lock(), "{}", line)?;
+    Ok(())
+}
+
+/// Runs an app without the TUI, for scripts, CI and other editors. Commands are read from stdin
+/// and events are written to stdout.
+pub struct Headless {
+    project_root: PathBuf,
+    use_fvm: bool,
+    configuration: Option<String>,
+    device_id: Option<String>,
+    json: bool,
+}
+
+impl Headless {
+    pub fn new(
+        project_root: Option<String>,
+        use_fvm: bool,
+        configuration: Option<String>,
+        device_id: Option<String>,
+        json: bool,
+    ) -> Result<Self> {
+        Ok(Self {
+            project_root: resolve_project_root(project_root)?,
+            use_fvm,
+            configuration,
+            device_id,
+            json,
+        })
+    }
+
+    fn print(&self, event: &Event) -> Result<()> {
+        let line = if self.json {
+            serde_json::to_string(event)?
+        } else if let Some(text) = event.text() {
+            text
+        } else {
+            return Ok(());
+        };
+        print_line(&line)
+    }
+
+    fn target(&self, state: &State) -> Result<RunTarget> {
+        let configuration = match &self.configuration {
+            Some(name) => Some(
+                state
+                    .launch_configurations
+                    .iter()
+                    .find(|c| &c.name == name)
+                    .cloned()
+                    .ok_or_else(|| {
+                        let names = state
+                            .launch_configurations
+                            .iter()
+                            .map(|c| c.name.clone())
+                            .collect::<Vec<_>>();
+                        eyre!(
+                            "Launch configuration {} is not found. Available: {}",
+                            name,
+                            names.join(", ")
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+        Ok(RunTarget {
+            configuration,
+            device_id: self.device_id.clone(),
+        })
+    }
+
+    pub async fn run(&mut self) -> Result<()> {
+        let initial_state = State::new(self.project_root.clone());
+        let store = Store::new_with_state(reducer, initial_state)
+            .wrap(ThunkMiddleware)
+            .await;
+        let daemon = Arc::new(FlutterDaemon::new(self.use_fvm)?);
+        let session_manager = Arc::new(SessionManager::new());
+        let context = Arc::new(Context::new(daemon.clone(), session_manager.clone()));
+        let (redux_action_tx, mut redux_action_rx) = mpsc::unbounded_channel::<ActionOrThunk>();
+
+        let (command_tx, mut command_rx) = mpsc::unbounded_channel::<String>();
+        let stdin_tx = command_tx.clone();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(tokio::io::stdin()).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if stdin_tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        tokio::spawn(async move {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                if command_tx.send("q".to_string()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        redux_action_tx.send(ThunkAction::LoadLaunchConfigurations.into())?;
+
+        let mut cursor = Cursor::default();
+        // Errors after the launch mean that the app could not be started.
+        let mut launched_at = None;
+        let mut quit = false;
+        loop {
+            while let Ok(action) = redux_action_rx.try_recv() {
+                match action {
+                    ActionOrThunk::Action(action) => {
+                        store.dispatch(action).await;
+                    }
+                    ActionOrThunk::Thunk(action) => {
+                        store
+                            .dispatch(thunk::ActionOrThunk::Thunk(thunk_impl(
+                                action,
+                                context.clone(),
+                            )))
+                            .await;
+                    }
+                }
+            }
+
+            let state = store.state_cloned().await;
+            for event in cursor.notification_events(&state) {
+                self.print(&event)?;
+            }
+
+            match (launched_at, state.sessions.first()) {
+                (None, _) if state.launch_configurations_loaded => {
+                    let target = self.target(&state)?;
+                    redux_action_tx.send(
+                        ThunkAction::RunNewApp {
+                            use_fvm: self.use_fvm,
+                            target: Some(target),
+                        }
+                        .into(),
+                    )?;
+                    launched_at = Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis());
+                }
+                (Some(launched_at), None) => {
+                    if let Some(error) = state.notifications.iter().find(|n| {
+                        n.level == NotificationLevel::Error && n.created_at >= launched_at
+                    }) {
+                        return Err(eyre!(error.message.clone()));
+                    }
+                }
+                (_, Some(session)) => {
+                    for event in cursor.session_events(session) {
+                        self.print(&event)?;
+                    }
+                    if session.stopped && !session.started && !quit {
+                        return Err(match &session.stop_error {
+                            Some(error) => eyre!("The app stopped before it started: {}", error),
+                            None => eyre!("The app stopped before it started"),
+                        });
+                    }
+                    if session.stopped {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+
+            tokio::select! {
+                Some(command) = command_rx.recv() => match command.trim() {
+                    "r" => redux_action_tx.send(ThunkAction::HotReload.into())?,
+                    "R" => redux_action_tx.send(ThunkAction::HotRestart.into())?,
+                    "q" if state.sessions.is_empty() => break,
+                    "q" => {
+                        quit = true;
+                        redux_action_tx.send(ThunkAction::StopApp.into())?;
+                    }
+                    _ => {}
+                },
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_events_as_json() {
+        let event = Event::Log {
+            stream: "stdout",
+            message: "flutter: hello".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"log","stream":"stdout","message":"flutter: hello"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Event::AppStarted).unwrap(),
+            r#"{"event":"app_started"}"#
+        );
+    }
+
+    #[test]
+    fn print_only_new_logs() {
+        let mut cursor = Cursor::default();
+        let mut session = SessionState {
+            logs: vec![
+                SessionLog::Progress {
+                    id: "0".to_string(),
+                    message: Some("Launching".to_string()),
+                    start_at: 0,
+                    end_at: None,
+                },
+                SessionLog::Stdout("a".to_string()),
+            ],
+            ..SessionState::default()
+        };
+        assert_eq!(cursor.session_events(&session).len(), 2);
+
+        session.logs[0] = SessionLog::Progress {
+            id: "0".to_string(),
+            message: Some("Launching".to_string()),
+            start_at: 0,
+            end_at: Some(1),
+        };
+        session.logs.push(SessionLog::Stderr("b".to_string()));
+        assert_eq!(
+            cursor.session_events(&session),
+            vec![
+                Event::Progress {
+                    message: "Launching".to_string(),
+                    finished: true,
+                },
+                Event::Log {
+                    stream: "stderr",
+                    message: "b".to_string(),
+                },
+            ]
+        );
+        assert!(cursor.session_events(&session).is_empty());
+    }
+
+    #[test]
+    fn print_stop_error() {
+        let mut cursor = Cursor::default();
+        let session = SessionState {
+            stopped: true,
+            stop_error: Some("Gradle task assembleDebug failed with exit code 1".to_string()),
+            ..SessionState::default()
+        };
+        let events = cursor.session_events(&session);
+        assert_eq!(
+            serde_json::to_string(&events).unwrap(),
+            r#"[{"event":"app_stop","error":"Gradle task assembleDebug failed with exit code 1"}]"#
+        );
+        assert_eq!(
+            events[0].text().unwrap(),
+            "App stopped: Gradle task assembleDebug failed with exit code 1"
+        );
+    }
+}
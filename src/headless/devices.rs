@@ -0,0 +1,154 @@
+use color_eyre::eyre::Result;
+use daemon::flutter::FlutterDaemon;
+use daemon::io::device::Device;
+use daemon::io::event::FlutterDaemonEvent;
+use serde::Serialize;
+
+use super::print_line;
+
+/// A device as printed by `flx devices`. Kept separate from the daemon's `Device` so that the
+/// output doesn't change when the daemon protocol does.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct DeviceInfo {
+    pub id: String,
+    pub name: String,
+    pub platform: String,
+    pub platform_type: String,
+    pub category: String,
+    pub emulator: bool,
+    pub emulator_id: Option<String>,
+    pub ephemeral: bool,
+    pub sdk: String,
+}
+
+impl From<&Device> for DeviceInfo {
+    fn from(device: &Device) -> Self {
+        Self {
+            id: device.id.clone(),
+            name: device.name.clone(),
+            platform: device.platform.clone(),
+            platform_type: device.platform_type.clone(),
+            category: device.category.clone(),
+            emulator: device.emulator,
+            emulator_id: device.emulator_id.clone(),
+            ephemeral: device.ephemeral,
+            sdk: device.sdk.clone(),
+        }
+    }
+}
+
+impl DeviceInfo {
+    /// Same columns as `flutter devices`.
+    pub fn text(&self) -> String {
+        format!(
+            "{}{} • {} • {} • {}",
+            self.name,
+            if self.emulator { " (emulator)" } else { "" },
+            self.id,
+            self.platform,
+            self.sdk
+        )
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "event")]
+enum DeviceEvent {
+    #[serde(rename = "device.added")]
+    Added { device: DeviceInfo },
+    #[serde(rename = "device.removed")]
+    Removed { device: DeviceInfo },
+}
+
+/// Lists the connected devices, or prints them as they are added and removed.
+pub struct Devices {
+    use_fvm: bool,
+    json: bool,
+    watch: bool,
+}
+
+impl Devices {
+    pub fn new(use_fvm: bool, json: bool, watch: bool) -> Self {
+        Self {
+            use_fvm,
+            json,
+            watch,
+        }
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        let daemon = FlutterDaemon::new(self.use_fvm)?;
+        if self.watch {
+            return self.watch(&daemon).await;
+        }
+
+        let devices = daemon
+            .get_devices()
+            .await?
+            .iter()
+            .map(DeviceInfo::from)
+            .collect::<Vec<_>>();
+        if self.json {
+            print_line(&serde_json::to_string(&devices)?)?;
+        } else if devices.is_empty() {
+            print_line("No devices found")?;
+        } else {
+            for device in devices {
+                print_line(&device.text())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Devices which are already connected are printed as added when the daemon starts polling.
+    /// Runs until the daemon exits, which is an error.
+    async fn watch(&self, daemon: &FlutterDaemon) -> Result<()> {
+        let mut events = daemon.subscribe_events();
+        daemon.enable_device().await?;
+        loop {
+            let event = match events.recv().await? {
+                FlutterDaemonEvent::DeviceAdded { params } => DeviceEvent::Added {
+                    device: DeviceInfo::from(&params),
+                },
+                FlutterDaemonEvent::DeviceRemoved { params } => DeviceEvent::Removed {
+                    device: DeviceInfo::from(&params),
+                },
+                _ => continue,
+            };
+            let line = match (&event, self.json) {
+                (_, true) => serde_json::to_string(&event)?,
+                (DeviceEvent::Added { device }, false) => format!("+ {}", device.text()),
+                (DeviceEvent::Removed { device }, false) => format!("- {}", device.text()),
+            };
+            print_line(&line)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_device_events() {
+        let device = DeviceInfo {
+            id: "emulator-5554".to_string(),
+            name: "sdk gphone64 arm64".to_string(),
+            platform: "android-arm64".to_string(),
+            platform_type: "android".to_string(),
+            category: "mobile".to_string(),
+            emulator: true,
+            emulator_id: Some("Pixel_7_API_34".to_string()),
+            ephemeral: true,
+            sdk: "Android 14 (API 34)".to_string(),
+        };
+        assert_eq!(
+            device.text(),
+            "sdk gphone64 arm64 (emulator) • emulator-5554 • android-arm64 • Android 14 (API 34)"
+        );
+        assert_eq!(
+            serde_json::to_string(&DeviceEvent::Removed { device }).unwrap(),
+            r#"{"event":"device.removed","device":{"id":"emulator-5554","name":"sdk gphone64 arm64","platform":"android-arm64","platform_type":"android","category":"mobile","emulator":true,"emulator_id":"Pixel_7_API_34","ephemeral":true,"sdk":"Android 14 (API 34)"}}"#
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use color_eyre::eyre::{eyre, Result};
+use daemon::flutter::FlutterDaemon;
+use daemon::io::emulator::Emulator;
+use daemon::io::event::FlutterDaemonEvent;
+use serde::Serialize;
+
+use crate::redux::selector::device_or_emulators::is_emulator_running;
+
+use super::devices::DeviceInfo;
+use super::print_line;
+
+// Cold boots of a fresh AVD can take a few minutes.
+const DEVICE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// An emulator as printed by `flx emulators`.
+#[derive(Serialize, Debug, PartialEq)]
+struct EmulatorInfo {
+    id: String,
+    name: String,
+    platform_type: String,
+    category: String,
+    running: bool,
+}
+
+impl EmulatorInfo {
+    fn new(emulator: &Emulator, running: bool) -> Self {
+        Self {
+            id: emulator.id.clone(),
+            name: emulator.name.clone(),
+            platform_type: emulator.platform_type.clone(),
+            category: emulator.category.clone(),
+            running,
+        }
+    }
+
+    fn text(&self) -> String {
+        format!(
+            "{} • {} • {}{}",
+            self.name,
+            self.id,
+            self.platform_type,
+            if self.running { " (running)" } else { "" }
+        )
+    }
+}
+
+/// Lists the available emulators, or launches one of them and waits until it can run apps.
+pub struct Emulators {
+    use_fvm: bool,
+    json: bool,
+    /// The emulator to launch, and whether to cold boot it.
+    launch: Option<(String, bool)>,
+}
+
+impl Emulators {
+    pub fn new(use_fvm: bool, json: bool, launch: Option<(String, bool)>) -> Self {
+        Self {
+            use_fvm,
+            json,
+            launch,
+        }
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        let daemon = FlutterDaemon::new(self.use_fvm)?;
+        match &self.launch {
+            Some((emulator_id, cold_boot)) => self.launch(&daemon, emulator_id, *cold_boot).await,
+            None => self.list(&daemon).await,
+        }
+    }
+
+    async fn list(&self, daemon: &FlutterDaemon) -> Result<()> {
+        let devices = daemon.get_devices().await?;
+        let emulators = daemon
+            .get_emulators()
+            .await?
+            .iter()
+            .map(|emulator| EmulatorInfo::new(emulator, is_emulator_running(emulator, &devices)))
+            .collect::<Vec<_>>();
+        if self.json {
+            print_line(&serde_json::to_string(&emulators)?)?;
+        } else if emulators.is_empty() {
+            print_line("No emulators found")?;
+        } else {
+            for emulator in emulators {
+                print_line(&emulator.text())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the device of the emulator once it is connected.
+    async fn launch(
+        &self,
+        daemon: &FlutterDaemon,
+        emulator_id: &str,
+        cold_boot: bool,
+    ) -> Result<()> {
+        let emulators = daemon.get_emulators().await?;
+        let Some(emulator) = emulators.iter().find(|e| e.id == emulator_id) else {
+            return Err(eyre!(
+                "Emulator {} is not found. Available: {}",
+                emulator_id,
+                emulators
+                    .iter()
+                    .map(|e| e.id.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        };
+
+        // Devices which are already connected are reported as added when polling starts, so
+        // this also works for an emulator which is running. Listen while launching so that the
+        // device is not missed.
+        let mut events = daemon.subscribe_events();
+        let launch = async {
+            daemon.enable_device().await?;
+            daemon.launch_emulator(emulator.id.clone(), cold_boot).await
+        };
+        let wait_for_device = async {
+            loop {
+                if let FlutterDaemonEvent::DeviceAdded { params: device } = events.recv().await? {
+                    if device.emulator_id.as_ref() == Some(&emulator.id) {
+                        return Ok(device);
+                    }
+                }
+            }
+        };
+        let ((), device) = tokio::try_join!(launch, async {
+            tokio::time::timeout(DEVICE_TIMEOUT, wait_for_device)
+                .await
+                .map_err(|_| {
+                    eyre!(
+                        "{} was launched, but its device did not connect",
+                        emulator.name
+                    )
+                })?
+        })?;
+
+        let device = DeviceInfo::from(&device);
+        if self.json {
+            print_line(&serde_json::to_string(&device)?)
+        } else {
+            print_line(&device.text())
+        }
+    }
+}
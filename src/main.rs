@@ -6,18 +6,24 @@ pub mod action;
 pub mod app;
 pub mod cli;
 pub mod components;
+pub mod config;
+#[cfg(unix)]
+pub mod control;
+pub mod headless;
 pub mod redux;
 pub mod session;
+pub mod theme;
 pub mod tui;
 pub mod utils;
 pub mod widgets;
 
 use clap::Parser;
-use cli::Cli;
+use cli::{Cli, Commands, EmulatorsCommands};
 use color_eyre::eyre::Result;
 
 use crate::{
     app::App,
+    headless::{devices::Devices, emulators::Emulators, Headless},
     utils::{initialize_logging, initialize_panic_handler, version},
 };
 
@@ -27,8 +33,41 @@ async fn tokio_main() -> Result<()> {
     initialize_panic_handler()?;
 
     let args = Cli::parse();
-    let mut app = App::new(args.project_root, args.fvm)?;
-    app.run().await?;
+    match args.command {
+        Some(Commands::Run(run)) => {
+            let mut headless = Headless::new(
+                args.project_root,
+                args.fvm,
+                run.config,
+                run.device,
+                run.json,
+            )?;
+            headless.run().await?;
+        }
+        Some(Commands::Devices(devices)) => {
+            Devices::new(args.fvm, devices.json, devices.watch)
+                .run()
+                .await?;
+        }
+        Some(Commands::Emulators(emulators)) => {
+            let launch = emulators
+                .command
+                .map(|EmulatorsCommands::Launch { id, cold }| (id, cold));
+            Emulators::new(args.fvm, emulators.json, launch)
+                .run()
+                .await?;
+        }
+        None => {
+            let mut app = App::new(
+                args.project_root,
+                args.fvm,
+                args.hot_reload_on_save,
+                args.vm_service_uri,
+                args.control_socket,
+            )?;
+            app.run().await?;
+        }
+    }
 
     Ok(())
 }
@@ -1,7 +1,10 @@
-use super::state::LaunchConfiguration;
+use super::state::{
+    DumpKind, Focus, LaunchConfiguration, LaunchConfigurationForm, NotificationLevel, PortForward,
+    ProjectState, ReloadResult, WidgetScreenshot,
+};
 use daemon::io::{device::Device, emulator::Emulator, event::AppMode};
 use devtools::protocols::{
-    flutter_extension::DiagnosticNode,
+    flutter_extension::{DiagnosticNode, Location},
     io_extension::{HttpProfileRequest, HttpProfileRequestRef},
 };
 use std::time::Duration;
@@ -39,6 +42,15 @@ pub enum Action {
         configurations: Vec<LaunchConfiguration>,
     },
 
+    SetDiscoveredFlavors {
+        flavors: Vec<String>,
+    },
+
+    ShowLaunchConfigurationEditor {
+        form: LaunchConfigurationForm,
+    },
+    HideLaunchConfigurationEditor,
+
     NextHomeTab,
     PreviousHomeTab,
     NextDevToolsTab,
@@ -46,14 +58,31 @@ pub enum Action {
 
     EnterDevTools,
     ExitDevTools,
+    SetFocus {
+        focus: Focus,
+    },
+    RestoreProjectState {
+        project_state: ProjectState,
+    },
+    ToggleZoom,
+    /// Widens the left column of the current screen by `delta` percent, or narrows it if negative.
+    ResizeSidebar {
+        delta: i16,
+    },
 
     RegisterSession {
         session_id: String,
         device_id: Option<String>,
         configuration: Option<LaunchConfiguration>,
+        group_id: Option<String>,
+    },
+    SetVmServiceUri {
+        session_id: String,
+        uri: String,
     },
     StopSession {
         session_id: String,
+        error: Option<String>,
     },
     UnregisterSession {
         session_id: String,
@@ -65,16 +94,33 @@ pub enum Action {
 
     NextSession,
     PreviousSession,
+    SelectSession {
+        session_id: String,
+    },
 
     NextDevice,
     PreviousDevice,
+    SelectDeviceOrEmulator {
+        id: String,
+    },
 
     NextDeviceForRunning,
     PreviousDeviceForRunning,
+    ToggleDeviceForRunning,
+    ToggleBroadcastReload,
+    SetHotReloadOnSave {
+        enabled: bool,
+    },
 
     ShowSelectDevicePopUp,
     HideSelectDevicePopUp,
 
+    ShowCommandPalette,
+    HideCommandPalette,
+
+    ShowHelp,
+    HideHelp,
+
     ShowSelectLaunchConfigurationPopup,
     HideSelectLaunchConfigurationPopuup,
 
@@ -114,6 +160,11 @@ pub enum Action {
         message: Option<String>,
     },
 
+    AppendReloadResult {
+        session_id: String,
+        result: ReloadResult,
+    },
+
     AppendStdoutLog {
         session_id: String,
         line: String,
@@ -194,21 +245,103 @@ pub enum Action {
 
     NextLog,
     PreviousLog,
+    SelectLog {
+        index: u64,
+    },
 
     NextFrame,
     PreviousFrame,
 
     NextReqest,
     PreviousRequest,
+    SelectRequest {
+        id: String,
+    },
 
     EnterNetworkRequest,
     ExitNetworkRequest,
 
-    SetDebugPaintEnabled {
+    AppendRebuildCounts {
         session_id: String,
-        enabled: bool,
+        locations: Vec<(i64, Location)>,
+        counts: Vec<(i64, u64)>,
+    },
+    ClearRebuildCounts {
+        session_id: String,
+    },
+
+    NextRebuildLocation,
+    PreviousRebuildLocation,
+
+    NextDumpKind,
+    PreviousDumpKind,
+
+    SetDump {
+        session_id: String,
+        kind: DumpKind,
+        dump: String,
+    },
+    SetSavedDumpPath {
+        session_id: String,
+        path: Option<PathBuf>,
+    },
+
+    ToggleWidgetPreview,
+    SetWidgetScreenshot {
+        session_id: String,
+        screenshot: Option<WidgetScreenshot>,
+    },
+    SetSavedScreenshotPath {
+        session_id: String,
+        path: Option<PathBuf>,
+    },
+
+    EnterDumpDetails,
+    ExitDumpDetails,
+
+    EnterAppSettings,
+    ExitAppSettings,
+    ShowTimeDilationPopup,
+    HideTimeDilationPopup,
+
+    ShowCreateEmulatorPopup,
+    HideCreateEmulatorPopup,
+
+    EnterPortForwards,
+    ExitPortForwards,
+    ShowAddPortForwardPopup,
+    HideAddPortForwardPopup,
+    AddPortForward {
+        forward: PortForward,
+    },
+    RemovePortForward {
+        forward: PortForward,
+    },
+
+    ShowNotification {
+        level: NotificationLevel,
+        message: String,
     },
-    SetSlowAnimationsEnabled {
+    SetTargetPlatform {
+        session_id: String,
+        platform: Option<String>,
+    },
+    SetBrightness {
+        session_id: String,
+        brightness: Option<String>,
+    },
+    SetTimeDilation {
+        session_id: String,
+        time_dilation: Option<String>,
+    },
+
+    ShowSearchDumpPopup,
+    HideSearchDumpPopup,
+    SetDumpSearchQuery {
+        query: String,
+    },
+
+    SetDebugPaintEnabled {
         session_id: String,
         enabled: bool,
     },
@@ -232,4 +365,12 @@ pub enum Action {
         session_id: String,
         enabled: bool,
     },
+    SetTrackRebuildWidgetsEnabled {
+        session_id: String,
+        enabled: bool,
+    },
+    SetTrackRepaintWidgetsEnabled {
+        session_id: String,
+        enabled: bool,
+    },
 }
@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use redux_rs::Selector;
@@ -8,15 +9,69 @@ use super::{
     action::Action,
     selector::{
         availale_devices::available_devices_selector,
+        current_session_rebuild_stats::current_session_rebuild_stats_selector,
         device_or_emulators::{self, device_or_emulators_selector, DeviceOrEmulator},
         selected_device::{self, selected_device_selector},
     },
     state::{
-        DevTools, FlutterFrame, Home, SdkVersion, SelectLaunchConfigurationPopupState,
-        SessionState, State,
+        DebugToggles, DevTools, DumpKind, FlutterFrame, Home, LaunchConfiguration,
+        LaunchConfigurationSource, LaunchRequest, Notification, PaneLayout, SdkVersion,
+        SelectLaunchConfigurationPopupState, SessionState, State,
     },
 };
 
+const MAX_NOTIFICATIONS: usize = 5;
+const MAX_RELOAD_HISTORY: usize = 20;
+
+/// Appends a configuration for each flavor which is not passed with `--flavor` by any of
+/// `configurations`.
+fn with_flavor_configurations(
+    configurations: Vec<LaunchConfiguration>,
+    flavors: &[String],
+) -> Vec<LaunchConfiguration> {
+    let covered = configurations
+        .iter()
+        .flat_map(|c| {
+            let args = [
+                c.tool_args.clone().unwrap_or_default(),
+                c.args.clone().unwrap_or_default(),
+            ]
+            .concat();
+            let mut flavors = vec![];
+            for (i, arg) in args.iter().enumerate() {
+                if let Some(flavor) = arg.strip_prefix("--flavor=") {
+                    flavors.push(flavor.to_string());
+                } else if arg == "--flavor" {
+                    flavors.extend(args.get(i + 1).cloned());
+                }
+            }
+            flavors
+        })
+        .collect::<HashSet<_>>();
+
+    let discovered = flavors
+        .iter()
+        .filter(|flavor| !covered.contains(*flavor))
+        .map(|flavor| LaunchConfiguration {
+            name: flavor.clone(),
+            request: LaunchRequest::Launch,
+            program: None,
+            args: None,
+            cwd: None,
+            flutter_mode: None,
+            vm_service_uri: None,
+            app_id: None,
+            tool_args: Some(vec!["--flavor".to_string(), flavor.clone()]),
+            env: None,
+            device_id: None,
+            pre_launch: None,
+            source: LaunchConfigurationSource::Discovered,
+            source_index: None,
+        })
+        .collect::<Vec<_>>();
+    [configurations, discovered].concat()
+}
+
 pub fn reducer(state: State, action: Action) -> State {
     match action {
         Action::SetSdkVersion {
@@ -62,6 +117,7 @@ pub fn reducer(state: State, action: Action) -> State {
                 new_state = State {
                     select_device_popup: SelectDevicePopupState {
                         selected_device_id: first_device.map(|d| d.id.clone()),
+                        ..new_state.select_device_popup.clone()
                     },
                     ..new_state
                 };
@@ -70,11 +126,57 @@ pub fn reducer(state: State, action: Action) -> State {
         }
         Action::RemoveDevice { device } => State {
             devices: state.devices.into_iter().filter(|d| d != &device).collect(),
+            port_forwards: state
+                .port_forwards
+                .into_iter()
+                .filter(|f| f.device_id != device.id)
+                .collect(),
+            focus: if state.focus == Focus::Home(Home::PortForwards)
+                && state.selected_device_or_emulator_id == Some(device.id.clone())
+            {
+                Focus::Home(Home::Devices)
+            } else {
+                state.focus
+            },
             ..state
         },
         Action::SetEmultors { emulators } => State { emulators, ..state },
-        Action::SetLaunchConfigurations { configurations } => State {
-            launch_configurations: configurations,
+        Action::SetLaunchConfigurations { configurations } => {
+            let launch_configurations =
+                with_flavor_configurations(configurations, &state.discovered_flavors);
+            State {
+                select_launch_configuration_poopup: SelectLaunchConfigurationPopupState {
+                    selected_index: state
+                        .select_launch_configuration_poopup
+                        .selected_index
+                        .filter(|_| !launch_configurations.is_empty())
+                        .map(|index| index.min(launch_configurations.len() - 1)),
+                },
+                launch_configurations,
+                launch_configurations_loaded: true,
+                ..state
+            }
+        }
+        Action::SetDiscoveredFlavors { flavors } => State {
+            launch_configurations: with_flavor_configurations(
+                state
+                    .launch_configurations
+                    .into_iter()
+                    .filter(|c| c.source != LaunchConfigurationSource::Discovered)
+                    .collect(),
+                &flavors,
+            ),
+            discovered_flavors: flavors,
+            ..state
+        },
+        Action::ShowLaunchConfigurationEditor { form } => State {
+            popup: Some(PopUp::EditLaunchConfiguration),
+            launch_configuration_form: Some(form),
+            ..state
+        },
+        Action::HideLaunchConfigurationEditor => State {
+            popup: Some(PopUp::SelectLaunchConfiguration),
+            launch_configuration_form: None,
             ..state
         },
         Action::NextHomeTab => State {
@@ -99,7 +201,9 @@ pub fn reducer(state: State, action: Action) -> State {
             focus: match state.focus {
                 Focus::DevTools(DevTools::App) => Focus::DevTools(DevTools::Inspector),
                 Focus::DevTools(DevTools::Inspector) => Focus::DevTools(DevTools::Performance),
-                Focus::DevTools(DevTools::Performance) => Focus::DevTools(DevTools::Network),
+                Focus::DevTools(DevTools::Performance) => Focus::DevTools(DevTools::RebuildStats),
+                Focus::DevTools(DevTools::RebuildStats) => Focus::DevTools(DevTools::Dumps),
+                Focus::DevTools(DevTools::Dumps) => Focus::DevTools(DevTools::Network),
                 Focus::DevTools(DevTools::Network) => Focus::DevTools(DevTools::App),
                 _ => state.focus,
             },
@@ -110,7 +214,9 @@ pub fn reducer(state: State, action: Action) -> State {
                 Focus::DevTools(DevTools::App) => Focus::DevTools(DevTools::Network),
                 Focus::DevTools(DevTools::Inspector) => Focus::DevTools(DevTools::App),
                 Focus::DevTools(DevTools::Performance) => Focus::DevTools(DevTools::Inspector),
-                Focus::DevTools(DevTools::Network) => Focus::DevTools(DevTools::Performance),
+                Focus::DevTools(DevTools::RebuildStats) => Focus::DevTools(DevTools::Performance),
+                Focus::DevTools(DevTools::Dumps) => Focus::DevTools(DevTools::RebuildStats),
+                Focus::DevTools(DevTools::Network) => Focus::DevTools(DevTools::Dumps),
                 _ => state.focus,
             },
             ..state
@@ -129,18 +235,75 @@ pub fn reducer(state: State, action: Action) -> State {
             focus: Focus::Home(Home::Runners),
             ..state
         },
+        Action::SetFocus { focus } => {
+            if matches!(focus, Focus::DevTools(_)) && state.session_id.is_none() {
+                state
+            } else {
+                State { focus, ..state }
+            }
+        }
+        Action::RestoreProjectState { project_state } => State {
+            focus: match state.focus {
+                Focus::Home(_) => Focus::Home(project_state.home),
+                focus => focus,
+            },
+            selected_device_or_emulator_id: state
+                .selected_device_or_emulator_id
+                .or(project_state.device_id.clone()),
+            last_device_id: project_state.device_id,
+            last_launch_configuration: project_state.launch_configuration,
+            pane_layout: project_state.pane_layout,
+            debug_toggles: project_state.debug_toggles,
+            project_state_restored: true,
+            ..state
+        },
+        Action::ToggleZoom => State {
+            pane_layout: PaneLayout {
+                zoomed: !state.pane_layout.zoomed,
+                ..state.pane_layout
+            },
+            ..state
+        },
+        Action::ResizeSidebar { delta } => {
+            let resize = |width: u16| {
+                width
+                    .saturating_add_signed(delta)
+                    .clamp(PaneLayout::MIN_SIDEBAR_WIDTH, PaneLayout::MAX_SIDEBAR_WIDTH)
+            };
+            let layout = state.pane_layout;
+            State {
+                pane_layout: match state.focus {
+                    Focus::Home(_) => PaneLayout {
+                        home_sidebar_width: resize(layout.home_sidebar_width),
+                        ..layout
+                    },
+                    Focus::DevTools(_) => PaneLayout {
+                        devtools_sidebar_width: resize(layout.devtools_sidebar_width),
+                        ..layout
+                    },
+                },
+                ..state
+            }
+        }
         Action::RegisterSession {
             session_id,
             device_id,
             configuration,
+            group_id,
         } => State {
             session_id: Some(session_id.clone()),
+            last_device_id: device_id.clone().or(state.last_device_id),
+            last_launch_configuration: configuration
+                .as_ref()
+                .map(|c| c.name.clone())
+                .or(state.last_launch_configuration),
             sessions: [
                 state.sessions,
                 vec![SessionState {
                     id: session_id,
                     device_id,
                     configuration,
+                    group_id,
                     display_refresh_rate: 60.0,
                     ..SessionState::default()
                 }],
@@ -148,7 +311,24 @@ pub fn reducer(state: State, action: Action) -> State {
             .concat(),
             ..state
         },
-        Action::StopSession { session_id } => State {
+        Action::SetVmServiceUri { session_id, uri } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            vm_service_uri: Some(uri.clone()),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::StopSession { session_id, error } => State {
             focus: {
                 if Some(session_id.clone()) == state.session_id {
                     Focus::Home(Home::Runners)
@@ -161,7 +341,11 @@ pub fn reducer(state: State, action: Action) -> State {
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
-                        SessionState { stopped: true, ..s }
+                        SessionState {
+                            stopped: true,
+                            stop_error: error.clone(),
+                            ..s
+                        }
                     } else {
                         s
                     }
@@ -221,6 +405,19 @@ pub fn reducer(state: State, action: Action) -> State {
             },
             ..state
         },
+        Action::SelectSession { session_id } => State {
+            session_id: state
+                .sessions
+                .iter()
+                .any(|s| s.id == session_id)
+                .then_some(session_id)
+                .or(state.session_id),
+            ..state
+        },
+        Action::SelectDeviceOrEmulator { id } => State {
+            selected_device_or_emulator_id: Some(id),
+            ..state
+        },
         Action::NextDevice => State {
             selected_device_or_emulator_id: match state.selected_device_or_emulator_id {
                 Some(ref selected_device_id) => {
@@ -311,6 +508,7 @@ pub fn reducer(state: State, action: Action) -> State {
                         }
                     }
                 },
+                checked_device_ids: state.select_device_popup.checked_device_ids.clone(),
             },
             ..state
         },
@@ -340,6 +538,7 @@ pub fn reducer(state: State, action: Action) -> State {
                         }
                     }
                 },
+                checked_device_ids: state.select_device_popup.checked_device_ids.clone(),
             },
             ..state
         },
@@ -386,15 +585,67 @@ pub fn reducer(state: State, action: Action) -> State {
             ..state
         },
         Action::ShowSelectDevicePopUp => {
-            let first_available_device = available_devices_selector(&state).next();
+            let last_device = available_devices_selector(&state)
+                .find(|d| Some(&d.id) == state.last_device_id.as_ref());
+            let device = last_device.or_else(|| available_devices_selector(&state).next());
             State {
                 popup: Some(PopUp::SelectDevice),
                 select_device_popup: SelectDevicePopupState {
-                    selected_device_id: first_available_device.map(|d| d.id.clone()),
+                    selected_device_id: device.map(|d| d.id.clone()),
+                    checked_device_ids: vec![],
+                },
+                ..state
+            }
+        }
+        Action::ShowCommandPalette => State {
+            popup: Some(PopUp::CommandPalette),
+            ..state
+        },
+        Action::HideCommandPalette => State {
+            popup: None,
+            ..state
+        },
+        Action::ShowHelp => {
+            if state.popup == Some(PopUp::Help) {
+                return state;
+            }
+            State {
+                popup: Some(PopUp::Help),
+                popup_under_help: state.popup.clone(),
+                ..state
+            }
+        }
+        Action::HideHelp => State {
+            popup: state.popup_under_help.clone(),
+            popup_under_help: None,
+            ..state
+        },
+        Action::ToggleDeviceForRunning => {
+            let Some(device_id) = state.select_device_popup.selected_device_id.clone() else {
+                return state;
+            };
+            let mut checked_device_ids = state.select_device_popup.checked_device_ids.clone();
+            if let Some(index) = checked_device_ids.iter().position(|id| id == &device_id) {
+                checked_device_ids.remove(index);
+            } else {
+                checked_device_ids.push(device_id);
+            }
+            State {
+                select_device_popup: SelectDevicePopupState {
+                    checked_device_ids,
+                    ..state.select_device_popup
                 },
                 ..state
             }
         }
+        Action::ToggleBroadcastReload => State {
+            broadcast_reload: !state.broadcast_reload,
+            ..state
+        },
+        Action::SetHotReloadOnSave { enabled } => State {
+            hot_reload_on_save: enabled,
+            ..state
+        },
         Action::HideSelectDevicePopUp => State {
             popup: None,
             ..state
@@ -405,7 +656,11 @@ pub fn reducer(state: State, action: Action) -> State {
                 selected_index: if state.launch_configurations.is_empty() {
                     None
                 } else {
-                    Some(0)
+                    state
+                        .launch_configurations
+                        .iter()
+                        .position(|c| Some(&c.name) == state.last_launch_configuration.as_ref())
+                        .or(Some(0))
                 },
             },
             ..state
@@ -623,6 +878,32 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
+        Action::AppendReloadResult { session_id, result } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            reloads: [
+                                s.reloads
+                                    .into_iter()
+                                    .rev()
+                                    .take(MAX_RELOAD_HISTORY - 1)
+                                    .rev()
+                                    .collect(),
+                                vec![result.clone()],
+                            ]
+                            .concat(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
         Action::AppendStdoutLog { session_id, line } => State {
             sessions: state
                 .sessions
@@ -881,6 +1162,23 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
+        Action::SelectLog { index } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if Some(s.id.clone()) == state.session_id && index < s.logs.len() as u64 {
+                        SessionState {
+                            selected_log_index: Some(index),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
         Action::NextFrame => State {
             sessions: state
                 .sessions
@@ -1015,6 +1313,25 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
+        Action::SelectRequest { id } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if Some(s.id.clone()) == state.session_id
+                        && s.requests.iter().any(|r| r.id == id)
+                    {
+                        SessionState {
+                            selected_request_id: Some(id.clone()),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
         Action::EnterNetworkRequest => State {
             focus: Focus::DevTools(DevTools::NetworkRequest),
             ..state
@@ -1023,6 +1340,404 @@ pub fn reducer(state: State, action: Action) -> State {
             focus: Focus::DevTools(DevTools::Network),
             ..state
         },
+        Action::AppendRebuildCounts {
+            session_id,
+            locations,
+            counts,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|mut s| {
+                    if s.id == session_id {
+                        s.rebuild_locations.extend(locations.clone());
+                        for count in s.rebuild_counts.values_mut() {
+                            count.last_frame = 0;
+                        }
+                        for (id, count) in counts.iter() {
+                            let entry = s.rebuild_counts.entry(*id).or_default();
+                            entry.last_frame += count;
+                            entry.total += count;
+                        }
+                        SessionState {
+                            selected_rebuild_location_id: s
+                                .selected_rebuild_location_id
+                                .or_else(|| counts.first().map(|(id, _)| *id)),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::ClearRebuildCounts { session_id } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            rebuild_counts: HashMap::new(),
+                            selected_rebuild_location_id: None,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::NextRebuildLocation => {
+            let ids = current_session_rebuild_stats_selector(&state)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            State {
+                sessions: state
+                    .sessions
+                    .into_iter()
+                    .map(|s| {
+                        if Some(s.id.clone()) == state.session_id {
+                            SessionState {
+                                selected_rebuild_location_id: {
+                                    if let Some(index) = ids
+                                        .iter()
+                                        .position(|id| Some(*id) == s.selected_rebuild_location_id)
+                                    {
+                                        ids.get(index + 1).or(ids.last()).copied()
+                                    } else {
+                                        ids.first().copied()
+                                    }
+                                },
+                                ..s
+                            }
+                        } else {
+                            s
+                        }
+                    })
+                    .collect(),
+                ..state
+            }
+        }
+        Action::PreviousRebuildLocation => {
+            let ids = current_session_rebuild_stats_selector(&state)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            State {
+                sessions: state
+                    .sessions
+                    .into_iter()
+                    .map(|s| {
+                        if Some(s.id.clone()) == state.session_id {
+                            SessionState {
+                                selected_rebuild_location_id: {
+                                    if let Some(index) = ids
+                                        .iter()
+                                        .position(|id| Some(*id) == s.selected_rebuild_location_id)
+                                    {
+                                        ids.get(index.saturating_sub(1)).copied()
+                                    } else {
+                                        ids.first().copied()
+                                    }
+                                },
+                                ..s
+                            }
+                        } else {
+                            s
+                        }
+                    })
+                    .collect(),
+                ..state
+            }
+        }
+        Action::NextDumpKind => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if Some(s.id.clone()) == state.session_id {
+                        let index = DumpKind::ALL
+                            .iter()
+                            .position(|kind| kind == &s.selected_dump_kind)
+                            .unwrap_or_default();
+                        SessionState {
+                            selected_dump_kind: DumpKind::ALL
+                                [(index + 1).min(DumpKind::ALL.len() - 1)],
+                            saved_dump_path: None,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::PreviousDumpKind => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if Some(s.id.clone()) == state.session_id {
+                        let index = DumpKind::ALL
+                            .iter()
+                            .position(|kind| kind == &s.selected_dump_kind)
+                            .unwrap_or_default();
+                        SessionState {
+                            selected_dump_kind: DumpKind::ALL[index.saturating_sub(1)],
+                            saved_dump_path: None,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetDump {
+            session_id,
+            kind,
+            dump,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|mut s| {
+                    if s.id == session_id {
+                        s.dumps.insert(kind, dump.clone());
+                        SessionState {
+                            dumps: s.dumps,
+                            saved_dump_path: None,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetSavedDumpPath { session_id, path } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            saved_dump_path: path.clone(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::ToggleWidgetPreview => State {
+            widget_preview_enabled: !state.widget_preview_enabled,
+            ..state
+        },
+        Action::SetWidgetScreenshot {
+            session_id,
+            screenshot,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            widget_screenshot: screenshot.clone(),
+                            saved_screenshot_path: None,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetSavedScreenshotPath { session_id, path } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            saved_screenshot_path: path.clone(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::EnterDumpDetails => State {
+            focus: Focus::DevTools(DevTools::DumpDetails),
+            ..state
+        },
+        Action::ExitDumpDetails => State {
+            focus: Focus::DevTools(DevTools::Dumps),
+            ..state
+        },
+        Action::EnterAppSettings => State {
+            focus: Focus::DevTools(DevTools::AppSettings),
+            ..state
+        },
+        Action::ExitAppSettings => State {
+            focus: Focus::DevTools(DevTools::App),
+            ..state
+        },
+        Action::ShowTimeDilationPopup => State {
+            popup: Some(PopUp::TimeDilation),
+            ..state
+        },
+        Action::HideTimeDilationPopup => State {
+            popup: None,
+            ..state
+        },
+        Action::EnterPortForwards => State {
+            focus: Focus::Home(Home::PortForwards),
+            ..state
+        },
+        Action::ExitPortForwards => State {
+            focus: Focus::Home(Home::Devices),
+            ..state
+        },
+        Action::ShowAddPortForwardPopup => State {
+            popup: Some(PopUp::AddPortForward),
+            ..state
+        },
+        Action::HideAddPortForwardPopup => State {
+            popup: None,
+            ..state
+        },
+        Action::AddPortForward { forward } => State {
+            port_forwards: [state.port_forwards, vec![forward]].concat(),
+            ..state
+        },
+        Action::RemovePortForward { forward } => State {
+            port_forwards: state
+                .port_forwards
+                .into_iter()
+                .filter(|f| f != &forward)
+                .collect(),
+            ..state
+        },
+        Action::ShowCreateEmulatorPopup => State {
+            popup: Some(PopUp::CreateEmulator),
+            ..state
+        },
+        Action::HideCreateEmulatorPopup => State {
+            popup: None,
+            ..state
+        },
+        Action::ShowNotification { level, message } => State {
+            notifications: [
+                state
+                    .notifications
+                    .into_iter()
+                    .rev()
+                    .take(MAX_NOTIFICATIONS - 1)
+                    .rev()
+                    .collect(),
+                vec![Notification {
+                    level,
+                    message,
+                    created_at: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis(),
+                }],
+            ]
+            .concat(),
+            ..state
+        },
+        Action::SetTargetPlatform {
+            session_id,
+            platform,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            target_platform: platform.clone(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetBrightness {
+            session_id,
+            brightness,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            brightness: brightness.clone(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetTimeDilation {
+            session_id,
+            time_dilation,
+        } => State {
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            slow_animations_enabled: time_dilation
+                                .as_ref()
+                                .and_then(|value| value.parse::<f64>().ok())
+                                .is_some_and(|value| value != 1.0),
+                            time_dilation: time_dilation.clone(),
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::ShowSearchDumpPopup => State {
+            popup: Some(PopUp::SearchDump),
+            ..state
+        },
+        Action::HideSearchDumpPopup => State {
+            popup: None,
+            ..state
+        },
+        Action::SetDumpSearchQuery { query } => State {
+            dump_search_query: query,
+            ..state
+        },
         Action::SetWidgetSummaryTree { session_id, tree } => State {
             sessions: state
                 .sessions
@@ -1184,6 +1899,10 @@ pub fn reducer(state: State, action: Action) -> State {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                debug_paint: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
@@ -1200,17 +1919,21 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetSlowAnimationsEnabled {
+        Action::SetDebugPaintBaselinesEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                debug_paint_baselines: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            slow_animations_enabled: enabled,
+                            debug_paint_baselines_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1220,17 +1943,21 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetDebugPaintBaselinesEnabled {
+        Action::SetRepaintRainbowEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                repaint_rainbow: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            debug_paint_baselines_enabled: enabled,
+                            repaint_rainbow_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1240,17 +1967,21 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetRepaintRainbowEnabled {
+        Action::SetInvertOversizedImagesEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                invert_oversized_images: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            repaint_rainbow_enabled: enabled,
+                            invert_oversized_images_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1260,17 +1991,21 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetInvertOversizedImagesEnabled {
+        Action::SetShowPerformanceOverlayEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                show_performance_overlay: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            invert_oversized_images_enabled: enabled,
+                            show_performance_overlay_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1280,17 +2015,21 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetShowPerformanceOverlayEnabled {
+        Action::SetShowWidgetInspectorEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                show_widget_inspector: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            show_performance_overlay_enabled: enabled,
+                            show_widget_inspector_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1300,17 +2039,45 @@ pub fn reducer(state: State, action: Action) -> State {
                 .collect(),
             ..state
         },
-        Action::SetShowWidgetInspectorEnabled {
+        Action::SetTrackRebuildWidgetsEnabled {
             session_id,
             enabled,
         } => State {
+            debug_toggles: DebugToggles {
+                track_rebuild_widgets: enabled,
+                ..state.debug_toggles
+            },
             sessions: state
                 .sessions
                 .into_iter()
                 .map(|s| {
                     if s.id == session_id {
                         SessionState {
-                            show_widget_inspector_enabled: enabled,
+                            track_rebuild_widgets_enabled: enabled,
+                            ..s
+                        }
+                    } else {
+                        s
+                    }
+                })
+                .collect(),
+            ..state
+        },
+        Action::SetTrackRepaintWidgetsEnabled {
+            session_id,
+            enabled,
+        } => State {
+            debug_toggles: DebugToggles {
+                track_repaint_widgets: enabled,
+                ..state.debug_toggles
+            },
+            sessions: state
+                .sessions
+                .into_iter()
+                .map(|s| {
+                    if s.id == session_id {
+                        SessionState {
+                            track_repaint_widgets_enabled: enabled,
                             ..s
                         }
                     } else {
@@ -1,5 +1,8 @@
 pub mod availale_devices;
 pub mod current_session;
 pub mod current_session_logs;
+pub mod current_session_rebuild_stats;
 pub mod device_or_emulators;
+pub mod project_state;
+pub mod reload_target_sessions;
 pub mod selected_device;
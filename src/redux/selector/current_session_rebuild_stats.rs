@@ -0,0 +1,17 @@
+use crate::redux::state::{RebuildCount, State};
+
+use super::current_session::current_session_selector;
+
+/// Rebuild counts of the current session, the most rebuilt locations first.
+pub fn current_session_rebuild_stats_selector(state: &State) -> Vec<(i64, &RebuildCount)> {
+    let Some(session) = current_session_selector(state) else {
+        return vec![];
+    };
+    let mut stats = session
+        .rebuild_counts
+        .iter()
+        .map(|(id, count)| (*id, count))
+        .collect::<Vec<_>>();
+    stats.sort_by(|(a_id, a), (b_id, b)| b.total.cmp(&a.total).then(a_id.cmp(b_id)));
+    stats
+}
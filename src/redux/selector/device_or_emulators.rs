@@ -17,26 +17,26 @@ pub fn device_or_emulators_selector(state: &State) -> Vec<DeviceOrEmulator> {
         .collect::<Vec<_>>();
 
     for emulator in state.emulators.iter() {
-        // If there are already running ios simultor, we don't need to show the ios simulator
-        if &emulator.id == "apple_ios_simulator"
-            && state
-                .devices
-                .iter()
-                .any(|d| &d.platform_type == "ios" && d.emulator)
-        {
+        if is_emulator_running(emulator, &state.devices) {
             continue;
         }
-
-        // If there are already running android emulator, we don't need to show the android emulator
-        if state
-            .devices
-            .iter()
-            .any(|d| d.emulator && d.id == emulator.id)
-        {
-            continue;
-        }
-
         devices.push(DeviceOrEmulator::Emulator(emulator.clone()));
     }
     devices
 }
+
+pub fn is_emulator_running(emulator: &Emulator, devices: &[Device]) -> bool {
+    // If there are already running ios simultor, we don't need to show the ios simulator
+    if &emulator.id == "apple_ios_simulator"
+        && devices
+            .iter()
+            .any(|d| &d.platform_type == "ios" && d.emulator)
+    {
+        return true;
+    }
+
+    // If there are already running android emulator, we don't need to show the android emulator
+    devices.iter().any(|d| {
+        d.emulator && (d.id == emulator.id || d.emulator_id.as_ref() == Some(&emulator.id))
+    })
+}
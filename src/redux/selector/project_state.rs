@@ -0,0 +1,16 @@
+use crate::redux::state::{Focus, Home, ProjectState, State};
+
+pub fn project_state_selector(state: &State) -> ProjectState {
+    ProjectState {
+        // Port forwards are listed for the selected device, which may not be connected next time.
+        home: match &state.focus {
+            Focus::Home(Home::PortForwards) => Home::Devices,
+            Focus::Home(home) => home.clone(),
+            Focus::DevTools(_) => Home::Runners,
+        },
+        device_id: state.last_device_id.clone(),
+        launch_configuration: state.last_launch_configuration.clone(),
+        pane_layout: state.pane_layout,
+        debug_toggles: state.debug_toggles,
+    }
+}
@@ -0,0 +1,20 @@
+use crate::redux::state::State;
+
+use super::current_session::current_session_selector;
+
+/// Returns the ids of the sessions that hot reload and hot restart apply to. In broadcast
+/// mode, these are all running sessions launched together with the current one.
+pub fn reload_target_session_ids_selector(state: &State) -> Vec<String> {
+    let Some(current_session) = current_session_selector(state) else {
+        return vec![];
+    };
+    match &current_session.group_id {
+        Some(group_id) if state.broadcast_reload => state
+            .sessions
+            .iter()
+            .filter(|s| s.group_id.as_ref() == Some(group_id) && !s.stopped)
+            .map(|s| s.id.clone())
+            .collect(),
+        _ => vec![current_session.id.clone()],
+    }
+}
@@ -1,19 +1,23 @@
 use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use std::{collections::HashMap, time::SystemTime};
 
 use daemon::io::emulator::Emulator;
 use daemon::io::{device::Device, event::AppMode};
-use devtools::protocols::flutter_extension::DiagnosticNode;
+use devtools::protocols::flutter_extension::{DiagnosticNode, Location};
 use devtools::protocols::io_extension::{HttpProfileRequest, HttpProfileRequestRef};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Home {
     Project,
     #[default]
     Runners,
     Devices,
+    PortForwards,
 }
 
 #[derive(Clone, PartialEq, Eq, Default)]
@@ -21,20 +25,46 @@ pub enum PopUp {
     #[default]
     SelectDevice,
     SelectLaunchConfiguration,
+    SearchDump,
+    TimeDilation,
+    CreateEmulator,
+    AddPortForward,
+    EditLaunchConfiguration,
+    CommandPalette,
+    Help,
 }
 
-#[derive(Clone, PartialEq, Eq, Default)]
+impl PopUp {
+    /// Popups with a text input receive every key, including the ones bound to commands.
+    pub fn has_text_input(&self) -> bool {
+        matches!(
+            self,
+            PopUp::SearchDump
+                | PopUp::TimeDilation
+                | PopUp::CreateEmulator
+                | PopUp::AddPortForward
+                | PopUp::EditLaunchConfiguration
+                | PopUp::CommandPalette
+        )
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub enum DevTools {
     #[default]
     App,
+    AppSettings,
     Performance,
+    RebuildStats,
+    Dumps,
+    DumpDetails,
     Inspector,
     WidgetDetails,
     Network,
     NetworkRequest,
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Focus {
     Home(Home),
     DevTools(DevTools),
@@ -60,13 +90,101 @@ pub struct SdkVersion {
     pub flutter_root: String,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub enum LaunchRequest {
+    #[default]
+    Launch,
+    Attach,
+}
+
+/// Where a launch configuration is defined.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub enum LaunchConfigurationSource {
+    /// A profile in `flx.toml`.
+    Flx,
+    /// A configuration in `.vscode/launch.json`.
+    #[default]
+    VSCode,
+    /// A flavor found in the android or ios project which no other configuration covers.
+    Discovered,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LaunchConfiguration {
     pub name: String,
+    pub request: LaunchRequest,
     pub program: Option<String>,
     pub args: Option<Vec<String>>,
     pub cwd: Option<String>,
     pub flutter_mode: Option<String>,
+    pub vm_service_uri: Option<String>,
+    pub app_id: Option<String>,
+    pub tool_args: Option<Vec<String>>,
+    pub env: Option<HashMap<String, String>>,
+    /// Device to run on instead of the one selected in the device picker.
+    pub device_id: Option<String>,
+    /// Shell commands run before launching the app.
+    pub pre_launch: Option<Vec<String>>,
+    pub source: LaunchConfigurationSource,
+    /// Index of the configuration in `flx.toml` or `.vscode/launch.json`.
+    pub source_index: Option<usize>,
+}
+
+/// Values of a launch configuration being added or edited in the editor popup.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct LaunchConfigurationForm {
+    /// The file to save the configuration to.
+    pub source: LaunchConfigurationSource,
+    /// Index of the configuration in the file. `None` when adding a new configuration.
+    pub index: Option<usize>,
+    /// Index of the configuration in the file which is copied, with the values of the form
+    /// applied to the copy.
+    pub duplicate_of: Option<usize>,
+    pub name: String,
+    pub program: String,
+    pub mode: String,
+    /// Arguments passed to `flutter run`, separated by spaces. Arguments with spaces are quoted.
+    pub tool_args: String,
+    /// Arguments passed to the app, in the same format as `tool_args`. Only `.vscode/launch.json`
+    /// has them.
+    pub args: String,
+    pub cwd: String,
+}
+
+impl LaunchConfigurationForm {
+    pub fn join_args(args: &[String]) -> String {
+        args.iter()
+            .map(|arg| {
+                if arg.contains(char::is_whitespace) {
+                    format!("\"{}\"", arg)
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn split_args(args_text: &str) -> Vec<String> {
+        let mut args = vec![];
+        let mut current = String::new();
+        let mut quoted = false;
+        for c in args_text.chars() {
+            match c {
+                '"' => quoted = !quoted,
+                c if c.is_whitespace() && !quoted => {
+                    if !current.is_empty() {
+                        args.push(std::mem::take(&mut current));
+                    }
+                }
+                c => current.push(c),
+            }
+        }
+        if !current.is_empty() {
+            args.push(current);
+        }
+        args
+    }
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -81,6 +199,17 @@ pub enum SessionLog {
     Stderr(String),
 }
 
+/// Outcome of a hot reload or hot restart of a session.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReloadResult {
+    pub full_restart: bool,
+    pub success: bool,
+    pub message: String,
+    /// Milliseconds taken by the reload.
+    pub duration: u128,
+    pub finished_at: u128,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct FlutterFrame {
     pub build: Duration,
@@ -91,17 +220,79 @@ pub struct FlutterFrame {
     pub vsync_overhead: Duration,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum DumpKind {
+    #[default]
+    WidgetTree,
+    RenderTree,
+    LayerTree,
+    SemanticsTreeInTraversalOrder,
+    SemanticsTreeInInverseHitTestOrder,
+    FocusTree,
+}
+
+impl DumpKind {
+    pub const ALL: [DumpKind; 6] = [
+        DumpKind::WidgetTree,
+        DumpKind::RenderTree,
+        DumpKind::LayerTree,
+        DumpKind::SemanticsTreeInTraversalOrder,
+        DumpKind::SemanticsTreeInInverseHitTestOrder,
+        DumpKind::FocusTree,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            DumpKind::WidgetTree => "Widget tree",
+            DumpKind::RenderTree => "Render tree",
+            DumpKind::LayerTree => "Layer tree",
+            DumpKind::SemanticsTreeInTraversalOrder => "Semantics tree (traversal order)",
+            DumpKind::SemanticsTreeInInverseHitTestOrder => "Semantics tree (hit test order)",
+            DumpKind::FocusTree => "Focus tree",
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            DumpKind::WidgetTree => "widget_tree",
+            DumpKind::RenderTree => "render_tree",
+            DumpKind::LayerTree => "layer_tree",
+            DumpKind::SemanticsTreeInTraversalOrder => "semantics_tree_traversal_order",
+            DumpKind::SemanticsTreeInInverseHitTestOrder => "semantics_tree_hit_test_order",
+            DumpKind::FocusTree => "focus_tree",
+        }
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct RebuildCount {
+    pub last_frame: u64,
+    pub total: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WidgetScreenshot {
+    pub value_id: String,
+    pub png: Arc<Vec<u8>>,
+}
+
 #[derive(Default, Clone, PartialEq)]
 pub struct SessionState {
     pub id: String,
     pub app_id: Option<String>,
     pub device_id: Option<String>,
     pub configuration: Option<LaunchConfiguration>,
+    /// Sessions launched together on multiple devices share the same group id.
+    pub group_id: Option<String>,
+    pub vm_service_uri: Option<String>,
     pub started: bool,
     pub stopped: bool,
+    /// Why the app stopped, e.g. the build failed.
+    pub stop_error: Option<String>,
     pub mode: Option<AppMode>,
     pub hot_reloading: bool,
     pub hot_restarting: bool,
+    pub reloads: Vec<ReloadResult>,
     pub logs: Vec<SessionLog>,
     pub frames: Vec<FlutterFrame>,
     pub requests: Vec<HttpProfileRequestRef>,
@@ -116,6 +307,17 @@ pub struct SessionState {
     pub selected_widget_object_group: Option<String>,
     pub selected_widget_details_tree: Option<DiagnosticNode>,
     pub opened_widget_details_value_ids: HashSet<String>,
+    pub rebuild_locations: HashMap<i64, Location>,
+    pub rebuild_counts: HashMap<i64, RebuildCount>,
+    pub selected_rebuild_location_id: Option<i64>,
+    pub selected_dump_kind: DumpKind,
+    pub dumps: HashMap<DumpKind, String>,
+    pub saved_dump_path: Option<PathBuf>,
+    pub widget_screenshot: Option<WidgetScreenshot>,
+    pub saved_screenshot_path: Option<PathBuf>,
+    pub target_platform: Option<String>,
+    pub brightness: Option<String>,
+    pub time_dilation: Option<String>,
 
     pub debug_paint_enabled: bool,
     pub slow_animations_enabled: bool,
@@ -124,11 +326,15 @@ pub struct SessionState {
     pub invert_oversized_images_enabled: bool,
     pub show_performance_overlay_enabled: bool,
     pub show_widget_inspector_enabled: bool,
+    pub track_rebuild_widgets_enabled: bool,
+    pub track_repaint_widgets_enabled: bool,
 }
 
 #[derive(Default, Clone, PartialEq, Eq)]
 pub struct SelectDevicePopupState {
     pub selected_device_id: Option<String>,
+    /// Devices checked to run the app on at once. The selected device is used when empty.
+    pub checked_device_ids: Vec<String>,
 }
 
 #[derive(Default, Clone, PartialEq)]
@@ -136,10 +342,85 @@ pub struct SelectLaunchConfigurationPopupState {
     pub selected_index: Option<usize>,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PortForward {
+    pub device_id: String,
+    pub port: u32,
+    pub host_port: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NotificationLevel {
+    Info,
+    Error,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub message: String,
+    pub created_at: u128,
+}
+
+/// Sizes of the splits and whether the focused pane is zoomed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaneLayout {
+    /// Width of the left column of the home screen, in percent.
+    pub home_sidebar_width: u16,
+    /// Width of the left column of DevTools, in percent.
+    pub devtools_sidebar_width: u16,
+    /// The focused pane fills the screen.
+    pub zoomed: bool,
+}
+
+impl PaneLayout {
+    pub const MIN_SIDEBAR_WIDTH: u16 = 15;
+    pub const MAX_SIDEBAR_WIDTH: u16 = 75;
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        Self {
+            home_sidebar_width: 25,
+            devtools_sidebar_width: 25,
+            zoomed: false,
+        }
+    }
+}
+
+/// Debug toggles of the last session, which are turned on again in new sessions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugToggles {
+    pub debug_paint: bool,
+    pub debug_paint_baselines: bool,
+    pub invert_oversized_images: bool,
+    pub repaint_rainbow: bool,
+    pub show_performance_overlay: bool,
+    pub show_widget_inspector: bool,
+    pub track_rebuild_widgets: bool,
+    pub track_repaint_widgets: bool,
+}
+
+/// What is remembered about a project between runs of flx.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectState {
+    pub home: Home,
+    pub device_id: Option<String>,
+    /// Name of the launch configuration.
+    pub launch_configuration: Option<String>,
+    pub pane_layout: PaneLayout,
+    pub debug_toggles: DebugToggles,
+}
+
 #[derive(Default, Clone, PartialEq)]
 pub struct State {
     pub focus: Focus,
     pub popup: Option<PopUp>,
+    /// The popup which was open when the help was opened, to go back to when it is closed.
+    pub popup_under_help: Option<PopUp>,
 
     pub sdk_version: Option<SdkVersion>,
 
@@ -147,7 +428,12 @@ pub struct State {
     pub devices: Vec<Device>,
     pub emulators: Vec<Emulator>,
     pub selected_device_or_emulator_id: Option<String>,
+    pub port_forwards: Vec<PortForward>,
     pub launch_configurations: Vec<LaunchConfiguration>,
+    pub launch_configurations_loaded: bool,
+    /// Android flavors and iOS schemes of the project.
+    pub discovered_flavors: Vec<String>,
+    pub launch_configuration_form: Option<LaunchConfigurationForm>,
     pub supported_platforms: HashMap<PathBuf, Vec<String>>,
 
     pub sessions: Vec<SessionState>,
@@ -155,6 +441,21 @@ pub struct State {
 
     pub select_device_popup: SelectDevicePopupState,
     pub select_launch_configuration_poopup: SelectLaunchConfigurationPopupState,
+    pub dump_search_query: String,
+    pub widget_preview_enabled: bool,
+    /// Hot reload and hot restart apply to every session in the group of the current session.
+    pub broadcast_reload: bool,
+    pub hot_reload_on_save: bool,
+    pub pane_layout: PaneLayout,
+    /// The device and launch configuration the last app was run with, which are selected first
+    /// in the popups.
+    pub last_device_id: Option<String>,
+    pub last_launch_configuration: Option<String>,
+    pub debug_toggles: DebugToggles,
+    /// The project state is saved only after it has been restored, so that it is not overwritten
+    /// with the defaults on start.
+    pub project_state_restored: bool,
+    pub notifications: Vec<Notification>,
 }
 
 impl State {
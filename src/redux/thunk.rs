@@ -2,23 +2,45 @@ use std::sync::Arc;
 
 use redux_rs::{middlewares::thunk::Thunk, StoreApi};
 
-use self::context::Context;
+use self::{context::Context, run_new_app::RunTarget};
 
-use super::{action::Action, state::State};
+use super::{
+    action::Action,
+    state::{LaunchConfigurationForm, PortForward, ProjectState, State},
+};
 
+pub mod connect_vm_service;
 pub mod context;
+pub mod create_emulator;
+pub mod delete_launch_configuration;
+pub mod discover_flavors;
+pub mod edit_launch_configuration;
+pub mod forward_port;
 pub mod hot_reload;
 pub mod hot_restart;
 pub mod launch_emulator;
+pub mod load_app_settings;
 pub mod load_details_subtree;
+pub mod load_dump;
 pub mod load_emulators;
 pub mod load_full_request;
+pub mod load_launch_configurations;
 pub mod load_layout_explorer_node;
+pub mod load_project_state;
 pub mod load_root_widget_summary_tree;
 pub mod load_sdk_versions;
-pub mod load_vscode_launch_setting;
+pub mod load_service_extension_states;
+pub mod load_widget_screenshot;
+pub mod override_brightness;
+pub mod override_platform;
+pub mod override_time_dilation;
+pub mod restart_session;
 pub mod run_new_app;
 pub mod run_new_vm_service;
+pub mod save_dump;
+pub mod save_launch_configuration;
+pub mod save_project_state;
+pub mod save_widget_screenshot;
 pub mod stop_app;
 pub mod toggle_debug_paint;
 pub mod toggle_debug_paint_baselines;
@@ -26,23 +48,90 @@ pub mod toggle_invert_oversized_images;
 pub mod toggle_repaint_rainbow;
 pub mod toggle_show_performance_overlay;
 pub mod toggle_slow_animations;
+pub mod toggle_track_rebuild_widgets;
+pub mod toggle_track_repaint_widgets;
 pub mod toggle_widget_inspector;
+pub mod unforward_port;
 pub mod watch_devices;
+pub mod watch_files;
 pub mod watch_frames;
+pub mod watch_rebuilds;
 pub mod watch_requests;
+pub mod watch_service_extensions;
 
 #[derive(Debug)]
 pub enum ThunkAction {
     WatchDevices,
-    LoadSdkVersions { use_fvm: bool },
+    WatchFiles,
+    LoadSdkVersions {
+        use_fvm: bool,
+    },
     LoadEmulators,
     LoadFullRequest,
-    LoadVSCodeLaunchSetting,
-    LoadRootWidgetSummaryTree { session_id: String },
-    LoadLayoutExplorerNode { value_id: String },
-    LoadDetailsSubtree { value_id: String },
-    RunNewApp { use_fvm: bool },
-    LaunchEmulator,
+    LoadLaunchConfigurations,
+    DiscoverFlavors,
+    LoadProjectState,
+    SaveProjectState {
+        project_state: ProjectState,
+    },
+    EditLaunchConfiguration {
+        index: Option<usize>,
+        duplicate: bool,
+    },
+    SaveLaunchConfiguration {
+        form: LaunchConfigurationForm,
+    },
+    DeleteLaunchConfiguration {
+        index: usize,
+    },
+    LoadRootWidgetSummaryTree {
+        session_id: String,
+    },
+    LoadLayoutExplorerNode {
+        value_id: String,
+    },
+    LoadDetailsSubtree {
+        value_id: String,
+    },
+    LoadDump,
+    SaveDump,
+    LoadWidgetScreenshot {
+        value_id: String,
+    },
+    SaveWidgetScreenshot,
+    LoadAppSettings {
+        session_id: String,
+    },
+    OverridePlatform {
+        platform: String,
+    },
+    OverrideBrightness {
+        brightness: String,
+    },
+    OverrideTimeDilation {
+        factor: String,
+    },
+    RunNewApp {
+        use_fvm: bool,
+        target: Option<RunTarget>,
+    },
+    ConnectVmService {
+        uri: String,
+    },
+    LaunchEmulator {
+        cold_boot: bool,
+    },
+    CreateEmulator {
+        name: Option<String>,
+    },
+    ForwardPort {
+        device_id: String,
+        port: u32,
+        host_port: Option<u32>,
+    },
+    UnforwardPort {
+        forward: PortForward,
+    },
     HotReload,
     HotRestart,
     StopApp,
@@ -53,6 +142,8 @@ pub enum ThunkAction {
     ToggleShowPerformanceOverlay,
     ToggleSlowAnimations,
     ToggleWidgetInspector,
+    ToggleTrackRebuildWidgets,
+    ToggleTrackRepaintWidgets,
 }
 
 pub fn thunk_impl<Api>(
@@ -64,25 +155,62 @@ where
 {
     match action {
         ThunkAction::WatchDevices => Box::new(watch_devices::WatchDevicesThunk::new(context)),
+        ThunkAction::WatchFiles => Box::new(watch_files::WatchFilesThunk::new(context)),
         ThunkAction::LoadEmulators => Box::new(load_emulators::LoadEmulatorsThunk::new(context)),
         ThunkAction::LoadSdkVersions { use_fvm } => Box::new(
             load_sdk_versions::LoadSdkVersionsThunk::new(context, use_fvm),
         ),
-        ThunkAction::LoadVSCodeLaunchSetting => {
-            Box::new(load_vscode_launch_setting::LoadVSCodeLaunchSettingThunk::new(context))
+        ThunkAction::EditLaunchConfiguration { index, duplicate } => Box::new(
+            edit_launch_configuration::EditLaunchConfigurationThunk::new(context, index, duplicate),
+        ),
+        ThunkAction::SaveLaunchConfiguration { form } => {
+            Box::new(save_launch_configuration::SaveLaunchConfigurationThunk::new(context, form))
+        }
+        ThunkAction::DeleteLaunchConfiguration { index } => Box::new(
+            delete_launch_configuration::DeleteLaunchConfigurationThunk::new(context, index),
+        ),
+        ThunkAction::DiscoverFlavors => {
+            Box::new(discover_flavors::DiscoverFlavorsThunk::new(context))
+        }
+        ThunkAction::LoadProjectState => {
+            Box::new(load_project_state::LoadProjectStateThunk::new(context))
+        }
+        ThunkAction::SaveProjectState { project_state } => Box::new(
+            save_project_state::SaveProjectStateThunk::new(context, project_state),
+        ),
+        ThunkAction::LoadLaunchConfigurations => {
+            Box::new(load_launch_configurations::LoadLaunchConfigurationsThunk::new(context))
         }
         ThunkAction::LoadRootWidgetSummaryTree { session_id } => Box::new(
             load_root_widget_summary_tree::LoadRootWidgetWithSummaryTreeThunk::new(
                 context, session_id,
             ),
         ),
-        ThunkAction::RunNewApp { use_fvm } => {
-            Box::new(run_new_app::RunNewAppThunk::new(context, use_fvm))
+        ThunkAction::RunNewApp { use_fvm, target } => {
+            Box::new(run_new_app::RunNewAppThunk::new(context, use_fvm, target))
+        }
+        ThunkAction::ConnectVmService { uri } => {
+            Box::new(connect_vm_service::ConnectVmServiceThunk::new(context, uri))
         }
         ThunkAction::LoadFullRequest => {
             Box::new(load_full_request::LoadFullRequestThunk::new(context))
         }
-        ThunkAction::LaunchEmulator => Box::new(launch_emulator::LaunchEmulatorThunk::new(context)),
+        ThunkAction::LaunchEmulator { cold_boot } => Box::new(
+            launch_emulator::LaunchEmulatorThunk::new(context, cold_boot),
+        ),
+        ThunkAction::CreateEmulator { name } => {
+            Box::new(create_emulator::CreateEmulatorThunk::new(context, name))
+        }
+        ThunkAction::ForwardPort {
+            device_id,
+            port,
+            host_port,
+        } => Box::new(forward_port::ForwardPortThunk::new(
+            context, device_id, port, host_port,
+        )),
+        ThunkAction::UnforwardPort { forward } => {
+            Box::new(unforward_port::UnforwardPortThunk::new(context, forward))
+        }
         ThunkAction::HotReload => Box::new(hot_reload::HotReloadThunk::new(context)),
         ThunkAction::HotRestart => Box::new(hot_restart::HotRestartThunk::new(context)),
         ThunkAction::StopApp => Box::new(stop_app::StopAppThunk::new(context)),
@@ -92,6 +220,26 @@ where
         ThunkAction::LoadDetailsSubtree { value_id } => Box::new(
             load_details_subtree::LoadDetailsSubtreeThunk::new(context, value_id),
         ),
+        ThunkAction::LoadDump => Box::new(load_dump::LoadDumpThunk::new(context)),
+        ThunkAction::SaveDump => Box::new(save_dump::SaveDumpThunk::new(context)),
+        ThunkAction::LoadWidgetScreenshot { value_id } => Box::new(
+            load_widget_screenshot::LoadWidgetScreenshotThunk::new(context, value_id),
+        ),
+        ThunkAction::SaveWidgetScreenshot => Box::new(
+            save_widget_screenshot::SaveWidgetScreenshotThunk::new(context),
+        ),
+        ThunkAction::LoadAppSettings { session_id } => Box::new(
+            load_app_settings::LoadAppSettingsThunk::new(context, session_id),
+        ),
+        ThunkAction::OverridePlatform { platform } => Box::new(
+            override_platform::OverridePlatformThunk::new(context, platform),
+        ),
+        ThunkAction::OverrideBrightness { brightness } => Box::new(
+            override_brightness::OverrideBrightnessThunk::new(context, brightness),
+        ),
+        ThunkAction::OverrideTimeDilation { factor } => Box::new(
+            override_time_dilation::OverrideTimeDilationThunk::new(context, factor),
+        ),
         ThunkAction::ToggleDebugPaint => {
             Box::new(toggle_debug_paint::ToggleDebugPaintThunk::new(context))
         }
@@ -113,5 +261,11 @@ where
         ThunkAction::ToggleWidgetInspector => Box::new(
             toggle_widget_inspector::ToggleWidgetInspectorThunk::new(context),
         ),
+        ThunkAction::ToggleTrackRebuildWidgets => {
+            Box::new(toggle_track_rebuild_widgets::ToggleTrackRebuildWidgetsThunk::new(context))
+        }
+        ThunkAction::ToggleTrackRepaintWidgets => {
+            Box::new(toggle_track_repaint_widgets::ToggleTrackRepaintWidgetsThunk::new(context))
+        }
     }
 }
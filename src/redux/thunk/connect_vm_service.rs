@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use super::{context::Context, run_new_vm_service::RunNewVmServiceThunk};
+
+/// Connects to the vm service of an app which was started outside of flx, without going
+/// through `flutter attach`. Hot reload and hot restart are not available in this mode.
+pub struct ConnectVmServiceThunk {
+    context: Arc<Context>,
+    uri: String,
+}
+
+impl ConnectVmServiceThunk {
+    pub fn new(context: Arc<Context>, uri: String) -> Self {
+        Self { context, uri }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for ConnectVmServiceThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let id = self.context.manager.connect_vm_service().await;
+
+        store
+            .dispatch(Action::RegisterSession {
+                session_id: id.clone(),
+                device_id: None,
+                configuration: None,
+                group_id: None,
+            })
+            .await;
+        // Sets the uri once connected, and shows why if the connection failed.
+        RunNewVmServiceThunk::new(self.context.clone(), id.clone(), self.uri.clone(), false)
+            .execute(store.clone())
+            .await;
+
+        let Some(session) = self.context.manager.session(id.clone()).await else {
+            return;
+        };
+        if !session.vm_service.is_closed() {
+            store
+                .dispatch(Action::SetAppStarted {
+                    session_id: id.clone(),
+                })
+                .await;
+            session.vm_service.wait_closed().await;
+        }
+
+        store
+            .dispatch(Action::StopSession {
+                session_id: id.clone(),
+                error: None,
+            })
+            .await;
+        if let Err(e) = self.context.manager.remove_session(id).await {
+            log::error!("Failed to remove session: {:?}", e);
+        }
+    }
+}
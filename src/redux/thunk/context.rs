@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use tokio::sync::{Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
 
+use crate::redux::state::ProjectState;
 use crate::session::session_manager::{self, SessionManager};
 
 use daemon::flutter::FlutterDaemon;
@@ -9,6 +11,10 @@ use daemon::flutter::FlutterDaemon;
 pub struct Context {
     pub daemon: Arc<FlutterDaemon>,
     pub manager: Arc<SessionManager>,
+    /// Cancels the running `WatchFilesThunk`, so that there is at most one of it.
+    pub file_watcher: std::sync::Mutex<Option<CancellationToken>>,
+    /// The project state waiting to be written by `SaveProjectStateThunk`. Locked while writing.
+    pub pending_project_state: Mutex<Option<ProjectState>>,
 }
 
 impl Context {
@@ -16,6 +22,8 @@ impl Context {
         Self {
             daemon,
             manager: session_manager,
+            file_watcher: std::sync::Mutex::new(None),
+            pending_project_state: Mutex::new(None),
         }
     }
 }
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{NotificationLevel, State},
+};
+
+use super::{context::Context, load_emulators::LoadEmulatorsThunk};
+
+pub struct CreateEmulatorThunk {
+    context: Arc<Context>,
+    name: Option<String>,
+}
+
+impl CreateEmulatorThunk {
+    pub fn new(context: Arc<Context>, name: Option<String>) -> Self {
+        Self { context, name }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for CreateEmulatorThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        store
+            .dispatch(Action::ShowNotification {
+                level: NotificationLevel::Info,
+                message: "Creating emulator...".to_string(),
+            })
+            .await;
+
+        let name = match self.context.daemon.create_emulator(self.name.clone()).await {
+            Ok(name) => name,
+            Err(e) => {
+                log::error!("Failed to create emulator: {:?}", e);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Failed to create emulator: {}", e),
+                    })
+                    .await;
+                return;
+            }
+        };
+
+        store
+            .dispatch(Action::ShowNotification {
+                level: NotificationLevel::Info,
+                message: format!("Created emulator {}", name),
+            })
+            .await;
+
+        LoadEmulatorsThunk::new(self.context.clone())
+            .execute(store)
+            .await;
+    }
+}
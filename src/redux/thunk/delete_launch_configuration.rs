@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use color_eyre::eyre::{eyre, Result};
+use std::{path::Path, sync::Arc};
+use tokio::fs;
+
+use project::profile::remove_launch_profile;
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+use vscode::launch::remove_launch_configuration;
+
+use crate::redux::{
+    action::Action,
+    state::{LaunchConfiguration, LaunchConfigurationSource, NotificationLevel, State},
+};
+
+use super::{context::Context, load_launch_configurations::LoadLaunchConfigurationsThunk};
+
+/// Removes a configuration from `flx.toml` or `.vscode/launch.json`, and reloads the launch
+/// configurations.
+pub struct DeleteLaunchConfigurationThunk {
+    context: Arc<Context>,
+    /// Index of the configuration in the launch configurations.
+    index: usize,
+}
+
+impl DeleteLaunchConfigurationThunk {
+    pub fn new(context: Arc<Context>, index: usize) -> Self {
+        Self { context, index }
+    }
+
+    async fn delete(project_root: &Path, configuration: &LaunchConfiguration) -> Result<()> {
+        let Some(index) = configuration.source_index else {
+            return Err(eyre!("{} is not saved in a file", configuration.name));
+        };
+        match configuration.source {
+            LaunchConfigurationSource::Flx => {
+                let path = project_root.join("flx.toml");
+                let toml = fs::read_to_string(&path).await?;
+                fs::write(&path, remove_launch_profile(&toml, index)?).await?;
+            }
+            LaunchConfigurationSource::VSCode => {
+                let path = project_root.join(".vscode/launch.json");
+                let json = fs::read_to_string(&path).await?;
+                fs::write(&path, remove_launch_configuration(&json, index)?).await?;
+            }
+            LaunchConfigurationSource::Discovered => {
+                return Err(eyre!("{} is not saved in a file", configuration.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for DeleteLaunchConfigurationThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+        let index = self.index;
+        let Some(configuration) = store
+            .select(move |state: &State| state.launch_configurations.get(index).cloned())
+            .await
+        else {
+            return;
+        };
+
+        if let Err(e) = Self::delete(&project_root, &configuration).await {
+            log::error!("Failed to delete launch configuration: {:?}", e);
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: format!("Failed to delete launch configuration: {}", e),
+                })
+                .await;
+            return;
+        }
+
+        store
+            .dispatch(Action::ShowNotification {
+                level: NotificationLevel::Info,
+                message: format!("Deleted {}", configuration.name),
+            })
+            .await;
+        LoadLaunchConfigurationsThunk::new(self.context.clone())
+            .execute(store)
+            .await;
+    }
+}
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+use tokio::fs;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use super::context::Context;
+
+const CACHE_PATH: &str = ".dart_tool/flx/flavors.json";
+
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+struct CachedFlavors {
+    /// The latest modification time of the files the flavors are read from.
+    modified: u64,
+    flavors: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct FlavorsCache {
+    android: Option<CachedFlavors>,
+    ios: Option<CachedFlavors>,
+}
+
+#[derive(Clone, Copy)]
+enum Platform {
+    Android,
+    Ios,
+}
+
+impl Platform {
+    /// Files whose changes may add or remove flavors.
+    fn sources(&self, project_root: &Path) -> Vec<PathBuf> {
+        match self {
+            Platform::Android => vec![
+                project_root.join("android/app/build.gradle"),
+                project_root.join("android/app/build.gradle.kts"),
+            ],
+            Platform::Ios => vec![
+                project_root.join("ios/Runner.xcodeproj/project.pbxproj"),
+                project_root.join("ios/Runner.xcodeproj/xcshareddata/xcschemes"),
+            ],
+        }
+    }
+
+    fn is_available(&self, project_root: &Path) -> bool {
+        match self {
+            Platform::Android => project_root.join("android/gradlew").exists(),
+            Platform::Ios => {
+                cfg!(target_os = "macos") && project_root.join("ios/Runner.xcodeproj").exists()
+            }
+        }
+    }
+
+    fn get_flavors(&self, project_root: String) -> Result<Option<Vec<String>>> {
+        match self {
+            Platform::Android => android::get_schemes(project_root),
+            Platform::Ios => ios::get_schemes(project_root),
+        }
+    }
+}
+
+/// Finds the android flavors and iOS schemes of the project in the background, so that they can
+/// be launched without writing launch configurations. `gradlew` is slow, so the result is cached
+/// in `.dart_tool` until the build files change.
+pub struct DiscoverFlavorsThunk {
+    context: Arc<Context>,
+}
+
+impl DiscoverFlavorsThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+
+    async fn read_cache(path: &Path) -> FlavorsCache {
+        let Ok(json) = fs::read_to_string(path).await else {
+            return FlavorsCache::default();
+        };
+        serde_json::from_str(&json).unwrap_or_default()
+    }
+
+    async fn write_cache(path: &Path, cache: &FlavorsCache) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(path, serde_json::to_string(cache)?).await?;
+        Ok(())
+    }
+
+    fn modified(paths: &[PathBuf]) -> u64 {
+        paths
+            .iter()
+            .filter_map(|path| path.metadata().ok()?.modified().ok())
+            .filter_map(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .max()
+            .unwrap_or_default()
+    }
+
+    async fn discover(
+        project_root: &Path,
+        platform: Platform,
+        cached: Option<CachedFlavors>,
+    ) -> Option<CachedFlavors> {
+        if !platform.is_available(project_root) {
+            return None;
+        }
+        let modified = Self::modified(&platform.sources(project_root));
+        if let Some(cached) = cached {
+            if cached.modified == modified {
+                return Some(cached);
+            }
+        }
+
+        let root = project_root.to_string_lossy().to_string();
+        let flavors = match tokio::task::spawn_blocking(move || platform.get_flavors(root)).await {
+            Ok(Ok(flavors)) => flavors.unwrap_or_default(),
+            Ok(Err(e)) => {
+                log::error!("Failed to discover flavors: {:?}", e);
+                return None;
+            }
+            Err(e) => {
+                log::error!("Failed to discover flavors: {:?}", e);
+                return None;
+            }
+        };
+        Some(CachedFlavors { modified, flavors })
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for DiscoverFlavorsThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+        let cache_path = project_root.join(CACHE_PATH);
+        let cache = Self::read_cache(&cache_path).await;
+
+        let (android, ios) = tokio::join!(
+            Self::discover(&project_root, Platform::Android, cache.android.clone()),
+            Self::discover(&project_root, Platform::Ios, cache.ios.clone()),
+        );
+
+        let flavors = android
+            .iter()
+            .chain(ios.iter())
+            .flat_map(|cached| cached.flavors.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        store
+            .dispatch(Action::SetDiscoveredFlavors { flavors })
+            .await;
+
+        if android != cache.android || ios != cache.ios {
+            if let Err(e) = Self::write_cache(&cache_path, &FlavorsCache { android, ios }).await {
+                log::error!("Failed to write flavors cache: {:?}", e);
+            }
+        }
+    }
+}
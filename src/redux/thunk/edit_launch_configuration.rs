@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use color_eyre::eyre::{eyre, Result};
+use std::{path::Path, sync::Arc};
+use tokio::fs;
+
+use project::profile::parse_launch_profiles;
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+use vscode::launch::parse_launch_configuration;
+
+use crate::redux::{
+    action::Action,
+    state::{
+        LaunchConfiguration, LaunchConfigurationForm, LaunchConfigurationSource, NotificationLevel,
+        State,
+    },
+};
+
+use super::context::Context;
+
+/// Opens the launch configuration editor. The values are read from the file as they are written,
+/// before variables are substituted and templates are merged.
+pub struct EditLaunchConfigurationThunk {
+    context: Arc<Context>,
+    /// Index of the configuration in the launch configurations. `None` to add a new one.
+    index: Option<usize>,
+    /// Open the editor with a copy of the configuration instead of the configuration itself.
+    duplicate: bool,
+}
+
+impl EditLaunchConfigurationThunk {
+    pub fn new(context: Arc<Context>, index: Option<usize>, duplicate: bool) -> Self {
+        Self {
+            context,
+            index,
+            duplicate,
+        }
+    }
+
+    async fn form(
+        project_root: &Path,
+        configuration: Option<LaunchConfiguration>,
+    ) -> Result<LaunchConfigurationForm> {
+        let default_source = if project_root.join(".vscode/launch.json").exists() {
+            LaunchConfigurationSource::VSCode
+        } else {
+            LaunchConfigurationSource::Flx
+        };
+        let Some(configuration) = configuration else {
+            return Ok(LaunchConfigurationForm {
+                source: default_source,
+                ..LaunchConfigurationForm::default()
+            });
+        };
+
+        match (&configuration.source, configuration.source_index) {
+            (LaunchConfigurationSource::Flx, Some(index)) => {
+                let toml = fs::read_to_string(project_root.join("flx.toml")).await?;
+                let profile = parse_launch_profiles(&toml)?
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| eyre!("Launch profile {} not found", index))?;
+                Ok(LaunchConfigurationForm {
+                    source: LaunchConfigurationSource::Flx,
+                    index: Some(index),
+                    name: profile.name,
+                    program: profile.target.unwrap_or_default(),
+                    mode: profile.mode.unwrap_or_default(),
+                    tool_args: LaunchConfigurationForm::join_args(&profile.tool_args),
+                    cwd: profile.cwd.unwrap_or_default(),
+                    ..LaunchConfigurationForm::default()
+                })
+            }
+            (LaunchConfigurationSource::VSCode, Some(index)) => {
+                let json = fs::read_to_string(project_root.join(".vscode/launch.json")).await?;
+                let config = parse_launch_configuration(&json)?
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| eyre!("Launch configuration {} not found", index))?;
+                Ok(LaunchConfigurationForm {
+                    source: LaunchConfigurationSource::VSCode,
+                    index: Some(index),
+                    name: config.name,
+                    program: config.program.unwrap_or_default(),
+                    mode: config.flutter_mode.unwrap_or_default(),
+                    tool_args: LaunchConfigurationForm::join_args(
+                        &config.tool_args.unwrap_or_default(),
+                    ),
+                    args: LaunchConfigurationForm::join_args(&config.args.unwrap_or_default()),
+                    cwd: config.cwd.unwrap_or_default(),
+                    ..LaunchConfigurationForm::default()
+                })
+            }
+            // Discovered flavors are saved as new configurations.
+            _ => Ok(LaunchConfigurationForm {
+                source: default_source,
+                index: None,
+                name: configuration.name,
+                program: configuration.program.unwrap_or_default(),
+                mode: configuration.flutter_mode.unwrap_or_default(),
+                tool_args: LaunchConfigurationForm::join_args(
+                    &configuration.tool_args.unwrap_or_default(),
+                ),
+                args: LaunchConfigurationForm::join_args(&configuration.args.unwrap_or_default()),
+                cwd: configuration.cwd.unwrap_or_default(),
+                ..LaunchConfigurationForm::default()
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for EditLaunchConfigurationThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+        let index = self.index;
+        let configuration = store
+            .select(move |state: &State| {
+                index.and_then(|index| state.launch_configurations.get(index).cloned())
+            })
+            .await;
+
+        let mut form = match Self::form(&project_root, configuration).await {
+            Ok(form) => form,
+            Err(e) => {
+                log::error!("Failed to read launch configuration: {:?}", e);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Failed to read launch configuration: {}", e),
+                    })
+                    .await;
+                return;
+            }
+        };
+        if self.duplicate {
+            // The copy keeps the values which the editor doesn't show.
+            form.duplicate_of = form.index.take();
+            form.name = format!("{} (copy)", form.name);
+        }
+
+        store
+            .dispatch(Action::ShowLaunchConfigurationEditor { form })
+            .await;
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{NotificationLevel, PortForward, State},
+};
+
+use super::context::Context;
+
+pub struct ForwardPortThunk {
+    context: Arc<Context>,
+    device_id: String,
+    port: u32,
+    host_port: Option<u32>,
+}
+
+impl ForwardPortThunk {
+    pub fn new(
+        context: Arc<Context>,
+        device_id: String,
+        port: u32,
+        host_port: Option<u32>,
+    ) -> Self {
+        Self {
+            context,
+            device_id,
+            port,
+            host_port,
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for ForwardPortThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let host_port = match self
+            .context
+            .daemon
+            .forward_device(self.device_id.clone(), self.port, self.host_port)
+            .await
+        {
+            Ok(host_port) => host_port,
+            Err(e) => {
+                log::error!("Failed to forward port {}: {:?}", self.port, e);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Failed to forward port {}: {}", self.port, e),
+                    })
+                    .await;
+                return;
+            }
+        };
+
+        store
+            .dispatch(Action::AddPortForward {
+                forward: PortForward {
+                    device_id: self.device_id.clone(),
+                    port: self.port,
+                    host_port,
+                },
+            })
+            .await;
+    }
+}
@@ -1,14 +1,16 @@
 use async_trait::async_trait;
-use color_eyre::eyre::Result;
 use std::sync::Arc;
 
 use redux_rs::{middlewares::thunk::Thunk, StoreApi};
 
-use crate::redux::{action::Action, state::State};
+use crate::redux::{
+    action::Action, selector::reload_target_sessions::reload_target_session_ids_selector,
+    state::State,
+};
 
-use daemon::flutter::FlutterDaemon;
+use daemon::run::RestartReason;
 
-use super::context::Context;
+use super::{context::Context, restart_session::RestartSessionThunk};
 
 pub struct HotReloadThunk {
     context: Arc<Context>,
@@ -26,18 +28,23 @@ where
     Api: StoreApi<State, Action> + Send + Sync + 'static,
 {
     async fn execute(&self, store: Arc<Api>) {
-        let Some(session_id) = store.select(|state: &State| state.session_id.clone()).await else {
-            return;
-        };
-
-        let Some(session) = self.context.manager.session(session_id.clone()).await else {
-            return;
-        };
-        let run = &session.run;
-
-        if let Err(err) = run.hot_reload().await {
-            log::error!("Failed to hot reload: {}", err);
-            return;
+        let session_ids = store.select(reload_target_session_ids_selector).await;
+
+        let handles = session_ids
+            .into_iter()
+            .map(|session_id| {
+                let thunk = RestartSessionThunk::new(
+                    self.context.clone(),
+                    session_id,
+                    false,
+                    RestartReason::Manual,
+                );
+                let store = store.clone();
+                tokio::spawn(async move { thunk.execute(store).await })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            let _ = handle.await;
         }
     }
 }
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use color_eyre::eyre::Result;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use redux_rs::{
     middlewares::thunk::{self, Thunk},
@@ -10,7 +10,7 @@ use redux_rs::{
 use crate::redux::{
     action::Action,
     selector::device_or_emulators::{self, device_or_emulators_selector, DeviceOrEmulator},
-    state::State,
+    state::{NotificationLevel, State},
     thunk::{run_new_vm_service::RunNewVmServiceThunk, thunk_impl, ThunkAction},
 };
 
@@ -18,13 +18,17 @@ use daemon::flutter::FlutterDaemon;
 
 use super::context::Context;
 
+// Cold boots of a fresh AVD can take a few minutes.
+const DEVICE_TIMEOUT: Duration = Duration::from_secs(300);
+
 pub struct LaunchEmulatorThunk {
     context: Arc<Context>,
+    cold_boot: bool,
 }
 
 impl LaunchEmulatorThunk {
-    pub fn new(context: Arc<Context>) -> Self {
-        Self { context }
+    pub fn new(context: Arc<Context>, cold_boot: bool) -> Self {
+        Self { context, cold_boot }
     }
 }
 
@@ -42,25 +46,65 @@ where
         };
 
         let device_or_emulators = store.select(device_or_emulators_selector).await;
-        let is_emulator =
-            device_or_emulators
-                .iter()
-                .any(|device_or_emulator| match device_or_emulator {
-                    DeviceOrEmulator::Emulator(emulator) => emulator.id == selected_emulator_id,
-                    _ => false,
-                });
-
-        if !is_emulator {
+        let Some(emulator) = device_or_emulators
+            .into_iter()
+            .find_map(|device_or_emulator| match device_or_emulator {
+                DeviceOrEmulator::Emulator(emulator) if emulator.id == selected_emulator_id => {
+                    Some(emulator)
+                }
+                _ => None,
+            })
+        else {
             return;
-        }
+        };
+
+        store
+            .dispatch(Action::ShowNotification {
+                level: NotificationLevel::Info,
+                message: if self.cold_boot {
+                    format!("Cold booting {}...", emulator.name)
+                } else {
+                    format!("Launching {}...", emulator.name)
+                },
+            })
+            .await;
 
         if let Err(e) = self
             .context
             .daemon
-            .launch_emulator(selected_emulator_id, false)
+            .launch_emulator(emulator.id.clone(), self.cold_boot)
             .await
         {
             log::error!("Failed to launch emulator: {:?}", e);
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: format!("Failed to launch {}: {}", emulator.name, e),
+                })
+                .await;
+            return;
         };
+
+        // The device is added to the list by `WatchDevicesThunk`. Wait for it here only to
+        // tell the user when the emulator is ready to run apps.
+        let wait_for_device = async {
+            loop {
+                match self.context.daemon.receive_device_added().await {
+                    Ok(device) if device.emulator_id.as_ref() == Some(&emulator.id) => {
+                        return Some(device);
+                    }
+                    Ok(_) => {}
+                    Err(_) => return None,
+                }
+            }
+        };
+        if let Ok(Some(device)) = tokio::time::timeout(DEVICE_TIMEOUT, wait_for_device).await {
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Info,
+                    message: format!("{} is ready", device.name),
+                })
+                .await;
+        }
     }
 }
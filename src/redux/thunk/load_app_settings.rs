@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use devtools::protocols::{
+    flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol,
+};
+
+use super::context::Context;
+
+pub struct LoadAppSettingsThunk {
+    context: Arc<Context>,
+    session_id: String,
+}
+
+impl LoadAppSettingsThunk {
+    pub fn new(context: Arc<Context>, session_id: String) -> Self {
+        Self {
+            context,
+            session_id,
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadAppSettingsThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(session) = self.context.manager.session(self.session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        let Ok(vm) = vm_service.get_vm().await else {
+            return;
+        };
+        let Some(main_isolate) = vm.isolates.iter().find(|isolate| isolate.name == "main") else {
+            return;
+        };
+
+        match vm_service.platform_override(&main_isolate.id, None).await {
+            Ok(platform) => {
+                store
+                    .dispatch(Action::SetTargetPlatform {
+                        session_id: self.session_id.clone(),
+                        platform: Some(platform.value),
+                    })
+                    .await;
+            }
+            Err(err) => log::error!("Failed to load platform override: {:?}", err),
+        }
+
+        match vm_service.brightness_override(&main_isolate.id, None).await {
+            Ok(brightness) => {
+                store
+                    .dispatch(Action::SetBrightness {
+                        session_id: self.session_id.clone(),
+                        brightness: Some(brightness.value),
+                    })
+                    .await;
+            }
+            Err(err) => log::error!("Failed to load brightness override: {:?}", err),
+        }
+
+        match vm_service.time_dilation(&main_isolate.id, None).await {
+            Ok(dilation) => {
+                store
+                    .dispatch(Action::SetTimeDilation {
+                        session_id: self.session_id.clone(),
+                        time_dilation: Some(dilation.time_dilation),
+                    })
+                    .await;
+            }
+            Err(err) => log::error!("Failed to load time dilation: {:?}", err),
+        }
+    }
+}
@@ -26,7 +26,7 @@ use devtools::{
 
 use daemon::flutter::FlutterDaemon;
 
-use super::context::Context;
+use super::{context::Context, load_widget_screenshot::LoadWidgetScreenshotThunk};
 
 pub struct LoadDetailsSubtreeThunk {
     context: Arc<Context>,
@@ -73,6 +73,20 @@ where
             return;
         };
 
+        if store
+            .select(|state: &State| state.widget_preview_enabled)
+            .await
+        {
+            let _store = store.clone();
+            let context = self.context.clone();
+            let value_id = self.value_id.clone();
+            tokio::spawn(async move {
+                LoadWidgetScreenshotThunk::new(context, value_id)
+                    .execute(_store)
+                    .await;
+            });
+        }
+
         let next_object_group = format!("subtree-{}-{}", session_id, self.value_id);
 
         store
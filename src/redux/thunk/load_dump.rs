@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    selector::current_session::current_session_selector_cloned,
+    state::{DumpKind, SessionState, State},
+};
+
+use devtools::{
+    protocols::{flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol},
+    vm_service::VmService,
+};
+
+use super::context::Context;
+
+pub struct LoadDumpThunk {
+    context: Arc<Context>,
+}
+
+impl LoadDumpThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadDumpThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(SessionState {
+            id: session_id,
+            selected_dump_kind,
+            ..
+        }) = store.select(current_session_selector_cloned).await
+        else {
+            return;
+        };
+
+        let Some(session) = self.context.manager.session(session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        let Ok(vm) = vm_service.get_vm().await else {
+            return;
+        };
+        let Some(main_isolate) = vm.isolates.iter().find(|isolate| isolate.name == "main") else {
+            return;
+        };
+        let isolate_id = &main_isolate.id;
+
+        let dump = match selected_dump_kind {
+            DumpKind::WidgetTree => vm_service.debug_dump_app(isolate_id).await,
+            DumpKind::RenderTree => vm_service.debug_dump_render_tree(isolate_id).await,
+            DumpKind::LayerTree => vm_service.debug_dump_layer_tree(isolate_id).await,
+            DumpKind::SemanticsTreeInTraversalOrder => {
+                vm_service
+                    .debug_dump_semantics_tree_in_traversal_order(isolate_id)
+                    .await
+            }
+            DumpKind::SemanticsTreeInInverseHitTestOrder => {
+                vm_service
+                    .debug_dump_semantics_tree_in_inverse_hit_test_order(isolate_id)
+                    .await
+            }
+            DumpKind::FocusTree => vm_service.debug_dump_focus_tree(isolate_id).await,
+        };
+
+        match dump {
+            Ok(dump) => {
+                store
+                    .dispatch(Action::SetDump {
+                        session_id,
+                        kind: selected_dump_kind,
+                        dump: dump.data,
+                    })
+                    .await;
+            }
+            Err(err) => {
+                log::error!("Failed to dump {:?}: {:?}", selected_dump_kind, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use project::profile::parse_launch_profiles;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
+use tokio::fs;
+use vscode::launch::{
+    parse_launch_configuration, resolve_launch_configurations, VSCodeLaunchConfigurationRequest,
+};
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{
+        LaunchConfiguration, LaunchConfigurationSource, LaunchRequest, NotificationLevel, State,
+    },
+};
+
+use vscode;
+
+use daemon::flutter::FlutterDaemon;
+
+use super::context::Context;
+
+/// Loads the launch profiles in `flx.toml` followed by the configurations in
+/// `.vscode/launch.json`.
+pub struct LoadLaunchConfigurationsThunk {
+    context: Arc<Context>,
+}
+
+impl LoadLaunchConfigurationsThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+
+    async fn load_profiles(flx_toml_path: &Path) -> Result<Vec<LaunchConfiguration>> {
+        if !flx_toml_path.exists() {
+            return Ok(vec![]);
+        }
+        let toml = fs::read_to_string(flx_toml_path).await?;
+        let profiles = parse_launch_profiles(&toml)?;
+
+        Ok(profiles
+            .iter()
+            .enumerate()
+            .map(|(index, profile)| LaunchConfiguration {
+                name: profile.name.clone(),
+                request: LaunchRequest::Launch,
+                program: profile.target.clone(),
+                args: None,
+                cwd: profile.cwd.clone(),
+                flutter_mode: profile.mode.clone(),
+                vm_service_uri: None,
+                app_id: None,
+                tool_args: Some(profile.flutter_tool_args()),
+                env: profile.env.clone(),
+                device_id: profile.device.clone(),
+                pre_launch: Some(profile.pre_launch.clone()),
+                source: LaunchConfigurationSource::Flx,
+                source_index: Some(index),
+            })
+            .collect())
+    }
+
+    async fn load_vscode_configurations(
+        &self,
+        project_root: &Path,
+        launch_json_path: &Path,
+    ) -> Vec<LaunchConfiguration> {
+        if !launch_json_path.exists() {
+            return vec![];
+        }
+        let Ok(json) = fs::read_to_string(launch_json_path).await else {
+            return vec![];
+        };
+
+        let Ok(configurations) = parse_launch_configuration(&json) else {
+            return vec![];
+        };
+
+        // Templates are dropped by resolving, so keep the indices of the others in the file.
+        let indices = configurations
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| config.template_for.is_none())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let configurations = resolve_launch_configurations(configurations, project_root)
+            .iter()
+            .zip(indices)
+            .map(|(config, index)| LaunchConfiguration {
+                name: config.name.clone(),
+                request: match config.request {
+                    VSCodeLaunchConfigurationRequest::Launch => LaunchRequest::Launch,
+                    VSCodeLaunchConfigurationRequest::Attach => LaunchRequest::Attach,
+                },
+                program: config.program.clone(),
+                args: config.args.clone(),
+                cwd: config.cwd.clone(),
+                flutter_mode: config.flutter_mode.clone(),
+                vm_service_uri: config.vm_service_uri.clone(),
+                app_id: config.app_id.clone(),
+                tool_args: config.tool_args.clone(),
+                env: config.env.clone(),
+                device_id: config.device_id.clone(),
+                pre_launch: None,
+                source: LaunchConfigurationSource::VSCode,
+                source_index: Some(index),
+            })
+            .collect::<Vec<_>>();
+
+        configurations
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadLaunchConfigurationsThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+
+        let profiles = match Self::load_profiles(&project_root.join("flx.toml")).await {
+            Ok(profiles) => profiles,
+            Err(e) => {
+                log::error!("Failed to load flx.toml: {:?}", e);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Failed to load flx.toml: {}", e),
+                    })
+                    .await;
+                vec![]
+            }
+        };
+        let launch_json_path = project_root.join(".vscode/launch.json");
+        let configurations = [
+            profiles,
+            self.load_vscode_configurations(&project_root, &launch_json_path)
+                .await,
+        ]
+        .concat();
+
+        let mut dirs = HashSet::new();
+        dirs.insert(project_root.clone());
+
+        for configuration in &configurations {
+            if let Some(cwd) = &configuration.cwd {
+                let cwd = project_root.clone().join(cwd);
+                dirs.insert(cwd);
+            }
+        }
+
+        store
+            .dispatch(Action::SetLaunchConfigurations { configurations })
+            .await;
+
+        let mut supported_platforms = HashMap::new();
+        for dir in dirs {
+            let Ok(dir_string) = dir.clone().into_os_string().into_string() else {
+                continue;
+            };
+
+            if let Ok(supprted_platforms) = self
+                .context
+                .daemon
+                .get_supported_platforms(dir_string.clone())
+                .await
+            {
+                supported_platforms.insert(dir, supprted_platforms);
+            };
+        }
+
+        store
+            .dispatch(Action::SetSupportedPlatforms {
+                supported_platforms,
+            })
+            .await;
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{ProjectState, State},
+};
+
+use crate::utils::get_project_state_path;
+
+use super::context::Context;
+
+/// Restores the selections, layout and debug toggles of the last time flx was opened in the
+/// project.
+pub struct LoadProjectStateThunk {
+    context: Arc<Context>,
+}
+
+impl LoadProjectStateThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadProjectStateThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+        let project_state =
+            match tokio::fs::read_to_string(get_project_state_path(&project_root)).await {
+                Ok(json) => serde_json::from_str::<ProjectState>(&json).unwrap_or_else(|e| {
+                    log::error!("Failed to read project state: {:?}", e);
+                    ProjectState::default()
+                }),
+                Err(_) => ProjectState::default(),
+            };
+        store
+            .dispatch(Action::RestoreProjectState { project_state })
+            .await;
+    }
+}
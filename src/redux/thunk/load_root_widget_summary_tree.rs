@@ -66,7 +66,6 @@ where
             return;
         };
         let vm_service = &session.vm_service;
-        let run = &session.run;
 
         let Ok(vm) = vm_service.get_vm().await else {
             return;
@@ -125,6 +124,9 @@ where
             .await;
 
         // Reload the root widget tree when hot restart is finished
+        let Some(run) = &session.run else {
+            return;
+        };
         while let Ok(params) = run.receive_app_progress().await {
             if params.progress_id == Some("hot.restart".to_string()) && params.finished {
                 LoadRootWidgetWithSummaryTreeThunk::new(
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use devtools::protocols::{
+    flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol,
+};
+
+use super::{context::Context, load_app_settings::LoadAppSettingsThunk};
+
+/// Maps the current value of a service extension to the action which stores it in the session.
+pub fn service_extension_state_action(
+    session_id: &str,
+    extension: &str,
+    value: &str,
+) -> Option<Action> {
+    let session_id = session_id.to_string();
+    let enabled = value == "true";
+    let action = match extension {
+        "ext.flutter.debugPaint" => Action::SetDebugPaintEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.debugPaintBaselinesEnabled" => Action::SetDebugPaintBaselinesEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.invertOversizedImages" => Action::SetInvertOversizedImagesEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.repaintRainbow" => Action::SetRepaintRainbowEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.showPerformanceOverlay" => Action::SetShowPerformanceOverlayEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.inspector.show" => Action::SetShowWidgetInspectorEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.inspector.trackRebuildDirtyWidgets" => Action::SetTrackRebuildWidgetsEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.inspector.trackRepaintWidgets" => Action::SetTrackRepaintWidgetsEnabled {
+            session_id,
+            enabled,
+        },
+        "ext.flutter.timeDilation" => Action::SetTimeDilation {
+            session_id,
+            time_dilation: Some(value.to_string()),
+        },
+        "ext.flutter.platformOverride" => Action::SetTargetPlatform {
+            session_id,
+            platform: Some(value.to_string()),
+        },
+        "ext.flutter.brightnessOverride" => Action::SetBrightness {
+            session_id,
+            brightness: Some(value.to_string()),
+        },
+        _ => return None,
+    };
+    Some(action)
+}
+
+/// Reads the current value of every service extension flx shows, so the UI reflects what the
+/// app is actually doing rather than what was last toggled from here.
+pub struct LoadServiceExtensionStatesThunk {
+    context: Arc<Context>,
+    session_id: String,
+    /// Turn on the debug toggles which were on in the last session before reading them. Only
+    /// done once for a session which flx launched, so that the toggles turned off in the app
+    /// stay off.
+    restore_debug_toggles: bool,
+}
+
+impl LoadServiceExtensionStatesThunk {
+    pub fn new(context: Arc<Context>, session_id: String, restore_debug_toggles: bool) -> Self {
+        Self {
+            context,
+            session_id,
+            restore_debug_toggles,
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadServiceExtensionStatesThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(session) = self.context.manager.session(self.session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        let Ok(vm) = vm_service.get_vm().await else {
+            return;
+        };
+        let Some(main_isolate) = vm.isolates.iter().find(|isolate| isolate.name == "main") else {
+            return;
+        };
+        let isolate_id = &main_isolate.id;
+        let toggles = store.select(|state: &State| state.debug_toggles).await;
+        // `None` only reads the value.
+        let restore = |enabled: bool| (self.restore_debug_toggles && enabled).then_some(true);
+
+        let togglables = [
+            (
+                "ext.flutter.debugPaint",
+                vm_service
+                    .debug_paint(isolate_id, restore(toggles.debug_paint))
+                    .await,
+            ),
+            (
+                "ext.flutter.debugPaintBaselinesEnabled",
+                vm_service
+                    .debug_paint_baseline_enabled(
+                        isolate_id,
+                        restore(toggles.debug_paint_baselines),
+                    )
+                    .await,
+            ),
+            (
+                "ext.flutter.invertOversizedImages",
+                vm_service
+                    .invert_oversized_image(isolate_id, restore(toggles.invert_oversized_images))
+                    .await,
+            ),
+            (
+                "ext.flutter.repaintRainbow",
+                vm_service
+                    .repaint_rainbow(isolate_id, restore(toggles.repaint_rainbow))
+                    .await,
+            ),
+            (
+                "ext.flutter.showPerformanceOverlay",
+                vm_service
+                    .show_performance_overlay(isolate_id, restore(toggles.show_performance_overlay))
+                    .await,
+            ),
+            (
+                "ext.flutter.inspector.show",
+                vm_service
+                    .show(isolate_id, restore(toggles.show_widget_inspector))
+                    .await,
+            ),
+            (
+                "ext.flutter.inspector.trackRebuildDirtyWidgets",
+                vm_service
+                    .track_rebuild_dirty_widgets(isolate_id, restore(toggles.track_rebuild_widgets))
+                    .await,
+            ),
+            (
+                "ext.flutter.inspector.trackRepaintWidgets",
+                vm_service
+                    .track_repaint_widgets(isolate_id, restore(toggles.track_repaint_widgets))
+                    .await,
+            ),
+        ];
+
+        for (extension, result) in togglables {
+            let togglable = match result {
+                Ok(togglable) => togglable,
+                Err(err) => {
+                    log::error!("Failed to load state of {}: {:?}", extension, err);
+                    continue;
+                }
+            };
+            if let Some(action) = service_extension_state_action(
+                &self.session_id,
+                extension,
+                &togglable.enabled.to_string(),
+            ) {
+                store.dispatch(action).await;
+            }
+        }
+
+        LoadAppSettingsThunk::new(self.context.clone(), self.session_id.clone())
+            .execute(store)
+            .await;
+    }
+}
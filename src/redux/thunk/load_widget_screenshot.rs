@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    selector::current_session::current_session_selector_cloned,
+    state::{SessionState, State, WidgetScreenshot},
+};
+
+use devtools::protocols::{
+    flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol,
+};
+
+use super::context::Context;
+
+const SCREENSHOT_WIDTH: f64 = 1024.0;
+const SCREENSHOT_HEIGHT: f64 = 1024.0;
+
+pub struct LoadWidgetScreenshotThunk {
+    context: Arc<Context>,
+    value_id: String,
+}
+
+impl LoadWidgetScreenshotThunk {
+    pub fn new(context: Arc<Context>, value_id: String) -> Self {
+        Self { context, value_id }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for LoadWidgetScreenshotThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(SessionState { id: session_id, .. }) =
+            store.select(current_session_selector_cloned).await
+        else {
+            return;
+        };
+
+        store
+            .dispatch(Action::SetWidgetScreenshot {
+                session_id: session_id.clone(),
+                screenshot: None,
+            })
+            .await;
+
+        let Some(session) = self.context.manager.session(session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        let Ok(vm) = vm_service.get_vm().await else {
+            return;
+        };
+        let Some(main_isolate) = vm.isolates.iter().find(|isolate| isolate.name == "main") else {
+            return;
+        };
+
+        let response = match vm_service
+            .screenshot(
+                &main_isolate.id,
+                &self.value_id,
+                SCREENSHOT_WIDTH,
+                SCREENSHOT_HEIGHT,
+                Some(0.0),
+                Some(1.0),
+                Some(false),
+            )
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                log::error!("Failed to take widget screenshot: {:?}", err);
+                return;
+            }
+        };
+        let Some(data) = response.result else {
+            return;
+        };
+        let png = match STANDARD.decode(data) {
+            Ok(png) => png,
+            Err(err) => {
+                log::error!("Failed to decode widget screenshot: {:?}", err);
+                return;
+            }
+        };
+
+        store
+            .dispatch(Action::SetWidgetScreenshot {
+                session_id,
+                screenshot: Some(WidgetScreenshot {
+                    value_id: self.value_id.clone(),
+                    png: Arc::new(png),
+                }),
+            })
+            .await;
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    selector::current_session::current_session_selector_cloned,
+    state::{SessionState, State},
+};
+
+use devtools::protocols::{
+    flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol,
+};
+
+use super::context::Context;
+
+pub struct OverrideBrightnessThunk {
+    context: Arc<Context>,
+    brightness: String,
+}
+
+impl OverrideBrightnessThunk {
+    pub fn new(context: Arc<Context>, brightness: String) -> Self {
+        Self {
+            context,
+            brightness,
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for OverrideBrightnessThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(SessionState { id: session_id, .. }) =
+            store.select(current_session_selector_cloned).await
+        else {
+            return;
+        };
+
+        let Some(session) = self.context.manager.session(session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        let Ok(vm) = vm_service.get_vm().await else {
+            return;
+        };
+        let Some(main_isolate) = vm.isolates.iter().find(|isolate| isolate.name == "main") else {
+            return;
+        };
+
+        match vm_service
+            .brightness_override(&main_isolate.id, Some(&self.brightness))
+            .await
+        {
+            Ok(response) => {
+                store
+                    .dispatch(Action::SetBrightness {
+                        session_id,
+                        brightness: Some(response.value),
+                    })
+                    .await;
+            }
+            Err(err) => log::error!("Failed to override brightness: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use color_eyre::eyre::{eyre, Result};
+use std::{
+    sync::Arc,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::{
+    redux::{
+        action::Action,
+        state::{NotificationLevel, ReloadResult, State},
+    },
+    session::session_manager::Session,
+};
+
+use daemon::run::RestartReason;
+use devtools::protocols::{
+    flutter_extension::FlutterExtensionProtocol, vm_service::VmServiceProtocol,
+};
+
+use super::context::Context;
+
+/// Hot reloads or hot restarts a single session, and reports the outcome with its duration as
+/// a notification, a log line and an entry of the session's reload history.
+pub struct RestartSessionThunk {
+    context: Arc<Context>,
+    session_id: String,
+    full_restart: bool,
+    reason: RestartReason,
+}
+
+impl RestartSessionThunk {
+    pub fn new(
+        context: Arc<Context>,
+        session_id: String,
+        full_restart: bool,
+        reason: RestartReason,
+    ) -> Self {
+        Self {
+            context,
+            session_id,
+            full_restart,
+            reason,
+        }
+    }
+
+    /// Returns whether the reload succeeded and the message to show for it.
+    async fn restart(&self, session: &Session) -> Result<(bool, String)> {
+        let Some(run) = &session.run else {
+            if self.full_restart {
+                return Err(eyre!(
+                    "Hot restart is not supported for apps connected to the vm service directly"
+                ));
+            }
+            return Self::reload_sources(session).await;
+        };
+
+        let result = if self.full_restart {
+            run.hot_restart(self.reason).await?
+        } else {
+            run.hot_reload(self.reason).await?
+        };
+        let mut message = if !result.message.is_empty() {
+            result.message.clone()
+        } else if result.is_success() {
+            if self.full_restart {
+                "Restarted".to_string()
+            } else {
+                "Reloaded".to_string()
+            }
+        } else {
+            "Failed".to_string()
+        };
+        if let Some(hint) = &result.hint_message {
+            message = format!("{} {}", message, hint);
+        }
+        Ok((result.is_success(), message))
+    }
+
+    /// Reloads an app which is connected to the vm service directly, without `flutter run`.
+    async fn reload_sources(session: &Session) -> Result<(bool, String)> {
+        let vm_service = &session.vm_service;
+        let vm = vm_service.get_vm().await?;
+        let main_isolate = vm
+            .isolates
+            .iter()
+            .find(|isolate| isolate.name == "main")
+            .ok_or_else(|| eyre!("main isolate not found"))?;
+
+        let report = vm_service
+            .reload_sources(&main_isolate.id, None, None, None, None)
+            .await?;
+        if !report.success {
+            let reasons = report
+                .notices
+                .iter()
+                .filter_map(|notice| notice.message.clone())
+                .collect::<Vec<_>>();
+            let message = if reasons.is_empty() {
+                "Reload rejected".to_string()
+            } else {
+                format!("Reload rejected: {}", reasons.join(", "))
+            };
+            return Ok((false, message));
+        }
+
+        vm_service.reassemble(&main_isolate.id).await?;
+        Ok((true, "Reloaded".to_string()))
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for RestartSessionThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(session) = self.context.manager.session(self.session_id.clone()).await else {
+            return;
+        };
+
+        let kind = if self.full_restart {
+            "Hot restart"
+        } else {
+            "Hot reload"
+        };
+        let started_at = Instant::now();
+        let (success, message) = match self.restart(&session).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("Failed to {}: {:?}", kind.to_lowercase(), e);
+                (false, e.to_string())
+            }
+        };
+        let duration = started_at.elapsed().as_millis();
+
+        let line = format!("{}: {} ({}ms)", kind, message, duration);
+        store
+            .dispatch(if success {
+                Action::AppendStdoutLog {
+                    session_id: self.session_id.clone(),
+                    line: line.clone(),
+                }
+            } else {
+                Action::AppendStderrLog {
+                    session_id: self.session_id.clone(),
+                    line: line.clone(),
+                }
+            })
+            .await;
+        store
+            .dispatch(Action::ShowNotification {
+                level: if success {
+                    NotificationLevel::Info
+                } else {
+                    NotificationLevel::Error
+                },
+                message: line,
+            })
+            .await;
+        store
+            .dispatch(Action::AppendReloadResult {
+                session_id: self.session_id.clone(),
+                result: ReloadResult {
+                    full_restart: self.full_restart,
+                    success,
+                    message,
+                    duration,
+                    finished_at: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis(),
+                },
+            })
+            .await;
+    }
+}
@@ -1,6 +1,11 @@
 use async_trait::async_trait;
-use color_eyre::eyre::Result;
-use std::sync::Arc;
+use color_eyre::eyre::{eyre, Result};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::process::Command;
+use uuid::Uuid;
 
 use redux_rs::{
     middlewares::thunk::{self, Thunk},
@@ -9,7 +14,7 @@ use redux_rs::{
 
 use crate::redux::{
     action::Action,
-    state::State,
+    state::{LaunchConfiguration, LaunchRequest, NotificationLevel, State},
     thunk::{run_new_vm_service::RunNewVmServiceThunk, thunk_impl, ThunkAction},
 };
 
@@ -17,14 +22,28 @@ use daemon::flutter::FlutterDaemon;
 
 use super::context::Context;
 
+/// What to run when it is not picked in the popups.
+#[derive(Clone, Debug)]
+pub struct RunTarget {
+    pub configuration: Option<LaunchConfiguration>,
+    /// Overrides the device of the configuration.
+    pub device_id: Option<String>,
+}
+
+#[derive(Clone)]
 pub struct RunNewAppThunk {
     context: Arc<Context>,
     use_fvm: bool,
+    target: Option<RunTarget>,
 }
 
 impl RunNewAppThunk {
-    pub fn new(context: Arc<Context>, use_fvm: bool) -> Self {
-        Self { context, use_fvm }
+    pub fn new(context: Arc<Context>, use_fvm: bool, target: Option<RunTarget>) -> Self {
+        Self {
+            context,
+            use_fvm,
+            target,
+        }
     }
 }
 
@@ -37,38 +56,187 @@ where
         let project_root = store
             .select(|state: &State| state.project_root.clone())
             .await;
-        let device_id = store
-            .select(|state: &State| state.select_device_popup.selected_device_id.clone())
+        let configuration = match &self.target {
+            Some(target) => target.configuration.clone(),
+            None => {
+                store
+                    .select(|state: &State| {
+                        let index = state.select_launch_configuration_poopup.selected_index?;
+                        if index >= state.launch_configurations.len() {
+                            return None;
+                        }
+                        state.launch_configurations.get(index).cloned()
+                    })
+                    .await
+            }
+        };
+
+        log::info!("configuration: {:?}", configuration);
+
+        if let Some(configuration) = &configuration {
+            if let Err(e) = self
+                .run_pre_launch_commands(&store, &project_root, configuration)
+                .await
+            {
+                log::error!("Failed to run pre-launch command: {:?}", e);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Pre-launch command failed: {}", e),
+                    })
+                    .await;
+                return;
+            }
+        }
+
+        // The device of the configuration is used unless devices are checked explicitly.
+        let popup = store
+            .select(|state: &State| state.select_device_popup.clone())
             .await;
-        log::info!("device_id: {:?}", device_id);
+        let configured_device_id = configuration.as_ref().and_then(|c| c.device_id.clone());
+        let device_ids = if let Some(target) = &self.target {
+            vec![target.device_id.clone().or(configured_device_id)]
+        } else if !popup.checked_device_ids.is_empty() {
+            popup.checked_device_ids.into_iter().map(Some).collect()
+        } else if configured_device_id.is_some() {
+            vec![configured_device_id]
+        } else {
+            vec![popup.selected_device_id]
+        };
+        log::info!("device_ids: {:?}", device_ids);
 
-        let configuration = store
-            .select(|state: &State| {
-                let index = state.select_launch_configuration_poopup.selected_index?;
-                if index >= state.launch_configurations.len() {
-                    return None;
-                }
-                state.launch_configurations.get(index).cloned()
+        let group_id = if device_ids.len() > 1 {
+            Some(Uuid::new_v4().to_string())
+        } else {
+            None
+        };
+
+        let handles = device_ids
+            .into_iter()
+            .map(|device_id| {
+                let thunk = self.clone();
+                let store = store.clone();
+                let project_root = project_root.clone();
+                let configuration = configuration.clone();
+                let group_id = group_id.clone();
+                tokio::spawn(async move {
+                    thunk
+                        .run(store, project_root, device_id, configuration, group_id)
+                        .await;
+                })
             })
-            .await;
+            .collect::<Vec<_>>();
+        for handle in handles {
+            if let Err(e) = handle.await {
+                log::error!("Failed to run app: {:?}", e);
+            }
+        }
+    }
+}
 
-        log::info!("configuration: {:?}", configuration);
+impl RunNewAppThunk {
+    /// Runs the pre-launch commands of the configuration one by one in its working directory.
+    async fn run_pre_launch_commands<Api>(
+        &self,
+        store: &Arc<Api>,
+        project_root: &Path,
+        configuration: &LaunchConfiguration,
+    ) -> Result<()>
+    where
+        Api: StoreApi<State, Action> + Send + Sync + 'static,
+    {
+        let cwd = project_root.join(configuration.cwd.clone().unwrap_or_default());
+        for command in configuration.pre_launch.iter().flatten() {
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Info,
+                    message: format!("Running {}", command),
+                })
+                .await;
+            let mut process = if cfg!(windows) {
+                let mut process = Command::new("cmd");
+                process.arg("/C");
+                process
+            } else {
+                let mut process = Command::new("sh");
+                process.arg("-c");
+                process
+            };
+            let output = process
+                .arg(command)
+                .current_dir(&cwd)
+                .envs(configuration.env.clone().unwrap_or_default())
+                .output()
+                .await?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let reason = stderr.lines().last().unwrap_or_default();
+                return Err(eyre!("{} ({}) {}", command, output.status, reason));
+            }
+        }
+        Ok(())
+    }
 
-        let Ok(id) = self
-            .context
-            .manager
-            .run_new_app(
-                project_root,
-                device_id.clone(),
-                configuration.clone().and_then(|c| c.program.clone()),
-                configuration.clone().and_then(|c| c.flutter_mode.clone()),
-                configuration.clone().and_then(|c| c.cwd.clone()),
-                configuration.clone().and_then(|c| c.args.clone()),
-                self.use_fvm,
-            )
-            .await
-        else {
-            return;
+    async fn run<Api>(
+        &self,
+        store: Arc<Api>,
+        project_root: PathBuf,
+        device_id: Option<String>,
+        configuration: Option<LaunchConfiguration>,
+        group_id: Option<String>,
+    ) where
+        Api: StoreApi<State, Action> + Send + Sync + 'static,
+    {
+        let manager = &self.context.manager;
+        // The debug toggles of the last session are only restored in apps which flx launches.
+        let restore_debug_toggles = !configuration
+            .as_ref()
+            .is_some_and(|c| c.request == LaunchRequest::Attach);
+        let result = match configuration.clone() {
+            Some(configuration) if configuration.request == LaunchRequest::Attach => {
+                manager
+                    .attach_app(
+                        project_root,
+                        device_id.clone(),
+                        configuration.program,
+                        configuration.vm_service_uri,
+                        configuration.app_id,
+                        configuration.cwd,
+                        configuration.tool_args,
+                        configuration.args,
+                        configuration.env,
+                        self.use_fvm,
+                    )
+                    .await
+            }
+            configuration => {
+                manager
+                    .run_new_app(
+                        project_root,
+                        device_id.clone(),
+                        configuration.clone().and_then(|c| c.program.clone()),
+                        configuration.clone().and_then(|c| c.flutter_mode.clone()),
+                        configuration.clone().and_then(|c| c.cwd.clone()),
+                        configuration.clone().and_then(|c| c.tool_args.clone()),
+                        configuration.clone().and_then(|c| c.args.clone()),
+                        configuration.clone().and_then(|c| c.env.clone()),
+                        self.use_fvm,
+                    )
+                    .await
+            }
+        };
+        let id = match result {
+            Ok(id) => id,
+            Err(err) => {
+                log::error!("Failed to start flutter: {:?}", err);
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Error,
+                        message: format!("Failed to start flutter: {}", err),
+                    })
+                    .await;
+                return;
+            }
         };
 
         log::info!("session_id: {:?}", id);
@@ -78,13 +246,16 @@ where
                 session_id: id.clone(),
                 device_id,
                 configuration,
+                group_id,
             })
             .await;
 
         let Some(session) = self.context.manager.session(id.clone()).await else {
             return;
         };
-        let run = &session.as_ref().run;
+        let Some(run) = &session.run else {
+            return;
+        };
 
         if let Ok(params) = run.receive_app_start().await {
             store
@@ -143,7 +314,9 @@ where
                     let context = self.context.clone();
                     let uri = params.ws_uri.clone();
                     tokio::spawn(async move {
-                        RunNewVmServiceThunk::new(context, id, uri).execute(store).await;
+                        RunNewVmServiceThunk::new(context, id, uri, restore_debug_toggles)
+                            .execute(store)
+                            .await;
                     });
                 },
                 Ok(line) = run.receive_stdout() => {
@@ -162,10 +335,16 @@ where
                         })
                         .await;
                 },
-                _ = run.receive_app_stop() => {
+                result = run.receive_app_stop() => {
+                    let error = match result {
+                        Ok(params) => params.error,
+                        // The flutter process exited without stopping the app.
+                        Err(e) => Some(e.to_string()),
+                    };
                     store
                         .dispatch(Action::StopSession {
                             session_id: id.clone(),
+                            error,
                         })
                         .await;
                     if let Err(e) = self
@@ -4,7 +4,10 @@ use std::sync::Arc;
 
 use redux_rs::{middlewares::thunk::Thunk, StoreApi};
 
-use crate::redux::{action::Action, state::State};
+use crate::redux::{
+    action::Action,
+    state::{NotificationLevel, State},
+};
 
 use daemon::flutter::FlutterDaemon;
 use devtools::{
@@ -14,21 +17,31 @@ use devtools::{
 
 use super::{
     context::Context, load_root_widget_summary_tree::LoadRootWidgetWithSummaryTreeThunk,
-    watch_frames::WatchFramesThunk, watch_requests::WatchRequestsThunk,
+    load_service_extension_states::LoadServiceExtensionStatesThunk, watch_frames::WatchFramesThunk,
+    watch_rebuilds::WatchRebuildsThunk, watch_requests::WatchRequestsThunk,
+    watch_service_extensions::WatchServiceExtensionsThunk,
 };
 
 pub struct RunNewVmServiceThunk {
     uri: String,
     session_id: String,
     context: Arc<Context>,
+    /// Turn on the debug toggles which were on in the last session.
+    restore_debug_toggles: bool,
 }
 
 impl RunNewVmServiceThunk {
-    pub fn new(context: Arc<Context>, session_id: String, uri: String) -> Self {
+    pub fn new(
+        context: Arc<Context>,
+        session_id: String,
+        uri: String,
+        restore_debug_toggles: bool,
+    ) -> Self {
         Self {
             context,
             session_id,
             uri,
+            restore_debug_toggles,
         }
     }
 }
@@ -44,9 +57,25 @@ where
         };
         let vm_service = &session.vm_service;
 
-        vm_service.connect(self.uri.clone()).await;
+        if let Err(err) = vm_service.connect(self.uri.clone()).await {
+            log::error!("{:?}", err);
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: err.to_string(),
+                })
+                .await;
+            return;
+        }
+
+        store
+            .dispatch(Action::SetVmServiceUri {
+                session_id: self.session_id.clone(),
+                uri: self.uri.clone(),
+            })
+            .await;
 
-        let stream_ids = vec![StreamId::Extension];
+        let stream_ids = vec![StreamId::Extension, StreamId::Isolate];
 
         for stream_id in stream_ids {
             if let Err(e) = vm_service.stream_listen(stream_id.clone()).await {
@@ -63,6 +92,15 @@ where
                 .await;
         });
 
+        let _store = store.clone();
+        let context = self.context.clone();
+        let session_id = self.session_id.clone();
+        tokio::spawn(async move {
+            WatchRebuildsThunk::new(context, session_id)
+                .execute(_store)
+                .await;
+        });
+
         let _store = store.clone();
         let context = self.context.clone();
         let session_id = self.session_id.clone();
@@ -80,5 +118,24 @@ where
                 .execute(_store)
                 .await;
         });
+
+        let _store = store.clone();
+        let context = self.context.clone();
+        let session_id = self.session_id.clone();
+        tokio::spawn(async move {
+            WatchServiceExtensionsThunk::new(context, session_id)
+                .execute(_store)
+                .await;
+        });
+
+        let _store = store.clone();
+        let context = self.context.clone();
+        let session_id = self.session_id.clone();
+        let restore_debug_toggles = self.restore_debug_toggles;
+        tokio::spawn(async move {
+            LoadServiceExtensionStatesThunk::new(context, session_id, restore_debug_toggles)
+                .execute(_store)
+                .await;
+        });
     }
 }
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::{
+    redux::{
+        action::Action,
+        selector::current_session::current_session_selector_cloned,
+        state::{SessionState, State},
+    },
+    utils::get_data_dir,
+};
+
+use super::context::Context;
+
+pub struct SaveDumpThunk {
+    context: Arc<Context>,
+}
+
+impl SaveDumpThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for SaveDumpThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(SessionState {
+            id: session_id,
+            selected_dump_kind,
+            dumps,
+            ..
+        }) = store.select(current_session_selector_cloned).await
+        else {
+            return;
+        };
+        let Some(dump) = dumps.get(&selected_dump_kind) else {
+            return;
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let directory = get_data_dir().join("dumps");
+        let path = directory.join(format!(
+            "{}_{}.txt",
+            selected_dump_kind.file_name(),
+            timestamp
+        ));
+
+        if let Err(err) = tokio::fs::create_dir_all(&directory).await {
+            log::error!("Failed to create {}: {:?}", directory.display(), err);
+            return;
+        }
+        if let Err(err) = tokio::fs::write(&path, dump).await {
+            log::error!("Failed to save dump to {}: {:?}", path.display(), err);
+            return;
+        }
+
+        store
+            .dispatch(Action::SetSavedDumpPath {
+                session_id,
+                path: Some(path),
+            })
+            .await;
+    }
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use std::{path::Path, sync::Arc};
+use tokio::fs;
+
+use project::profile::{duplicate_launch_profile, save_launch_profile, LaunchProfileChanges};
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+use vscode::launch::{
+    duplicate_launch_configuration, save_launch_configuration, LaunchConfigurationChanges,
+};
+
+use crate::redux::{
+    action::Action,
+    state::{LaunchConfigurationForm, LaunchConfigurationSource, NotificationLevel, State},
+};
+
+use super::{context::Context, load_launch_configurations::LoadLaunchConfigurationsThunk};
+
+/// Writes the configuration in the editor to `flx.toml` or `.vscode/launch.json`, and reloads
+/// the launch configurations.
+pub struct SaveLaunchConfigurationThunk {
+    context: Arc<Context>,
+    form: LaunchConfigurationForm,
+}
+
+impl SaveLaunchConfigurationThunk {
+    pub fn new(context: Arc<Context>, form: LaunchConfigurationForm) -> Self {
+        Self { context, form }
+    }
+
+    async fn save(&self, project_root: &Path) -> Result<()> {
+        let non_empty = |value: &String| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        let form = &self.form;
+        let split_args = |value: &String| {
+            Some(LaunchConfigurationForm::split_args(value)).filter(|args| !args.is_empty())
+        };
+
+        let (path, contents) = match form.source {
+            LaunchConfigurationSource::VSCode => {
+                let path = project_root.join(".vscode/launch.json");
+                let mut json = fs::read_to_string(&path).await.unwrap_or_default();
+                let mut index = form.index;
+                if let Some(source_index) = form.duplicate_of {
+                    let (duplicated, duplicate_index) =
+                        duplicate_launch_configuration(&json, source_index)?;
+                    json = duplicated;
+                    index = Some(duplicate_index);
+                }
+                let changes = LaunchConfigurationChanges {
+                    name: form.name.trim().to_string(),
+                    program: non_empty(&form.program),
+                    flutter_mode: non_empty(&form.mode),
+                    args: split_args(&form.args),
+                    tool_args: split_args(&form.tool_args),
+                    cwd: non_empty(&form.cwd),
+                };
+                (path, save_launch_configuration(&json, index, &changes)?)
+            }
+            _ => {
+                let path = project_root.join("flx.toml");
+                let mut toml = fs::read_to_string(&path).await.unwrap_or_default();
+                let mut index = form.index;
+                if let Some(source_index) = form.duplicate_of {
+                    let (duplicated, duplicate_index) =
+                        duplicate_launch_profile(&toml, source_index)?;
+                    toml = duplicated;
+                    index = Some(duplicate_index);
+                }
+                let changes = LaunchProfileChanges {
+                    name: form.name.trim().to_string(),
+                    target: non_empty(&form.program),
+                    mode: non_empty(&form.mode),
+                    cwd: non_empty(&form.cwd),
+                    tool_args: split_args(&form.tool_args),
+                };
+                (path, save_launch_profile(&toml, index, &changes)?)
+            }
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&path, contents).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for SaveLaunchConfigurationThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+
+        if let Err(e) = self.save(&project_root).await {
+            log::error!("Failed to save launch configuration: {:?}", e);
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: format!("Failed to save launch configuration: {}", e),
+                })
+                .await;
+            return;
+        }
+
+        store.dispatch(Action::HideLaunchConfigurationEditor).await;
+        store
+            .dispatch(Action::ShowNotification {
+                level: NotificationLevel::Info,
+                message: format!("Saved {}", self.form.name.trim()),
+            })
+            .await;
+        LoadLaunchConfigurationsThunk::new(self.context.clone())
+            .execute(store)
+            .await;
+    }
+}
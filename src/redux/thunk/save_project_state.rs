@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use std::{path::Path, sync::Arc, time::Duration};
+use tokio::time::sleep;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{ProjectState, State},
+};
+
+use crate::utils::get_project_state_path;
+
+use super::context::Context;
+
+/// Resizing a pane changes the state on every key press, so writes wait for it to settle.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Writes the project state to its file in the data directory. Only the latest state is written,
+/// and never while another write is running, so an older state can't replace a newer one.
+pub struct SaveProjectStateThunk {
+    context: Arc<Context>,
+    project_state: ProjectState,
+}
+
+impl SaveProjectStateThunk {
+    pub fn new(context: Arc<Context>, project_state: ProjectState) -> Self {
+        Self {
+            context,
+            project_state,
+        }
+    }
+
+    /// Writes the state without waiting, e.g. when flx exits.
+    pub async fn save(&self, project_root: &Path) {
+        *self.context.pending_project_state.lock().await = Some(self.project_state.clone());
+        self.flush(project_root).await;
+    }
+
+    async fn flush(&self, project_root: &Path) {
+        let mut pending = self.context.pending_project_state.lock().await;
+        // Already written by a later save.
+        let Some(project_state) = pending.take() else {
+            return;
+        };
+        if let Err(e) = Self::write(&get_project_state_path(project_root), &project_state).await {
+            log::error!("Failed to save project state: {:?}", e);
+        }
+    }
+
+    /// Writes to a temporary file which replaces the state file, so that a crash doesn't leave
+    /// it half written.
+    async fn write(path: &Path, project_state: &ProjectState) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let temp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&temp_path, serde_json::to_string_pretty(project_state)?).await?;
+        tokio::fs::rename(&temp_path, path).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for SaveProjectStateThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let project_root = store
+            .select(|state: &State| state.project_root.clone())
+            .await;
+        *self.context.pending_project_state.lock().await = Some(self.project_state.clone());
+        sleep(DEBOUNCE).await;
+        self.flush(&project_root).await;
+    }
+}
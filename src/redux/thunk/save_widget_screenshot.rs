@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::{
+    redux::{
+        action::Action,
+        selector::current_session::current_session_selector_cloned,
+        state::{SessionState, State},
+    },
+    utils::get_data_dir,
+};
+
+use super::context::Context;
+
+pub struct SaveWidgetScreenshotThunk {
+    context: Arc<Context>,
+}
+
+impl SaveWidgetScreenshotThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for SaveWidgetScreenshotThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(SessionState {
+            id: session_id,
+            widget_screenshot: Some(screenshot),
+            ..
+        }) = store.select(current_session_selector_cloned).await
+        else {
+            return;
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let directory = get_data_dir().join("screenshots");
+        let file_name: String = screenshot
+            .value_id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let path = directory.join(format!("{}_{}.png", file_name, timestamp));
+
+        if let Err(err) = tokio::fs::create_dir_all(&directory).await {
+            log::error!("Failed to create {}: {:?}", directory.display(), err);
+            return;
+        }
+        if let Err(err) = tokio::fs::write(&path, screenshot.png.as_slice()).await {
+            log::error!("Failed to save screenshot to {}: {:?}", path.display(), err);
+            return;
+        }
+
+        store
+            .dispatch(Action::SetSavedScreenshotPath {
+                session_id,
+                path: Some(path),
+            })
+            .await;
+    }
+}
@@ -4,7 +4,11 @@ use std::sync::Arc;
 
 use redux_rs::{middlewares::thunk::Thunk, StoreApi};
 
-use crate::redux::{action::Action, state::State};
+use crate::redux::{
+    action::Action,
+    selector::current_session::current_session_selector_cloned,
+    state::{LaunchRequest, SessionState, State},
+};
 
 use daemon::flutter::FlutterDaemon;
 
@@ -26,7 +30,12 @@ where
     Api: StoreApi<State, Action> + Send + Sync + 'static,
 {
     async fn execute(&self, store: Arc<Api>) {
-        let Some(session_id) = store.select(|state: &State| state.session_id.clone()).await else {
+        let Some(SessionState {
+            id: session_id,
+            configuration,
+            ..
+        }) = store.select(current_session_selector_cloned).await
+        else {
             return;
         };
 
@@ -36,10 +45,21 @@ where
             })
             .await;
 
-        if let Some(session) = self.context.manager.session(session_id.clone()).await {
-            if let Err(e) = session.run.stop().await {
-                log::error!("Failed to stop session {}: {}", session_id, e);
-            }
+        let Some(session) = self.context.manager.session(session_id.clone()).await else {
+            return;
+        };
+        let Some(run) = &session.run else {
+            session.vm_service.disconnect();
+            return;
+        };
+
+        // Apps we attached to were started by someone else, so leave them running.
+        let result = match configuration.map(|c| c.request) {
+            Some(LaunchRequest::Attach) => run.detach().await,
+            _ => run.stop().await,
         };
+        if let Err(e) = result {
+            log::error!("Failed to stop session {}: {}", session_id, e);
+        }
     }
 }
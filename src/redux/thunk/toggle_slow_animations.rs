@@ -66,9 +66,9 @@ where
             .await
         {
             store
-                .dispatch(Action::SetSlowAnimationsEnabled {
+                .dispatch(Action::SetTimeDilation {
                     session_id,
-                    enabled: diration.time_dilation != *"1.0",
+                    time_dilation: Some(diration.time_dilation),
                 })
                 .await;
         }
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{
+    action::Action,
+    state::{NotificationLevel, PortForward, State},
+};
+
+use super::context::Context;
+
+pub struct UnforwardPortThunk {
+    context: Arc<Context>,
+    forward: PortForward,
+}
+
+impl UnforwardPortThunk {
+    pub fn new(context: Arc<Context>, forward: PortForward) -> Self {
+        Self { context, forward }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for UnforwardPortThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let PortForward {
+            device_id,
+            port,
+            host_port,
+        } = self.forward.clone();
+        if let Err(e) = self
+            .context
+            .daemon
+            .unforward_device(device_id, port, host_port)
+            .await
+        {
+            log::error!("Failed to remove port forward {}: {:?}", port, e);
+            store
+                .dispatch(Action::ShowNotification {
+                    level: NotificationLevel::Error,
+                    message: format!("Failed to remove port forward {}: {}", port, e),
+                })
+                .await;
+            return;
+        }
+
+        store
+            .dispatch(Action::RemovePortForward {
+                forward: self.forward.clone(),
+            })
+            .await;
+    }
+}
@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::redux::{
+    action::Action,
+    state::{NotificationLevel, State},
+};
+
+use daemon::run::RestartReason;
+
+use super::{context::Context, restart_session::RestartSessionThunk};
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+const SYNC_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Change {
+    Source,
+    Packages,
+    Pubspec,
+}
+
+impl Change {
+    fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        if file_name == "pubspec.yaml" {
+            Some(Change::Pubspec)
+        } else if file_name == "package_config.json" && path.parent()?.file_name()? == ".dart_tool"
+        {
+            Some(Change::Packages)
+        } else if file_name.ends_with(".dart") && !file_name.starts_with('.') {
+            Some(Change::Source)
+        } else {
+            None
+        }
+    }
+}
+
+/// Watches `lib/`, `pubspec.yaml` and `.dart_tool/` of the project and of every `cwd` in the
+/// launch configurations while hot reload on save is enabled. Saved dart files hot reload the
+/// running apps, and updated packages hot restart them.
+///
+/// Dispatch it after changing `hot_reload_on_save`. It starts watching when enabled and nothing is
+/// watched yet, and stops the running watcher when disabled.
+pub struct WatchFilesThunk {
+    context: Arc<Context>,
+}
+
+impl WatchFilesThunk {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+
+    fn project_dirs(state: &State) -> HashSet<PathBuf> {
+        let mut dirs = HashSet::new();
+        dirs.insert(state.project_root.clone());
+        for configuration in &state.launch_configurations {
+            if let Some(cwd) = &configuration.cwd {
+                dirs.insert(state.project_root.join(cwd));
+            }
+        }
+        dirs
+    }
+
+    fn running_session_ids(state: &State) -> Vec<String> {
+        state
+            .sessions
+            .iter()
+            .filter(|s| s.started && !s.stopped)
+            .map(|s| s.id.clone())
+            .collect()
+    }
+
+    fn watch(watcher: &mut RecommendedWatcher, dir: &Path) {
+        let targets = [
+            (dir.join("lib"), RecursiveMode::Recursive),
+            (dir.to_path_buf(), RecursiveMode::NonRecursive),
+            (dir.join(".dart_tool"), RecursiveMode::NonRecursive),
+        ];
+        for (path, mode) in targets {
+            if !path.exists() {
+                continue;
+            }
+            if let Err(e) = watcher.watch(&path, mode) {
+                log::error!("Failed to watch {}: {:?}", path.display(), e);
+            }
+        }
+    }
+
+    /// Returns the token of a new watcher, or `None` when there is nothing to start.
+    fn sync_watcher(&self, enabled: bool) -> Option<CancellationToken> {
+        let mut file_watcher = self.context.file_watcher.lock().unwrap();
+        if !enabled {
+            if let Some(token) = file_watcher.take() {
+                token.cancel();
+            }
+            return None;
+        }
+        if file_watcher
+            .as_ref()
+            .is_some_and(|token| !token.is_cancelled())
+        {
+            return None;
+        }
+        let token = CancellationToken::new();
+        *file_watcher = Some(token.clone());
+        Some(token)
+    }
+
+    async fn restart<Api>(&self, store: &Arc<Api>, full_restart: bool)
+    where
+        Api: StoreApi<State, Action> + Send + Sync + 'static,
+    {
+        let session_ids = store.select(Self::running_session_ids).await;
+        for session_id in session_ids {
+            let thunk = RestartSessionThunk::new(
+                self.context.clone(),
+                session_id,
+                full_restart,
+                RestartReason::Save,
+            );
+            let store = store.clone();
+            tokio::spawn(async move { thunk.execute(store).await });
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for WatchFilesThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let enabled = store.select(|state: &State| state.hot_reload_on_save).await;
+        let Some(token) = self.sync_watcher(enabled) else {
+            return;
+        };
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<Event>();
+        let mut watcher = match notify::recommended_watcher(move |event| {
+            if let Ok(event) = event {
+                let _ = tx.send(event);
+            }
+        }) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                log::error!("Failed to start file watcher: {:?}", e);
+                token.cancel();
+                return;
+            }
+        };
+
+        let mut watched_dirs = HashSet::new();
+        let mut interval = tokio::time::interval(SYNC_INTERVAL);
+        loop {
+            let event = tokio::select! {
+                _ = token.cancelled() => break,
+                Some(event) = rx.recv() => event,
+                _ = interval.tick() => {
+                    // Launch configurations are loaded asynchronously, so pick up new
+                    // directories as they appear.
+                    for dir in store.select(Self::project_dirs).await {
+                        if watched_dirs.insert(dir.clone()) {
+                            Self::watch(&mut watcher, &dir);
+                        }
+                    }
+                    continue;
+                },
+            };
+
+            let mut changes = vec![];
+            let mut next = Some(event);
+            while let Some(event) = next {
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    changes.extend(event.paths.iter().filter_map(|p| Change::from_path(p)));
+                }
+                next = tokio::time::timeout(DEBOUNCE, rx.recv())
+                    .await
+                    .ok()
+                    .flatten();
+            }
+
+            if changes.is_empty() || token.is_cancelled() {
+                continue;
+            }
+            if changes.contains(&Change::Pubspec) {
+                store
+                    .dispatch(Action::ShowNotification {
+                        level: NotificationLevel::Info,
+                        message: "pubspec.yaml changed. Run `flutter pub get` and restart the app if you added plugins.".to_string(),
+                    })
+                    .await;
+            } else if changes.contains(&Change::Packages) {
+                self.restart(&store, true).await;
+            } else {
+                self.restart(&store, false).await;
+            }
+        }
+    }
+}
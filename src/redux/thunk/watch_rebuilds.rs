@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use color_eyre::eyre::Result;
+use serde_json::Value;
+use std::sync::Arc;
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use devtools::{
+    protocols::{
+        flutter_extension::RebuildDirtyWidgetsEvent,
+        vm_service::{EventKind, StreamId},
+    },
+    vm_service::VmService,
+};
+
+use super::context::Context;
+
+pub struct WatchRebuildsThunk {
+    session_id: String,
+    context: Arc<Context>,
+}
+
+impl WatchRebuildsThunk {
+    pub fn new(context: Arc<Context>, session_id: String) -> Self {
+        Self {
+            context,
+            session_id,
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for WatchRebuildsThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(session) = self.context.manager.session(self.session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        // Events are only posted while `track_rebuild_dirty_widgets` is enabled. The locations
+        // are only sent with the first event which uses them, so none may be missed.
+        let mut events = vm_service.subscribe_events(StreamId::Extension);
+        while let Ok(event) = events.recv().await {
+            if event.kind != EventKind::Extension {
+                continue;
+            }
+            if event.extension_kind != Some("Flutter.RebuildDirtyWidgets".to_string()) {
+                continue;
+            }
+            let Some(data) = event.extension_data else {
+                continue;
+            };
+            let rebuilds =
+                match serde_json::from_value::<RebuildDirtyWidgetsEvent>(Value::Object(data)) {
+                    Ok(rebuilds) => rebuilds,
+                    Err(err) => {
+                        log::error!("Failed to parse rebuild event: {:?}", err);
+                        continue;
+                    }
+                };
+
+            store
+                .dispatch(Action::AppendRebuildCounts {
+                    session_id: self.session_id.clone(),
+                    locations: rebuilds.new_locations(),
+                    counts: rebuilds.counts(),
+                })
+                .await;
+        }
+    }
+}
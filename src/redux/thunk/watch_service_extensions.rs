@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{sync::Arc, time::Duration};
+use tokio::time::{timeout_at, Instant};
+
+use redux_rs::{middlewares::thunk::Thunk, StoreApi};
+
+use crate::redux::{action::Action, state::State};
+
+use devtools::{
+    protocols::vm_service::{Event, EventKind, StreamId},
+    vm_service::VmService,
+};
+
+use super::{
+    context::Context,
+    load_service_extension_states::{
+        service_extension_state_action, LoadServiceExtensionStatesThunk,
+    },
+};
+
+/// Extensions are registered in a burst, the states are reloaded once none was added for this
+/// long.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+pub struct WatchServiceExtensionsThunk {
+    session_id: String,
+    context: Arc<Context>,
+}
+
+impl WatchServiceExtensionsThunk {
+    pub fn new(context: Arc<Context>, session_id: String) -> Self {
+        Self {
+            context,
+            session_id,
+        }
+    }
+
+    /// Applies `Flutter.ServiceExtensionStateChanged` events, which are posted whenever a
+    /// service extension is changed, including from other clients such as DevTools.
+    async fn watch_state_changes<Api>(&self, store: &Arc<Api>, vm_service: &VmService)
+    where
+        Api: StoreApi<State, Action> + Send + Sync + 'static,
+    {
+        let mut events = vm_service.subscribe_events(StreamId::Extension);
+        while let Ok(event) = events.recv().await {
+            if event.kind != EventKind::Extension {
+                continue;
+            }
+            if event.extension_kind != Some("Flutter.ServiceExtensionStateChanged".to_string()) {
+                continue;
+            }
+            let Some(data) = event.extension_data else {
+                continue;
+            };
+            let Some(Value::String(extension)) = data.get("extension") else {
+                continue;
+            };
+            let value = match data.get("value") {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => continue,
+            };
+            if let Some(action) =
+                service_extension_state_action(&self.session_id, extension, &value)
+            {
+                store.dispatch(action).await;
+            }
+        }
+    }
+
+    /// Flutter registers its service extensions again after every hot restart with their
+    /// default values, so the states are reloaded once they show up.
+    async fn watch_added_extensions<Api>(&self, store: &Arc<Api>, vm_service: &VmService)
+    where
+        Api: StoreApi<State, Action> + Send + Sync + 'static,
+    {
+        let is_added = |event: &Event| {
+            event.kind == EventKind::ServiceExtensionAdded
+                && event
+                    .extension_rpc
+                    .as_ref()
+                    .is_some_and(|rpc| rpc.starts_with("ext.flutter."))
+        };
+        let mut events = vm_service.subscribe_events(StreamId::Isolate);
+        while let Ok(event) = events.recv().await {
+            if !is_added(&event) {
+                continue;
+            }
+            // Wait for the rest of the burst, so that the states are loaded once.
+            let mut deadline = Instant::now() + DEBOUNCE;
+            loop {
+                match timeout_at(deadline, events.recv()).await {
+                    Ok(Ok(event)) if is_added(&event) => deadline = Instant::now() + DEBOUNCE,
+                    Ok(Ok(_)) => {}
+                    Ok(Err(_)) => return,
+                    Err(_) => break,
+                }
+            }
+            LoadServiceExtensionStatesThunk::new(
+                self.context.clone(),
+                self.session_id.clone(),
+                false,
+            )
+            .execute(store.clone())
+            .await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Api> Thunk<State, Action, Api> for WatchServiceExtensionsThunk
+where
+    Api: StoreApi<State, Action> + Send + Sync + 'static,
+{
+    async fn execute(&self, store: Arc<Api>) {
+        let Some(session) = self.context.manager.session(self.session_id.clone()).await else {
+            return;
+        };
+        let vm_service = &session.vm_service;
+
+        tokio::join!(
+            self.watch_state_changes(&store, vm_service),
+            self.watch_added_extensions(&store, vm_service),
+        );
+    }
+}
@@ -12,12 +12,14 @@ use uuid::Uuid;
 
 pub struct Session {
     pub id: String,
-    pub run: FlutterRun,
+    /// The `flutter run` or `flutter attach` process. `None` when connected to the vm service
+    /// directly.
+    pub run: Option<FlutterRun>,
     pub vm_service: VmService,
 }
 
 impl Session {
-    pub fn new(run: FlutterRun, vm_service: VmService) -> Self {
+    pub fn new(run: Option<FlutterRun>, vm_service: VmService) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
             run,
@@ -51,7 +53,9 @@ impl SessionManager {
         program: Option<String>,
         flutter_mode: Option<String>,
         cwd: Option<String>,
+        tool_args: Option<Vec<String>>,
         args: Option<Vec<String>>,
+        env: Option<HashMap<String, String>>,
         use_fvm: bool,
     ) -> Result<String> {
         log::info!("Running new app");
@@ -61,17 +65,58 @@ impl SessionManager {
             program.clone(),
             flutter_mode.clone(),
             cwd.clone(),
+            tool_args,
             args.clone(),
+            env,
             use_fvm,
         )?;
-        let vm_service = VmService::new();
-        let session = Session::new(run, vm_service);
+        Ok(self.insert_session(Some(run)).await)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn attach_app(
+        &self,
+        project_root: PathBuf,
+        device_id: Option<String>,
+        program: Option<String>,
+        debug_url: Option<String>,
+        app_id: Option<String>,
+        cwd: Option<String>,
+        tool_args: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+        env: Option<HashMap<String, String>>,
+        use_fvm: bool,
+    ) -> Result<String> {
+        log::info!("Attaching to app");
+        let run = FlutterRun::attach(
+            project_root,
+            device_id,
+            program,
+            debug_url,
+            app_id,
+            cwd,
+            tool_args,
+            args,
+            env,
+            use_fvm,
+        )?;
+        Ok(self.insert_session(Some(run)).await)
+    }
+
+    /// Creates a session which talks to the vm service only, without the flutter tool.
+    pub async fn connect_vm_service(&self) -> String {
+        log::info!("Connecting to vm service");
+        self.insert_session(None).await
+    }
+
+    async fn insert_session(&self, run: Option<FlutterRun>) -> String {
+        let session = Session::new(run, VmService::new());
         let session_id = session.id.clone();
         self.sessions
             .write()
             .await
             .insert(session_id.clone(), Arc::new(session));
-        Ok(session_id)
+        session_id
     }
 
     pub async fn session(&self, id: String) -> Option<Arc<Session>> {
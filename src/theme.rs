@@ -0,0 +1,171 @@
+use ratatui::style::Color;
+use serde::{de::Deserializer, Deserialize};
+
+/// A built-in theme which the colours in the config override.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+/// The colours of the UI, by what they are used for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Text which is not styled otherwise.
+    pub text: Color,
+    /// Borders of panes which are not focused.
+    pub border: Color,
+    /// Borders of the focused pane and popups.
+    pub focus: Color,
+    /// Background of the selected row.
+    pub highlight: Color,
+    /// Keys, labels and table headers.
+    pub key: Color,
+    /// Hints and secondary text.
+    pub hint: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub info: Color,
+    /// Apps which are restarting and other states which stand out from the warnings.
+    pub accent: Color,
+    /// Property values in dumps.
+    pub value: Color,
+    /// Search matches.
+    pub search_match: Color,
+    pub search_match_text: Color,
+    /// Name of the syntect theme to highlight files with.
+    pub syntax: String,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            text: Color::White,
+            border: Color::White,
+            focus: Color::Green,
+            highlight: Color::DarkGray,
+            key: Color::Yellow,
+            hint: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Blue,
+            accent: Color::LightMagenta,
+            value: Color::Cyan,
+            search_match: Color::Yellow,
+            search_match_text: Color::Black,
+            syntax: "Solarized (dark)".to_string(),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            text: Color::Black,
+            border: Color::DarkGray,
+            focus: Color::Green,
+            highlight: Color::Rgb(215, 215, 215),
+            key: Color::Blue,
+            hint: Color::Gray,
+            success: Color::Green,
+            warning: Color::Rgb(175, 95, 0),
+            error: Color::Red,
+            info: Color::Blue,
+            accent: Color::Magenta,
+            value: Color::Rgb(0, 120, 140),
+            search_match: Color::Yellow,
+            search_match_text: Color::Black,
+            syntax: "Solarized (light)".to_string(),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            text: Color::White,
+            border: Color::White,
+            focus: Color::LightGreen,
+            highlight: Color::Blue,
+            key: Color::LightYellow,
+            hint: Color::Gray,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            info: Color::LightBlue,
+            accent: Color::LightMagenta,
+            value: Color::LightCyan,
+            search_match: Color::LightYellow,
+            search_match_text: Color::Black,
+            syntax: "base16-eighties.dark".to_string(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl From<ThemeName> for Theme {
+    fn from(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// The built-in theme with the colours which are overridden.
+        #[derive(Default, Deserialize)]
+        #[serde(default)]
+        struct Overrides {
+            name: ThemeName,
+            text: Option<Color>,
+            border: Option<Color>,
+            focus: Option<Color>,
+            highlight: Option<Color>,
+            key: Option<Color>,
+            hint: Option<Color>,
+            success: Option<Color>,
+            warning: Option<Color>,
+            error: Option<Color>,
+            info: Option<Color>,
+            accent: Option<Color>,
+            value: Option<Color>,
+            search_match: Option<Color>,
+            search_match_text: Option<Color>,
+            syntax: Option<String>,
+        }
+
+        let overrides = Overrides::deserialize(deserializer)?;
+        let theme = Theme::from(overrides.name);
+        Ok(Theme {
+            text: overrides.text.unwrap_or(theme.text),
+            border: overrides.border.unwrap_or(theme.border),
+            focus: overrides.focus.unwrap_or(theme.focus),
+            highlight: overrides.highlight.unwrap_or(theme.highlight),
+            key: overrides.key.unwrap_or(theme.key),
+            hint: overrides.hint.unwrap_or(theme.hint),
+            success: overrides.success.unwrap_or(theme.success),
+            warning: overrides.warning.unwrap_or(theme.warning),
+            error: overrides.error.unwrap_or(theme.error),
+            info: overrides.info.unwrap_or(theme.info),
+            accent: overrides.accent.unwrap_or(theme.accent),
+            value: overrides.value.unwrap_or(theme.value),
+            search_match: overrides.search_match.unwrap_or(theme.search_match),
+            search_match_text: overrides
+                .search_match_text
+                .unwrap_or(theme.search_match_text),
+            syntax: overrides.syntax.unwrap_or(theme.syntax),
+        })
+    }
+}
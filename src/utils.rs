@@ -1,6 +1,6 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use directories::ProjectDirs;
 use lazy_static::lazy_static;
 use ratatui::prelude::*;
@@ -30,6 +30,7 @@ lazy_static! {
             .ok()
             .map(PathBuf::from);
     pub static ref LOG_ENV: String = format!("{}_LOGLEVEL", PROJECT_NAME.clone());
+    pub static ref GRAPHICS_ENV: String = format!("{}_GRAPHICS", PROJECT_NAME.clone());
     pub static ref LOG_FILE: String = format!("{}.log", env!("CARGO_PKG_NAME"));
 }
 
@@ -100,6 +101,24 @@ pub fn get_data_dir() -> PathBuf {
     directory
 }
 
+/// Where the state of the project is remembered. It is kept out of the project, so that
+/// `flutter clean` doesn't delete it, in a file named after a hash of the project root.
+pub fn get_project_state_path(project_root: &Path) -> PathBuf {
+    let project_root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    // FNV-1a, which unlike `DefaultHasher` gives the same hash in every Rust release.
+    let hash = project_root
+        .to_string_lossy()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    get_data_dir()
+        .join("projects")
+        .join(format!("{:016x}.json", hash))
+}
+
 pub fn get_config_dir() -> PathBuf {
     let directory = if let Some(s) = CONFIG_FOLDER.clone() {
         s
@@ -111,6 +130,18 @@ pub fn get_config_dir() -> PathBuf {
     directory
 }
 
+/// The project root given on the command line, or the current directory.
+pub fn resolve_project_root(project_root: Option<String>) -> Result<PathBuf> {
+    let Some(project_root) = project_root else {
+        return Ok(std::env::current_dir()?);
+    };
+    let path = Path::new(&project_root).to_path_buf();
+    if !path.exists() {
+        return Err(eyre!("Invalid project root"));
+    }
+    Ok(path)
+}
+
 pub fn initialize_logging() -> Result<()> {
     let directory = get_data_dir();
     std::fs::create_dir_all(directory.clone())?;
@@ -182,6 +213,55 @@ Data directory: {data_dir_path}"
     )
 }
 
+/// Scores how well `text` matches `query` when the characters of the query appear in order,
+/// ignoring case. Consecutive characters and characters at the start of words score higher.
+/// Returns `None` when the text doesn't match.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<i64> {
+    let text = text.chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        let index =
+            (position..text.len()).find(|i| text[*i].to_lowercase().eq(q.to_lowercase()))?;
+        score += match previous_match {
+            Some(previous) if previous + 1 == index => 8,
+            _ if index == 0 || !text[index - 1].is_alphanumeric() => 6,
+            _ => 1,
+        };
+        score -= (index - position) as i64;
+        previous_match = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+/// Returns the index of the item at `row` on the screen, in a list drawn with borders in `area`
+/// and scrolled to `offset`. `heights` are the number of lines of each item. Returns `None` on the
+/// borders and below the last item.
+pub fn list_item_at(
+    area: Rect,
+    row: u16,
+    offset: usize,
+    heights: impl IntoIterator<Item = usize>,
+) -> Option<usize> {
+    let inner = area.inner(&Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    if row < inner.top() || row >= inner.bottom() {
+        return None;
+    }
+    let mut line = (row - inner.top()) as usize;
+    for (index, height) in heights.into_iter().enumerate().skip(offset) {
+        if line < height {
+            return Some(index);
+        }
+        line -= height;
+    }
+    None
+}
+
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::vertical([
         Constraint::Percentage((100 - percent_y) / 2),
@@ -197,3 +277,49 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     ])
     .split(popup_layout[1])[1]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_in_order() {
+        assert!(fuzzy_match("hr", "Hot reload").is_some());
+        assert!(fuzzy_match("rh", "Hot reload").is_none());
+        assert_eq!(fuzzy_match("", "Hot reload"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_consecutive_characters() {
+        assert!(
+            fuzzy_match("reload", "Hot reload")
+                > fuzzy_match("reload", "Toggle repaint rainbow load")
+        );
+        assert!(fuzzy_match("tdp", "Toggle debug paint") > fuzzy_match("tdp", "Start and deploy"));
+    }
+
+    #[test]
+    fn project_state_path_per_project() {
+        let path = get_project_state_path(Path::new("/nonexistent/app"));
+        assert_eq!(
+            path.parent(),
+            Some(get_data_dir().join("projects").as_path())
+        );
+        assert_eq!(path, get_project_state_path(Path::new("/nonexistent/app")));
+        assert_ne!(
+            path,
+            get_project_state_path(Path::new("/nonexistent/other"))
+        );
+    }
+
+    #[test]
+    fn list_item_at_row() {
+        let area = Rect::new(0, 10, 20, 6);
+        assert_eq!(list_item_at(area, 10, 0, [1; 5]), None);
+        assert_eq!(list_item_at(area, 11, 0, [1; 5]), Some(0));
+        assert_eq!(list_item_at(area, 13, 2, [1; 5]), Some(4));
+        assert_eq!(list_item_at(area, 14, 2, [1; 5]), None);
+        assert_eq!(list_item_at(area, 15, 0, [1; 5]), None);
+        assert_eq!(list_item_at(area, 13, 0, [2, 3]), Some(1));
+    }
+}
@@ -1 +1,2 @@
+pub mod graphics;
 pub mod tree;
@@ -0,0 +1,269 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use color_eyre::eyre::{eyre, Result};
+use icy_sixel::{DiffusionMethod, MethodForLargest, MethodForRep, PixelFormat, Quality};
+use image::imageops::{self, FilterType};
+use image::{Rgba, RgbaImage};
+use ratatui::prelude::*;
+
+use crate::utils::GRAPHICS_ENV;
+
+const KITTY_CHUNK_SIZE: usize = 4096;
+const DEFAULT_CELL_SIZE: (u16, u16) = (8, 16);
+
+/// The way images are drawn in the terminal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+    #[default]
+    HalfBlocks,
+}
+
+impl GraphicsProtocol {
+    /// Detects the protocol supported by the current terminal. `FLX_GRAPHICS` can be set to
+    /// `kitty`, `sixel` or `halfblocks` to override the detection.
+    pub fn detect() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(value) = var(&GRAPHICS_ENV) {
+            match value.to_lowercase().as_str() {
+                "kitty" => return Self::Kitty,
+                "sixel" => return Self::Sixel,
+                "halfblocks" => return Self::HalfBlocks,
+                _ => {}
+            }
+        }
+
+        // tmux and screen swallow graphics escapes unless passthrough is configured.
+        if var("TMUX").is_some() || var("STY").is_some() {
+            return Self::HalfBlocks;
+        }
+
+        let term = var("TERM").unwrap_or_default();
+        let term_program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("KITTY_WINDOW_ID").is_some()
+            || term.contains("kitty")
+            || term.contains("ghostty")
+            || matches!(term_program.as_str(), "WezTerm" | "ghostty")
+        {
+            return Self::Kitty;
+        }
+        if term.starts_with("foot") || term.starts_with("mlterm") || term.contains("sixel") {
+            return Self::Sixel;
+        }
+        Self::HalfBlocks
+    }
+}
+
+/// Returns the size of a terminal cell in pixels, falling back to a common default when the
+/// terminal doesn't report it.
+pub fn cell_size() -> (u16, u16) {
+    match crossterm::terminal::window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => {
+            (size.width / size.columns, size.height / size.rows)
+        }
+        _ => DEFAULT_CELL_SIZE,
+    }
+}
+
+/// Scales `image` to the largest size that fits in `bounds` while keeping its aspect ratio.
+pub fn fit_size(image: (u32, u32), bounds: (u32, u32)) -> (u32, u32) {
+    let (width, height) = image;
+    let (max_width, max_height) = bounds;
+    if width == 0 || height == 0 || max_width == 0 || max_height == 0 {
+        return (0, 0);
+    }
+    let scale = f64::min(
+        max_width as f64 / width as f64,
+        max_height as f64 / height as f64,
+    );
+    (
+        ((width as f64 * scale) as u32).clamp(1, max_width),
+        ((height as f64 * scale) as u32).clamp(1, max_height),
+    )
+}
+
+/// Builds the escape sequence which transmits `png` and displays it over `columns` x `rows`
+/// cells at the cursor position using the kitty graphics protocol.
+pub fn kitty_sequence(png: &[u8], id: u32, columns: u16, rows: u16) -> String {
+    let payload = STANDARD.encode(png);
+    let chunks = payload
+        .as_bytes()
+        .chunks(KITTY_CHUNK_SIZE)
+        .collect::<Vec<_>>();
+    let mut sequence = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = if index + 1 < chunks.len() { 1 } else { 0 };
+        let chunk = String::from_utf8_lossy(chunk);
+        if index == 0 {
+            sequence.push_str(&format!(
+                "\x1b_Ga=T,f=100,i={},c={},r={},C=1,q=2,m={};{}\x1b\\",
+                id, columns, rows, more, chunk
+            ));
+        } else {
+            sequence.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    sequence
+}
+
+/// Builds the escape sequence which removes the kitty image with `id` from the screen.
+pub fn kitty_delete_sequence(id: u32) -> String {
+    format!("\x1b_Ga=d,d=I,i={},q=2\x1b\\", id)
+}
+
+/// Encodes `image` resized to `width` x `height` pixels as sixel data.
+pub fn sixel_sequence(image: &RgbaImage, width: u32, height: u32) -> Result<String> {
+    let resized = imageops::resize(image, width, height, FilterType::Triangle);
+    icy_sixel::sixel_string(
+        resized.as_raw(),
+        width as i32,
+        height as i32,
+        PixelFormat::RGBA8888,
+        DiffusionMethod::Stucki,
+        MethodForLargest::Auto,
+        MethodForRep::Auto,
+        Quality::HIGH,
+    )
+    .map_err(|err| eyre!("Failed to encode sixel: {}", err))
+}
+
+/// Draws an image with `▀` characters, using the foreground for the upper pixel and the
+/// background for the lower one. Works in every terminal with true color support.
+pub struct HalfBlockImage<'a> {
+    image: &'a RgbaImage,
+}
+
+impl<'a> HalfBlockImage<'a> {
+    pub fn new(image: &'a RgbaImage) -> Self {
+        Self { image }
+    }
+
+    fn color(pixel: &Rgba<u8>) -> Option<Color> {
+        let [r, g, b, a] = pixel.0;
+        if a < 128 {
+            None
+        } else {
+            Some(Color::Rgb(r, g, b))
+        }
+    }
+}
+
+impl Widget for HalfBlockImage<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (width, height) = fit_size(
+            self.image.dimensions(),
+            (area.width as u32, area.height as u32 * 2),
+        );
+        if width == 0 || height == 0 {
+            return;
+        }
+        let resized = imageops::resize(self.image, width, height, FilterType::Triangle);
+
+        for y in (0..height).step_by(2) {
+            for x in 0..width {
+                let top = Self::color(resized.get_pixel(x, y));
+                let bottom = if y + 1 < height {
+                    Self::color(resized.get_pixel(x, y + 1))
+                } else {
+                    None
+                };
+                let cell = buf.get_mut(area.x + x as u16, area.y + (y / 2) as u16);
+                match (top, bottom) {
+                    (Some(top), Some(bottom)) => cell.set_char('▀').set_fg(top).set_bg(bottom),
+                    (Some(top), None) => cell.set_char('▀').set_fg(top),
+                    (None, Some(bottom)) => cell.set_char('▄').set_fg(bottom),
+                    (None, None) => cell,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_fit_size() {
+        assert_eq!(fit_size((100, 50), (20, 20)), (20, 10));
+        assert_eq!(fit_size((50, 100), (20, 20)), (10, 20));
+        assert_eq!(fit_size((4, 4), (20, 10)), (10, 10));
+        assert_eq!(fit_size((1000, 1), (10, 10)), (10, 1));
+        assert_eq!(fit_size((0, 10), (10, 10)), (0, 0));
+    }
+
+    #[test]
+    fn test_detect_protocol() {
+        let detect = |vars: &[(&str, &str)]| {
+            let vars: HashMap<String, String> = vars
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            GraphicsProtocol::from_env(|key| vars.get(key).cloned())
+        };
+        assert_eq!(detect(&[("TERM", "xterm-kitty")]), GraphicsProtocol::Kitty);
+        assert_eq!(
+            detect(&[("TERM_PROGRAM", "WezTerm")]),
+            GraphicsProtocol::Kitty
+        );
+        assert_eq!(detect(&[("TERM", "foot")]), GraphicsProtocol::Sixel);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color")]),
+            GraphicsProtocol::HalfBlocks
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")]),
+            GraphicsProtocol::HalfBlocks
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("FLX_GRAPHICS", "sixel")]),
+            GraphicsProtocol::Sixel
+        );
+    }
+
+    #[test]
+    fn test_kitty_sequence_chunks_payload() {
+        let png = vec![0u8; KITTY_CHUNK_SIZE];
+        let sequence = kitty_sequence(&png, 7, 10, 5);
+        let chunks = sequence
+            .split("\x1b\\")
+            .filter(|chunk| !chunk.is_empty())
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].starts_with("\x1b_Ga=T,f=100,i=7,c=10,r=5,C=1,q=2,m=1;"));
+        assert!(chunks[1].starts_with("\x1b_Gm=0;"));
+        let payload = chunks
+            .iter()
+            .map(|chunk| chunk.split_once(';').unwrap().1)
+            .collect::<String>();
+        assert_eq!(STANDARD.decode(payload).unwrap(), png);
+    }
+
+    #[test]
+    fn test_render_half_block_image() {
+        let mut image = RgbaImage::new(2, 4);
+        for x in 0..2 {
+            image.put_pixel(x, 0, Rgba([255, 0, 0, 255]));
+            image.put_pixel(x, 1, Rgba([0, 0, 255, 255]));
+            image.put_pixel(x, 2, Rgba([0, 0, 0, 0]));
+            image.put_pixel(x, 3, Rgba([0, 255, 0, 255]));
+        }
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        HalfBlockImage::new(&image).render(buf.area, &mut buf);
+
+        let cell = buf.get(0, 0);
+        assert_eq!(cell.symbol(), "▀");
+        assert_eq!(cell.fg, Color::Rgb(255, 0, 0));
+        assert_eq!(cell.bg, Color::Rgb(0, 0, 255));
+        let cell = buf.get(1, 1);
+        assert_eq!(cell.symbol(), "▄");
+        assert_eq!(cell.fg, Color::Rgb(0, 255, 0));
+        assert_eq!(cell.bg, Color::Reset);
+    }
+}
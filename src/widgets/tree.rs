@@ -1,7 +1,9 @@
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
-use ratatui::layout::Offset;
+use ratatui::layout::{Offset, Position};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Clear, List, ListItem, ListState, StatefulWidgetRef, WidgetRef};
 
@@ -11,7 +13,11 @@ pub struct TreeState {
     list_state: ListState,
 
     flat_nodes_cache: Vec<NodeData>,
-    flat_nodes_cache_key: Option<Vec<Vec<String>>>,
+    flat_nodes_cache_key: Option<(Vec<Vec<String>>, u64)>,
+
+    /// Where the nodes were last rendered, and how many columns of them were scrolled out.
+    area: Rect,
+    indent: usize,
 }
 
 impl Default for TreeState {
@@ -29,6 +35,9 @@ impl TreeState {
 
             flat_nodes_cache: vec![],
             flat_nodes_cache_key: None,
+
+            area: Rect::default(),
+            indent: 0,
         }
     }
 
@@ -86,6 +95,24 @@ impl TreeState {
             self.open(id);
         }
     }
+
+    /// Returns the id of the node last rendered at the position on the screen, and whether the
+    /// position is on the marker which opens and closes the node.
+    pub fn node_at(&self, column: u16, row: u16) -> Option<(String, bool)> {
+        if !self.area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let node = self
+            .flat_nodes_cache
+            .get(self.offset() + (row - self.area.y) as usize)?;
+        let depth = node.path.len() - 1;
+        let on_marker = depth.checked_sub(self.indent) == Some((column - self.area.x) as usize)
+            && node
+                .line
+                .get(depth)
+                .is_some_and(|(marker, _)| marker != "─");
+        Some((node.path.last()?.clone(), on_marker))
+    }
 }
 
 #[derive(Clone)]
@@ -117,6 +144,25 @@ impl<'a> Node<'a> {
 
         paths
     }
+
+    /// Hash of the spans of this node and its descendants, used to detect label changes
+    /// which do not change the shape of the tree.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_content(&mut hasher);
+        hasher.finish()
+    }
+
+    fn hash_content<H: Hasher>(&self, hasher: &mut H) {
+        self.id.hash(hasher);
+        for span in &self.spans {
+            span.content.hash(hasher);
+            span.style.hash(hasher);
+        }
+        for child in &self.children {
+            child.hash_content(hasher);
+        }
+    }
 }
 
 pub struct NodeData {
@@ -220,10 +266,13 @@ impl<'a> StatefulWidgetRef for Tree<'a> {
     type State = TreeState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let paths = self.root.flatten(&state.opened, &[]);
-        if state.flat_nodes_cache_key.as_ref() != Some(&paths) {
+        let key = (
+            self.root.flatten(&state.opened, &[]),
+            self.root.content_hash(),
+        );
+        if state.flat_nodes_cache_key.as_ref() != Some(&key) {
             state.flat_nodes_cache = Self::make_lines(&self.root, state, &[], &[], &[]);
-            state.flat_nodes_cache_key = Some(paths);
+            state.flat_nodes_cache_key = Some(key);
         }
 
         let lines = &state.flat_nodes_cache;
@@ -260,6 +309,11 @@ impl<'a> StatefulWidgetRef for Tree<'a> {
             let selected_indent = lines[selected].path.len().saturating_sub(5);
             indent = indent.min(selected_indent);
         };
+        state.indent = indent;
+        state.area = match &self.block {
+            Some(block) => block.inner(area),
+            None => area,
+        };
 
         let items = lines.iter().enumerate().map(|(i, d)| {
             if i < state.list_state.offset()
@@ -533,6 +587,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_node_at() {
+        let mut hash_set = HashSet::new();
+        hash_set.insert("root".to_string());
+        let mut state = TreeState::new().with_opened(hash_set);
+
+        let node = Node::new(
+            "root",
+            vec![Span::raw("root")],
+            vec![
+                Node::new(
+                    "node1",
+                    vec![Span::raw("node1")],
+                    vec![Node::new("hidden", vec![Span::raw("hidden")], vec![])],
+                ),
+                Node::new("node2", vec![Span::raw("node2")], vec![]),
+            ],
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        StatefulWidgetRef::render_ref(
+            &Tree::new(node).block(block),
+            buffer.area,
+            &mut buffer,
+            &mut state,
+        );
+
+        assert_eq!(state.node_at(1, 1), Some(("root".to_string(), true)));
+        assert_eq!(state.node_at(2, 2), Some(("node1".to_string(), true)));
+        assert_eq!(state.node_at(5, 2), Some(("node1".to_string(), false)));
+        // Leaves have nothing to open.
+        assert_eq!(state.node_at(2, 3), Some(("node2".to_string(), false)));
+        assert_eq!(state.node_at(0, 1), None);
+        assert_eq!(state.node_at(1, 4), None);
+    }
+
     #[test]
     fn test_tree_with_shifted() {
         let mut hash_set = HashSet::new();
@@ -612,4 +705,35 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn test_tree_updates_changed_labels() {
+        let mut hash_set = HashSet::new();
+        hash_set.insert("root".to_string());
+        let mut state = TreeState::new().with_opened(hash_set);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        let node = Node::new(
+            "root",
+            vec![Span::raw("root")],
+            vec![Node::new("node1", vec![Span::raw("node1")], vec![])],
+        );
+        StatefulWidgetRef::render_ref(&Tree::new(node), buffer.area, &mut buffer, &mut state);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        let node = Node::new(
+            "root",
+            vec![Span::raw("root")],
+            vec![Node::new(
+                "node1",
+                vec![Span::raw("node1"), Span::raw(" 1/3")],
+                vec![],
+            )],
+        );
+        StatefulWidgetRef::render_ref(&Tree::new(node), buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(vec!["○ root              ", "╰─ node1 1/3        ",])
+        );
+    }
 }